@@ -10,17 +10,35 @@
 //! If you need a production-grade BLAS/LAPACK-backed stack, reach for `nalgebra`/`ndarray` + friends.
 //! If you want approachable implementations you can step through and experiment with, this crate is
 //! meant to be useful.
+//!
+//! ## Features
+//!
+//! - `rayon`: runs dense products, `CsrMatrix::spmv`, the row elimination of the LUP
+//!   decomposition, the columns of the multiple right-hand side solvers and element-wise operations
+//!   on the rayon thread pool. Every output entry is accumulated in the same order as in the serial
+//!   build, so results are bit-identical.
 
 
 mod matrix;
 pub(crate) use matrix::IntoMatrixTrait;
 pub use matrix::{
-    BasicOperationsTrait, CsrMatrix, Matrix, Operation, Position, Shape, SquareMatrix,
-    SquareMatrixTrait, TryIntoSquareMatrixTrait, TryIntoSymmetricCompactedMatrixTrait, Vector,
-    Vector3, VectorTrait,
+    AssemblyStorage, BandStorage, BandedCholeskyFactorization, BandedLuFactorization, BandedMatrix,
+    BasicOperationsTrait, BlockMatrix, ClosureOperator, ConstrainedDiagonal, ConvergenceInfo,
+    CooAssembler, CsrAssembler, CsrMatrix, CsrPattern, DirichletMapping, EulerSequence,
+    IncompleteCholesky, IncompleteLu, LinearOperatorTrait, LupFactorization, Matrix, MatrixBlock,
+    MatrixNormTrait, Operation, OrderingReport, Permutation, PermuteTrait, Pivoting, Position,
+    Precision, PreconditionerTrait, Quaternion, ReducedSystem, Rotation3, SMatrix, SVector, Shape,
+    SparseCholeskyFactorization, SparseCholeskySymbolic, SparseLuFactorization, SquareMatrix,
+    SquareMatrixTrait, SymmetricCsrMatrix, TryIntoSquareMatrixTrait,
+    TryIntoSymmetricCompactedMatrixTrait, Vector, Vector3, VectorTrait, bicgstab,
+    conjugate_gradient, gemm, gemm_nt, gemm_tn, largest_singular_value,
 };
 
+mod scalars;
+pub use scalars::{Complex, Rational};
+
 mod traits;
-pub use traits::FloatTrait;
+pub use traits::{FieldTrait, FloatTrait, MaybeSendSyncTrait};
 
+#[cfg(test)]
 mod tests;
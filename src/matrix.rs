@@ -4,30 +4,95 @@
 //! Most “interesting” routines live under `matrix::traits` and are implemented for `SquareMatrix`.
 //!
 //! High-level structure:
-//! - `structs`: small helper structs (shape, position, …)
+//! - `structs`: small helper structs (shape, position, ordering report, …)
 //! - `traits`: algorithm contracts (e.g. square-matrix solvers/decompositions)
 //! - concrete types: `Matrix`, `SquareMatrix`, `CsrMatrix`
+//! - `gemm`: BLAS-style dense products with optionally transposed operands
+//! - `quaternion`, `rotation_3`: 3D rotations (quaternion, matrix, axis-angle, Euler angles)
+//! - `s_matrix`: fixed-size `SMatrix` / `SVector` with array storage for small element blocks
+//! - `matrix_functions`: `powi`, `expm`, `sqrtm`, `logm` on row-major buffers
+//! - `lup_factorization`: reusable LUP factors (repeated solves, condition estimation)
+//! - `permutation`: `Permutation` of rows / columns / unknowns (`PermuteTrait` applies it)
+//! - `ordering`: RCM, approximate minimum degree and nested dissection on sparsity graphs
+//! - `incomplete_lu`, `incomplete_cholesky`: ILU(0), ILU(k), ILUT and IC(0) preconditioners
+//! - `krylov`: preconditioned conjugate gradient and BiCGSTAB on any `LinearOperatorTrait`
+//! - `assembler`: scatter of element matrices into a global `CsrMatrix` (COO or fixed pattern)
+//! - `banded_matrix`, `banded_factorization`: LAPACK-style band storage, banded LU and Cholesky
+//! - `block_matrix`: `BlockMatrix` of dense / CSR blocks (mixed formulations, Schur complement)
+//! - `boundary_conditions`: Dirichlet conditions by elimination, zeroing or penalty
+//! - `closure_operator`: `ClosureOperator`, closures as matrix-free linear operators
+//! - `power_iteration`: largest singular value of a linear operator
+//! - `symmetric_csr_matrix`: `SymmetricCsrMatrix`, upper-triangle CSR storage of symmetric matrices
+//! - `sparse_cholesky`, `sparse_lu`: sparse direct factorizations of `CsrMatrix` with reusable
+//!   symbolic analysis
 //! - implementations: trait impls + operator overloads
 
+mod assembler;
+mod banded_factorization;
+mod banded_matrix;
+mod block_matrix;
+mod boundary_conditions;
+mod closed_form;
+mod closure_operator;
+
 
 mod csr_matrix;
 mod enums;
+mod gemm;
+mod incomplete_cholesky;
+mod incomplete_lu;
+mod kernels;
+mod krylov;
+mod lup_factorization;
+#[allow(clippy::module_inception)]
 mod matrix;
+mod matrix_functions;
+mod ordering;
+mod permutation;
+mod power_iteration;
+mod quaternion;
+mod rotation_3;
+mod s_matrix;
+mod sparse_cholesky;
+mod sparse_lu;
 mod square_matrix;
 mod structs;
+mod symmetric_csr_matrix;
 mod traits;
 mod vector;
 mod vector_3;
 
+pub use assembler::{CooAssembler, CsrAssembler, CsrPattern};
+pub use banded_factorization::{BandedCholeskyFactorization, BandedLuFactorization};
+pub use banded_matrix::BandedMatrix;
+pub use block_matrix::{BlockMatrix, MatrixBlock};
+pub use boundary_conditions::{DirichletMapping, ReducedSystem};
+pub use closure_operator::ClosureOperator;
 pub use csr_matrix::CsrMatrix;
-pub use enums::Operation;
+pub use enums::{
+    AssemblyStorage, BandStorage, ConstrainedDiagonal, EulerSequence, Operation, Pivoting,
+    Precision,
+};
+pub use gemm::{gemm, gemm_nt, gemm_tn};
+pub use incomplete_cholesky::IncompleteCholesky;
+pub use incomplete_lu::IncompleteLu;
+pub use krylov::{bicgstab, conjugate_gradient};
+pub use lup_factorization::LupFactorization;
 pub use matrix::Matrix;
+pub use permutation::Permutation;
+pub use power_iteration::largest_singular_value;
+pub use quaternion::Quaternion;
+pub use rotation_3::Rotation3;
+pub use s_matrix::{SMatrix, SVector};
+pub use sparse_cholesky::{SparseCholeskyFactorization, SparseCholeskySymbolic};
+pub use sparse_lu::SparseLuFactorization;
 pub use square_matrix::SquareMatrix;
-pub use structs::{Position, Shape};
+pub use structs::{ConvergenceInfo, OrderingReport, Position, Shape};
+pub use symmetric_csr_matrix::SymmetricCsrMatrix;
 pub(crate) use traits::IntoMatrixTrait;
 pub use traits::{
-    BasicOperationsTrait, SquareMatrixTrait, TryIntoSquareMatrixTrait,
-    TryIntoSymmetricCompactedMatrixTrait, VectorTrait,
+    BasicOperationsTrait, LinearOperatorTrait, MatrixNormTrait, PermuteTrait, PreconditionerTrait,
+    SquareMatrixTrait, TryIntoSquareMatrixTrait, TryIntoSymmetricCompactedMatrixTrait, VectorTrait,
 };
 pub use vector::Vector;
 pub use vector_3::Vector3;
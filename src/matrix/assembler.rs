@@ -0,0 +1,332 @@
+//! Assembly of element matrices and vectors into a global sparse system.
+//!
+//! Every element contributes a dense `k x k` matrix (row-major) and/or a `k`-vector on its list of
+//! `k` global DOFs; entries with the same global position are summed. Two paths are provided:
+//!
+//! - `CooAssembler` collects `(row, column, value)` triplets and compresses them into a
+//!   `CsrMatrix` at the end. Nothing needs to be known in advance.
+//! - `CsrPattern` is the symbolic pass: it builds the CSR pattern once from the DOF lists of all
+//!   elements. A `CsrAssembler` then adds element matrices directly into the values of that pattern
+//!   (numeric pass) and can be `reset` and refilled, e.g. at every step of a nonlinear or transient
+//!   analysis, without sorting or allocating again.
+//!
+//! With `AssemblyStorage::Upper` only the upper triangle of the global matrix is kept, which halves
+//! the memory of symmetric matrices. It comes out of `to_symmetric_csr` as a `SymmetricCsrMatrix`;
+//! `to_csr` refuses it, since every `CsrMatrix` consumer would read the triangle as the full
+//! matrix. The right-hand side is always assembled in full.
+
+use crate::{AssemblyStorage, CsrMatrix, FloatTrait, SymmetricCsrMatrix};
+
+fn check_dofs(order: usize, dofs: &[usize]) -> Result<(), String> {
+    if let Some(&dof) = dofs.iter().find(|&&dof| dof >= order) {
+        return Err(format!(
+            "Assembler: DOF {} is out of range for order {}",
+            dof, order
+        ));
+    }
+    Ok(())
+}
+
+/// Checks the DOFs and the number of values of an element matrix (`is_matrix`) or vector.
+fn check_element(
+    order: usize,
+    dofs: &[usize],
+    values_len: usize,
+    is_matrix: bool,
+) -> Result<(), String> {
+    let (kind, expected_len) = if is_matrix {
+        ("matrix", dofs.len() * dofs.len())
+    } else {
+        ("vector", dofs.len())
+    };
+    if values_len != expected_len {
+        return Err(format!(
+            "Assembler: element {} has {} values, expected {} for {} DOFs",
+            kind,
+            values_len,
+            expected_len,
+            dofs.len()
+        ));
+    }
+    check_dofs(order, dofs)
+}
+
+/// `to_csr` needs `Full` storage, `to_symmetric_csr` needs `Upper`.
+fn check_storage(storage: AssemblyStorage, expected: AssemblyStorage) -> Result<(), String> {
+    match (storage, expected) {
+        (AssemblyStorage::Upper, AssemblyStorage::Full) => Err(
+            "Assembler: upper-triangle storage, use to_symmetric_csr for the full matrix"
+                .to_string(),
+        ),
+        (AssemblyStorage::Full, AssemblyStorage::Upper) => {
+            Err("Assembler: full storage, use to_csr".to_string())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn is_stored(storage: AssemblyStorage, row: usize, column: usize) -> bool {
+    storage == AssemblyStorage::Full || row <= column
+}
+
+fn add_vector<V>(rhs: &mut [V], dofs: &[usize], values: &[V]) -> Result<(), String>
+where
+    V: FloatTrait<Output = V>,
+{
+    check_element(rhs.len(), dofs, values.len(), false)?;
+    for (&dof, value) in dofs.iter().zip(values) {
+        rhs[dof] += *value;
+    }
+    Ok(())
+}
+
+/// Assembler collecting triplets, compressed by `to_csr`.
+#[derive(Clone, Debug)]
+pub struct CooAssembler<V> {
+    order: usize,
+    storage: AssemblyStorage,
+    triplets: Vec<(usize, usize, V)>,
+    rhs: Vec<V>,
+}
+
+impl<V> CooAssembler<V>
+where
+    V: FloatTrait<Output = V>,
+{
+    pub fn create(order: usize, storage: AssemblyStorage) -> Self {
+        CooAssembler {
+            order,
+            storage,
+            triplets: Vec::new(),
+            rhs: vec![V::from(0f32); order],
+        }
+    }
+
+    pub fn get_order(&self) -> usize {
+        self.order
+    }
+
+    /// Number of triplets collected so far, duplicates included.
+    pub fn get_triplets_number(&self) -> usize {
+        self.triplets.len()
+    }
+
+    pub fn get_rhs(&self) -> &[V] {
+        &self.rhs
+    }
+
+    /// Adds the row-major `dofs.len() x dofs.len()` element matrix `values`.
+    pub fn add_element_matrix(&mut self, dofs: &[usize], values: &[V]) -> Result<(), String> {
+        check_element(self.order, dofs, values.len(), true)?;
+        for (a, &row) in dofs.iter().enumerate() {
+            for (b, &column) in dofs.iter().enumerate() {
+                if is_stored(self.storage, row, column) {
+                    self.triplets
+                        .push((row, column, values[a * dofs.len() + b]));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn add_element_vector(&mut self, dofs: &[usize], values: &[V]) -> Result<(), String> {
+        add_vector(&mut self.rhs, dofs, values)
+    }
+
+    /// Global matrix with the duplicate positions summed; needs `AssemblyStorage::Full`.
+    pub fn to_csr(&self) -> Result<CsrMatrix<V>, String>
+    where
+        V: Clone,
+    {
+        check_storage(self.storage, AssemblyStorage::Full)?;
+        CsrMatrix::from_coo(self.order, self.order, &self.triplets)
+    }
+
+    /// Global symmetric matrix from its upper triangle; needs `AssemblyStorage::Upper`.
+    pub fn to_symmetric_csr(&self) -> Result<SymmetricCsrMatrix<V>, String>
+    where
+        V: Clone,
+    {
+        check_storage(self.storage, AssemblyStorage::Upper)?;
+        SymmetricCsrMatrix::from_upper_csr(&CsrMatrix::from_coo(
+            self.order,
+            self.order,
+            &self.triplets,
+        )?)
+    }
+}
+
+/// Sparsity pattern of a global matrix, built once from the DOF lists of the elements.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsrPattern {
+    order: usize,
+    storage: AssemblyStorage,
+    row_ptr: Vec<usize>,
+    col_index: Vec<usize>,
+}
+
+impl CsrPattern {
+    /// Symbolic pass over the DOF lists of all elements.
+    pub fn create<I, D>(order: usize, storage: AssemblyStorage, elements: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = D>,
+        D: AsRef<[usize]>,
+    {
+        let mut rows: Vec<Vec<usize>> = vec![Vec::new(); order];
+        for element in elements {
+            let dofs = element.as_ref();
+            check_dofs(order, dofs)?;
+            for &row in dofs {
+                rows[row].extend(
+                    dofs.iter()
+                        .copied()
+                        .filter(|&column| is_stored(storage, row, column)),
+                );
+            }
+        }
+
+        let mut row_ptr = Vec::with_capacity(order + 1);
+        row_ptr.push(0);
+        let mut col_index = Vec::new();
+        for mut row in rows {
+            row.sort_unstable();
+            row.dedup();
+            col_index.extend(row);
+            row_ptr.push(col_index.len());
+        }
+        Ok(CsrPattern {
+            order,
+            storage,
+            row_ptr,
+            col_index,
+        })
+    }
+
+    pub fn get_order(&self) -> usize {
+        self.order
+    }
+
+    pub fn get_storage(&self) -> AssemblyStorage {
+        self.storage
+    }
+
+    pub fn get_nnz(&self) -> usize {
+        self.col_index.len()
+    }
+
+    pub fn get_row_ptr(&self) -> &[usize] {
+        &self.row_ptr
+    }
+
+    pub fn get_col_index(&self) -> &[usize] {
+        &self.col_index
+    }
+
+    /// Index of `(row, column)` in `col_index`.
+    fn find(&self, row: usize, column: usize) -> Option<usize> {
+        let start = self.row_ptr[row];
+        self.col_index[start..self.row_ptr[row + 1]]
+            .binary_search(&column)
+            .ok()
+            .map(|offset| start + offset)
+    }
+}
+
+/// Numeric pass: adds element matrices into the values of a `CsrPattern`.
+#[derive(Clone, Debug)]
+pub struct CsrAssembler<'a, V> {
+    pattern: &'a CsrPattern,
+    values: Vec<V>,
+    rhs: Vec<V>,
+}
+
+impl<'a, V> CsrAssembler<'a, V>
+where
+    V: FloatTrait<Output = V>,
+{
+    pub fn create(pattern: &'a CsrPattern) -> Self {
+        CsrAssembler {
+            pattern,
+            values: vec![V::from(0f32); pattern.get_nnz()],
+            rhs: vec![V::from(0f32); pattern.get_order()],
+        }
+    }
+
+    pub fn get_pattern(&self) -> &CsrPattern {
+        self.pattern
+    }
+
+    pub fn get_rhs(&self) -> &[V] {
+        &self.rhs
+    }
+
+    /// Zeroes the matrix and the right-hand side for the next numeric pass.
+    pub fn reset(&mut self) {
+        self.values.fill(V::from(0f32));
+        self.rhs.fill(V::from(0f32));
+    }
+
+    /// Adds the row-major `dofs.len() x dofs.len()` element matrix `values`. Every position must
+    /// be in the pattern; when one is not, nothing is added.
+    pub fn add_element_matrix(&mut self, dofs: &[usize], values: &[V]) -> Result<(), String> {
+        let pattern = self.pattern;
+        check_element(pattern.order, dofs, values.len(), true)?;
+        let stored = || {
+            dofs.iter().enumerate().flat_map(move |(a, &row)| {
+                dofs.iter()
+                    .enumerate()
+                    .filter(move |&(_, &column)| is_stored(pattern.storage, row, column))
+                    .map(move |(b, &column)| (a * dofs.len() + b, row, column))
+            })
+        };
+        if let Some((_, row, column)) =
+            stored().find(|&(_, row, column)| pattern.find(row, column).is_none())
+        {
+            return Err(format!(
+                "Assembler: entry ({}, {}) is not in the pattern",
+                row, column
+            ));
+        }
+        for (k, row, column) in stored() {
+            let index = pattern.find(row, column).expect("Positions are checked");
+            self.values[index] += values[k];
+        }
+        Ok(())
+    }
+
+    pub fn add_element_vector(&mut self, dofs: &[usize], values: &[V]) -> Result<(), String> {
+        add_vector(&mut self.rhs, dofs, values)
+    }
+
+    fn to_pattern_csr(&self) -> Result<CsrMatrix<V>, String>
+    where
+        V: Clone,
+    {
+        CsrMatrix::create(
+            self.pattern.order,
+            self.pattern.order,
+            self.values.clone(),
+            self.pattern.col_index.clone(),
+            self.pattern.row_ptr.clone(),
+        )
+    }
+
+    /// Global matrix on the pattern, explicit zeros included; needs `AssemblyStorage::Full`.
+    pub fn to_csr(&self) -> Result<CsrMatrix<V>, String>
+    where
+        V: Clone,
+    {
+        check_storage(self.pattern.storage, AssemblyStorage::Full)?;
+        self.to_pattern_csr()
+    }
+
+    /// Global symmetric matrix on the pattern, explicit zeros included; needs
+    /// `AssemblyStorage::Upper`.
+    pub fn to_symmetric_csr(&self) -> Result<SymmetricCsrMatrix<V>, String>
+    where
+        V: Clone,
+    {
+        check_storage(self.pattern.storage, AssemblyStorage::Upper)?;
+        SymmetricCsrMatrix::from_upper_csr(&self.to_pattern_csr()?)
+    }
+}
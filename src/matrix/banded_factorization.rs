@@ -0,0 +1,259 @@
+//! Factorizations of a `BandedMatrix`, following the LAPACK band routines.
+//!
+//! - `BandedLuFactorization`: `P A = L U` with partial pivoting (`gbtf2`). Row interchanges widen
+//!   `U` to `kl + ku` superdiagonals, so the factors take `(2 kl + ku + 1) * n` values: `U` in the
+//!   first `kl + ku + 1` rows of the band array, the multipliers of `L` below. `L` is kept as the
+//!   sequence of interchanges and column eliminations, not as a permuted triangle.
+//! - `BandedCholeskyFactorization`: `A = Uᵀ U` of a symmetric positive definite matrix (`pbtf2`
+//!   with `'U'`); `U` has the band of `A` and overwrites its storage.
+
+use crate::{BandStorage, BandedMatrix, FloatTrait, Position, PreconditionerTrait};
+
+#[derive(Clone, Debug)]
+pub struct BandedLuFactorization<V> {
+    order: usize,
+    lower_bandwidth: usize,
+    upper_bandwidth: usize,
+    // Band array with `2 * kl + ku + 1` rows, `a_ij` at `kl + ku + i - j + j * ld`.
+    values: Vec<V>,
+    pivots: Vec<usize>,
+}
+
+fn check_rhs<V>(order: usize, b: &[V], name: &str) -> Result<(), String> {
+    if b.len() != order {
+        return Err(format!(
+            "{}: dimension mismatch: A is {}x{}, b has len {}",
+            name,
+            order,
+            order,
+            b.len()
+        ));
+    }
+    Ok(())
+}
+
+impl<V> BandedLuFactorization<V>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    pub fn create(a: &BandedMatrix<V>) -> Result<Self, String> {
+        let n = a.get_order();
+        let (kl, ku) = (a.get_lower_bandwidth(), a.get_upper_bandwidth());
+        let kv = kl + ku;
+        let ld = 2 * kl + ku + 1;
+        let index = move |i: usize, j: usize| kv + i - j + j * ld;
+
+        let mut values = vec![V::from(0f32); ld * n];
+        for j in 0..n {
+            for i in j.saturating_sub(ku)..=(j + kl).min(n - 1) {
+                values[index(i, j)] = a.get_element_value(&Position(i, j))?;
+            }
+        }
+
+        let mut pivots = Vec::with_capacity(n);
+        // Last column reached by the rows interchanged so far.
+        let mut last_column = 0;
+        for j in 0..n {
+            let km = kl.min(n - 1 - j);
+            let mut p = 0;
+            for r in 1..=km {
+                if values[index(j + r, j)].my_abs() > values[index(j + p, j)].my_abs() {
+                    p = r;
+                }
+            }
+            pivots.push(j + p);
+            let pivot = values[index(j + p, j)];
+            if pivot == V::from(0f32) || pivot.my_is_nan() {
+                return Err(format!("BandedLu: matrix is singular (column {})", j));
+            }
+            last_column = last_column.max((j + ku + p).min(n - 1));
+            if p != 0 {
+                for c in j..=last_column {
+                    values.swap(index(j, c), index(j + p, c));
+                }
+            }
+            for r in 1..=km {
+                values[index(j + r, j)] = values[index(j + r, j)] / pivot;
+            }
+            for c in j + 1..=last_column {
+                let u_jc = values[index(j, c)];
+                if u_jc == V::from(0f32) {
+                    continue;
+                }
+                for r in 1..=km {
+                    let update = values[index(j + r, j)] * u_jc;
+                    values[index(j + r, c)] -= update;
+                }
+            }
+        }
+
+        Ok(BandedLuFactorization {
+            order: n,
+            lower_bandwidth: kl,
+            upper_bandwidth: ku,
+            values,
+            pivots,
+        })
+    }
+
+    pub fn get_order(&self) -> usize {
+        self.order
+    }
+
+    /// `get_pivots()[j]` is the row interchanged with row `j` at step `j` (LAPACK `ipiv`, from 0).
+    pub fn get_pivots(&self) -> &[usize] {
+        &self.pivots
+    }
+
+    /// Band array of the factors, `2 * kl + ku + 1` rows per column.
+    pub fn get_values(&self) -> &[V] {
+        &self.values
+    }
+
+    /// Solves `A x = b`: interchanges and eliminations of `L` forward, then `U x = y` backward.
+    pub fn solve(&self, b: &[V]) -> Result<Vec<V>, String> {
+        let n = self.order;
+        check_rhs(n, b, "BandedLuFactorization")?;
+        let (kl, kv) = (
+            self.lower_bandwidth,
+            self.lower_bandwidth + self.upper_bandwidth,
+        );
+        let ld = 2 * kl + self.upper_bandwidth + 1;
+        let index = |i: usize, j: usize| kv + i - j + j * ld;
+
+        let mut x = b.to_vec();
+        for j in 0..n {
+            x.swap(j, self.pivots[j]);
+            for r in 1..=kl.min(n - 1 - j) {
+                let update = self.values[index(j + r, j)] * x[j];
+                x[j + r] -= update;
+            }
+        }
+        for j in (0..n).rev() {
+            x[j] = x[j] / self.values[index(j, j)];
+            for i in j.saturating_sub(kv)..j {
+                let update = self.values[index(i, j)] * x[j];
+                x[i] -= update;
+            }
+        }
+        Ok(x)
+    }
+}
+
+/// Exact solve as a preconditioner. Panics if `r` does not match the order of the factorization.
+impl<V> PreconditionerTrait<V> for BandedLuFactorization<V>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    fn apply(&self, r: &[V]) -> Vec<V> {
+        self.solve(r)
+            .expect("Dimensions must match the factorized matrix")
+    }
+
+    fn get_order(&self) -> Option<usize> {
+        Some(self.order)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BandedCholeskyFactorization<V> {
+    order: usize,
+    bandwidth: usize,
+    // `U` in the symmetric band layout, `u_ij` at `kd + i - j + j * (kd + 1)`.
+    values: Vec<V>,
+}
+
+impl<V> BandedCholeskyFactorization<V>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    pub fn create(a: &BandedMatrix<V>) -> Result<Self, String> {
+        if a.get_storage() != BandStorage::Symmetric {
+            return Err("BandedCholesky: matrix must be in symmetric band storage!".to_string());
+        }
+        let n = a.get_order();
+        let kd = a.get_upper_bandwidth();
+        let index = |i: usize, j: usize| kd + i - j + j * (kd + 1);
+
+        let mut values = a.get_values().to_vec();
+        for j in 0..n {
+            let a_jj = values[index(j, j)];
+            if a_jj.my_is_nan() || a_jj <= V::from(0f32) {
+                return Err(format!(
+                    "BandedCholesky: matrix is not positive definite (column {})",
+                    j
+                ));
+            }
+            let u_jj = a_jj.my_sqrt();
+            values[index(j, j)] = u_jj;
+            let kn = kd.min(n - 1 - j);
+            for c in j + 1..=j + kn {
+                values[index(j, c)] = values[index(j, c)] / u_jj;
+            }
+            // Rank-one update of the trailing upper band.
+            for c in j + 1..=j + kn {
+                let u_jc = values[index(j, c)];
+                for r in j + 1..=c {
+                    let update = values[index(j, r)] * u_jc;
+                    values[index(r, c)] -= update;
+                }
+            }
+        }
+
+        Ok(BandedCholeskyFactorization {
+            order: n,
+            bandwidth: kd,
+            values,
+        })
+    }
+
+    pub fn get_order(&self) -> usize {
+        self.order
+    }
+
+    /// `U` in the symmetric band layout, `kd + 1` rows per column.
+    pub fn get_values(&self) -> &[V] {
+        &self.values
+    }
+
+    /// Solves `A x = b`: `Uᵀ y = b` forward, then `U x = y` backward.
+    pub fn solve(&self, b: &[V]) -> Result<Vec<V>, String> {
+        let n = self.order;
+        check_rhs(n, b, "BandedCholeskyFactorization")?;
+        let kd = self.bandwidth;
+        let index = |i: usize, j: usize| kd + i - j + j * (kd + 1);
+
+        let mut x = b.to_vec();
+        for j in 0..n {
+            let mut sum = x[j];
+            let column = j.saturating_sub(kd)..j;
+            for (i, &x_i) in column.clone().zip(&x[column]) {
+                sum -= self.values[index(i, j)] * x_i;
+            }
+            x[j] = sum / self.values[index(j, j)];
+        }
+        for j in (0..n).rev() {
+            x[j] = x[j] / self.values[index(j, j)];
+            for i in j.saturating_sub(kd)..j {
+                let update = self.values[index(i, j)] * x[j];
+                x[i] -= update;
+            }
+        }
+        Ok(x)
+    }
+}
+
+/// Exact solve as a preconditioner. Panics if `r` does not match the order of the factorization.
+impl<V> PreconditionerTrait<V> for BandedCholeskyFactorization<V>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    fn apply(&self, r: &[V]) -> Vec<V> {
+        self.solve(r)
+            .expect("Dimensions must match the factorized matrix")
+    }
+
+    fn get_order(&self) -> Option<usize> {
+        Some(self.order)
+    }
+}
@@ -0,0 +1,325 @@
+//! `BandedMatrix` type + implementations.
+//!
+//! A square matrix whose nonzero entries lie within `kl` subdiagonals and `ku` superdiagonals,
+//! stored by columns in the LAPACK band layouts (`BandStorage`): `(kl + ku + 1) * n` values for a
+//! general matrix, `(kd + 1) * n` for the upper band of a symmetric one. Positions of the band
+//! outside the matrix are kept as zeros.
+//!
+//! Conversions from `SquareMatrix` and `CsrMatrix` take the bandwidths from the nonzero entries, so
+//! renumber with reverse Cuthill–McKee first when they are large. `lu` (partial pivoting) and
+//! `cholesky` (symmetric positive definite) cost `O(n * kl * (kl + ku))` and `O(n * kd²)` instead of
+//! the `O(n³)` of a dense factorization.
+
+use crate::matrix::banded_factorization::{BandedCholeskyFactorization, BandedLuFactorization};
+use crate::matrix::traits::check_operand_length;
+use crate::{
+    BandStorage, CsrMatrix, FloatTrait, LinearOperatorTrait, Position, SquareMatrix,
+    SymmetricCsrMatrix,
+};
+
+#[derive(Clone, Debug)]
+pub struct BandedMatrix<V> {
+    order: usize,
+    lower_bandwidth: usize,
+    upper_bandwidth: usize,
+    storage: BandStorage,
+    values: Vec<V>,
+}
+
+impl<V> BandedMatrix<V>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    /// Takes `values` in the general band layout, `(kl + ku + 1) * order` of them.
+    pub fn create(
+        order: usize,
+        lower_bandwidth: usize,
+        upper_bandwidth: usize,
+        values: Vec<V>,
+    ) -> Result<Self, String> {
+        let banded_matrix = BandedMatrix {
+            order,
+            lower_bandwidth,
+            upper_bandwidth,
+            storage: BandStorage::General,
+            values,
+        };
+        banded_matrix.check_length()?;
+        Ok(banded_matrix)
+    }
+
+    /// Takes `values` in the symmetric (upper) band layout, `(kd + 1) * order` of them.
+    pub fn create_symmetric(
+        order: usize,
+        bandwidth: usize,
+        values: Vec<V>,
+    ) -> Result<Self, String> {
+        let banded_matrix = BandedMatrix {
+            order,
+            lower_bandwidth: bandwidth,
+            upper_bandwidth: bandwidth,
+            storage: BandStorage::Symmetric,
+            values,
+        };
+        banded_matrix.check_length()?;
+        Ok(banded_matrix)
+    }
+
+    fn check_length(&self) -> Result<(), String> {
+        let expected = self.get_leading_dimension() * self.order;
+        if self.values.len() != expected {
+            return Err(format!(
+                "BandedMatrix: band storage has {} values, expected {}",
+                self.values.len(),
+                expected
+            ));
+        }
+        Ok(())
+    }
+
+    fn zeros(
+        order: usize,
+        lower_bandwidth: usize,
+        upper_bandwidth: usize,
+        storage: BandStorage,
+    ) -> Self {
+        let mut banded_matrix = BandedMatrix {
+            order,
+            lower_bandwidth,
+            upper_bandwidth,
+            storage,
+            values: Vec::new(),
+        };
+        banded_matrix.values = vec![V::from(0f32); banded_matrix.get_leading_dimension() * order];
+        banded_matrix
+    }
+
+    /// General band storage with the bandwidths of the nonzero entries of `a`.
+    pub fn from_csr(a: &CsrMatrix<V>) -> Result<Self, String> {
+        let n = a.get_n_rows();
+        if a.get_n_cols() != n {
+            return Err(format!(
+                "BandedMatrix: matrix is not square: A is {}x{}",
+                n,
+                a.get_n_cols()
+            ));
+        }
+        let (row_ptr, col_index, values) = (a.get_row_ptr(), a.get_col_index(), a.get_values());
+        let entries = || {
+            (0..n)
+                .flat_map(move |i| (row_ptr[i]..row_ptr[i + 1]).map(move |idx| (i, idx)))
+                .map(|(i, idx)| (i, col_index[idx], values[idx]))
+                .filter(|&(_, _, value)| value != V::from(0f32))
+        };
+        let lower_bandwidth = entries()
+            .map(|(i, j, _)| i.saturating_sub(j))
+            .max()
+            .unwrap_or(0);
+        let upper_bandwidth = entries()
+            .map(|(i, j, _)| j.saturating_sub(i))
+            .max()
+            .unwrap_or(0);
+        let mut banded_matrix =
+            Self::zeros(n, lower_bandwidth, upper_bandwidth, BandStorage::General);
+        for (i, j, value) in entries() {
+            let index = banded_matrix
+                .index(i, j)
+                .expect("Entry is within the detected band");
+            banded_matrix.values[index] += value;
+        }
+        Ok(banded_matrix)
+    }
+
+    /// Symmetric band storage with the bandwidth of the nonzero entries of `a`.
+    pub fn from_symmetric_csr(a: &SymmetricCsrMatrix<V>) -> Self {
+        let (row_ptr, col_index, values) = (a.get_row_ptr(), a.get_col_index(), a.get_values());
+        let entries = || {
+            (0..a.get_order())
+                .flat_map(move |i| (row_ptr[i]..row_ptr[i + 1]).map(move |idx| (i, idx)))
+                .map(|(i, idx)| (i, col_index[idx], values[idx]))
+                .filter(|&(_, _, value)| value != V::from(0f32))
+        };
+        let bandwidth = entries().map(|(i, j, _)| j - i).max().unwrap_or(0);
+        let mut banded_matrix =
+            Self::zeros(a.get_order(), bandwidth, bandwidth, BandStorage::Symmetric);
+        for (i, j, value) in entries() {
+            let index = banded_matrix
+                .index(i, j)
+                .expect("Entry is within the detected band");
+            banded_matrix.values[index] += value;
+        }
+        banded_matrix
+    }
+
+    /// Symmetric band storage of a full `a`, symmetric within `rel_tol` as for
+    /// `SymmetricCsrMatrix::from_csr`.
+    pub fn symmetric_from_csr(a: &CsrMatrix<V>, rel_tol: V) -> Result<Self, String> {
+        Ok(Self::from_symmetric_csr(&SymmetricCsrMatrix::from_csr(
+            a, rel_tol,
+        )?))
+    }
+
+    /// General band storage with the bandwidths of the nonzero entries of `a`.
+    pub fn from_square_matrix(a: &SquareMatrix<V>) -> Result<Self, String> {
+        Self::from_csr(&CsrMatrix::from_square_matrix(a)?)
+    }
+
+    /// Symmetric band storage of `a`, symmetric within `rel_tol`.
+    pub fn symmetric_from_square_matrix(a: &SquareMatrix<V>, rel_tol: V) -> Result<Self, String> {
+        Ok(Self::from_symmetric_csr(
+            &SymmetricCsrMatrix::from_square_matrix(a, rel_tol)?,
+        ))
+    }
+
+    /// Position of `a_ij` in the band array, `None` outside the band (or, for symmetric storage,
+    /// outside the band of the mirrored entry).
+    fn index(&self, i: usize, j: usize) -> Option<usize> {
+        let ld = self.get_leading_dimension();
+        match self.storage {
+            BandStorage::General => (i + self.upper_bandwidth >= j
+                && j + self.lower_bandwidth >= i)
+                .then(|| self.upper_bandwidth + i - j + j * ld),
+            BandStorage::Symmetric => {
+                let (i, j) = (i.min(j), i.max(j));
+                (j - i <= self.upper_bandwidth).then(|| self.upper_bandwidth + i - j + j * ld)
+            }
+        }
+    }
+
+    pub fn get_order(&self) -> usize {
+        self.order
+    }
+
+    pub fn get_lower_bandwidth(&self) -> usize {
+        self.lower_bandwidth
+    }
+
+    pub fn get_upper_bandwidth(&self) -> usize {
+        self.upper_bandwidth
+    }
+
+    pub fn get_storage(&self) -> BandStorage {
+        self.storage
+    }
+
+    /// Rows of the band array: `kl + ku + 1` for general storage, `kd + 1` for symmetric storage.
+    pub fn get_leading_dimension(&self) -> usize {
+        match self.storage {
+            BandStorage::General => self.lower_bandwidth + self.upper_bandwidth + 1,
+            BandStorage::Symmetric => self.upper_bandwidth + 1,
+        }
+    }
+
+    /// Band array in the LAPACK layout of `get_storage()`.
+    pub fn get_values(&self) -> &[V] {
+        &self.values
+    }
+
+    /// Value of `a_ij`, zero outside the band.
+    pub fn get_element_value(&self, position: &Position) -> Result<V, String> {
+        let Position(i, j) = *position;
+        if i >= self.order || j >= self.order {
+            return Err("Element is absent".to_string());
+        }
+        Ok(self
+            .index(i, j)
+            .map_or(V::from(0f32), |index| self.values[index]))
+    }
+
+    /// Nonzero entries of the whole matrix (both triangles for symmetric storage), by columns.
+    fn entries(&self) -> impl Iterator<Item = (usize, usize, V)> + '_ {
+        let n = self.order;
+        (0..n)
+            .flat_map(move |j| {
+                let first = j.saturating_sub(self.upper_bandwidth);
+                let last = (j + self.lower_bandwidth).min(n.saturating_sub(1));
+                (first..=last).map(move |i| (i, j))
+            })
+            .filter_map(|(i, j)| {
+                let value = self.values[self.index(i, j)?];
+                (value != V::from(0f32)).then_some((i, j, value))
+            })
+    }
+
+    /// Full matrix in CSR form, zeros of the band not stored.
+    pub fn to_csr(&self) -> Result<CsrMatrix<V>, String> {
+        let triplets: Vec<(usize, usize, V)> = self.entries().collect();
+        CsrMatrix::from_coo(self.order, self.order, &triplets)
+    }
+
+    /// `y = A * x`.
+    pub fn spmv(&self, x: &[V]) -> Result<Vec<V>, String> {
+        if x.len() != self.order {
+            return Err(format!(
+                "BandedMatrix::spmv: dimension mismatch: A is {}x{}, x has len {}",
+                self.order,
+                self.order,
+                x.len()
+            ));
+        }
+        Ok(self.multiply(x, false))
+    }
+
+    /// `A * x` or `Aᵀ * x` column by column over the stored band.
+    fn multiply(&self, x: &[V], transpose: bool) -> Vec<V> {
+        let n = self.order;
+        let ld = self.get_leading_dimension();
+        let ku = self.upper_bandwidth;
+        let mut y = vec![V::from(0f32); n];
+        for j in 0..n {
+            let column = &self.values[j * ld..(j + 1) * ld];
+            match self.storage {
+                BandStorage::General => {
+                    let last = (j + self.lower_bandwidth).min(n - 1);
+                    for i in j.saturating_sub(ku)..=last {
+                        let a_ij = column[ku + i - j];
+                        if transpose {
+                            y[j] += a_ij * x[i];
+                        } else {
+                            y[i] += a_ij * x[j];
+                        }
+                    }
+                }
+                BandStorage::Symmetric => {
+                    for i in j.saturating_sub(ku)..j {
+                        let a_ij = column[ku + i - j];
+                        y[i] += a_ij * x[j];
+                        y[j] += a_ij * x[i];
+                    }
+                    y[j] += column[ku] * x[j];
+                }
+            }
+        }
+        y
+    }
+
+    /// LU factorization with partial pivoting (`gbtrf`); symmetric storage is expanded first.
+    pub fn lu(&self) -> Result<BandedLuFactorization<V>, String> {
+        BandedLuFactorization::create(self)
+    }
+
+    /// Cholesky factorization `A = Uᵀ U` (`pbtrf`) of a positive definite matrix in symmetric
+    /// storage.
+    pub fn cholesky(&self) -> Result<BandedCholeskyFactorization<V>, String> {
+        BandedCholeskyFactorization::create(self)
+    }
+}
+
+impl<V> LinearOperatorTrait<V> for BandedMatrix<V>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    fn get_dimensions(&self) -> (usize, usize) {
+        (self.order, self.order)
+    }
+
+    fn apply(&self, x: &[V]) -> Result<Vec<V>, String> {
+        check_operand_length(self.order, x.len(), self.get_dimensions())?;
+        Ok(self.multiply(x, false))
+    }
+
+    fn apply_transpose(&self, y: &[V]) -> Result<Vec<V>, String> {
+        check_operand_length(self.order, y.len(), self.get_dimensions())?;
+        Ok(self.multiply(y, true))
+    }
+}
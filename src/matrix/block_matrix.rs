@@ -0,0 +1,319 @@
+//! `BlockMatrix` type + implementations.
+//!
+//! A matrix partitioned into block rows and block columns of given sizes, every block being a dense
+//! `Matrix`, a `CsrMatrix` or absent (zero). Mixed formulations give such systems, e.g.
+//! `[[K, Bᵀ], [B, 0]]` for displacement-pressure or Lagrange multiplier problems.
+//!
+//! `spmv` multiplies block by block without flattening. `to_csr` and `to_matrix` flatten into a
+//! single matrix; stored zeros of dense blocks are dropped by `to_csr`. `schur_complement` reduces a
+//! 2x2 block matrix to `S = D - C A⁻¹ B` with a sparse LU of `A`.
+
+use crate::matrix::traits::{apply_elements, check_operand_length};
+use crate::{BasicOperationsTrait, CsrMatrix, FloatTrait, LinearOperatorTrait, Matrix, Position};
+
+#[derive(Clone, Debug)]
+pub enum MatrixBlock<V> {
+    Dense(Matrix<V>),
+    Sparse(CsrMatrix<V>),
+}
+
+impl<V> MatrixBlock<V>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    pub fn get_dimensions(&self) -> (usize, usize) {
+        match self {
+            MatrixBlock::Dense(matrix) => (matrix.get_shape().0, matrix.get_shape().1),
+            MatrixBlock::Sparse(matrix) => (matrix.get_n_rows(), matrix.get_n_cols()),
+        }
+    }
+
+    /// Stored entries as `(row, column, value)`.
+    fn triplets(&self) -> Vec<(usize, usize, V)> {
+        match self {
+            MatrixBlock::Dense(matrix) => {
+                let mut triplets: Vec<(usize, usize, V)> = matrix
+                    .get_elements()
+                    .iter()
+                    .filter(|(_, value)| **value != V::from(0f32))
+                    .map(|(Position(i, j), value)| (*i, *j, *value))
+                    .collect();
+                triplets.sort_by_key(|&(i, j, _)| (i, j));
+                triplets
+            }
+            MatrixBlock::Sparse(matrix) => {
+                let (row_ptr, col_index, values) = (
+                    matrix.get_row_ptr(),
+                    matrix.get_col_index(),
+                    matrix.get_values(),
+                );
+                (0..matrix.get_n_rows())
+                    .flat_map(|i| (row_ptr[i]..row_ptr[i + 1]).map(move |idx| (i, idx)))
+                    .map(|(i, idx)| (i, col_index[idx], values[idx]))
+                    .collect()
+            }
+        }
+    }
+
+    fn to_csr(&self) -> Result<CsrMatrix<V>, String> {
+        match self {
+            MatrixBlock::Dense(_) => {
+                let (rows_number, columns_number) = self.get_dimensions();
+                CsrMatrix::from_coo(rows_number, columns_number, &self.triplets())
+            }
+            MatrixBlock::Sparse(matrix) => Ok(matrix.clone()),
+        }
+    }
+}
+
+impl<V> LinearOperatorTrait<V> for MatrixBlock<V>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    fn get_dimensions(&self) -> (usize, usize) {
+        MatrixBlock::get_dimensions(self)
+    }
+
+    fn apply(&self, x: &[V]) -> Result<Vec<V>, String> {
+        match self {
+            MatrixBlock::Dense(matrix) => {
+                apply_elements(matrix.get_elements(), self.get_dimensions(), x, false)
+            }
+            MatrixBlock::Sparse(matrix) => matrix.spmv(x),
+        }
+    }
+
+    fn apply_transpose(&self, y: &[V]) -> Result<Vec<V>, String> {
+        match self {
+            MatrixBlock::Dense(matrix) => {
+                apply_elements(matrix.get_elements(), self.get_dimensions(), y, true)
+            }
+            MatrixBlock::Sparse(matrix) => matrix.spmv_transpose(y),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BlockMatrix<V> {
+    row_sizes: Vec<usize>,
+    column_sizes: Vec<usize>,
+    // Block (i, j) at i * column_sizes.len() + j; `None` is a zero block.
+    blocks: Vec<Option<MatrixBlock<V>>>,
+}
+
+/// Start of every block (prefix sums of `sizes`), with the total at the end.
+fn offsets(sizes: &[usize]) -> Vec<usize> {
+    let mut offsets = Vec::with_capacity(sizes.len() + 1);
+    offsets.push(0);
+    for size in sizes {
+        offsets.push(offsets.last().expect("Offsets are never empty") + size);
+    }
+    offsets
+}
+
+impl<V> BlockMatrix<V>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    /// Block matrix with all blocks zero.
+    pub fn create(row_sizes: &[usize], column_sizes: &[usize]) -> Self {
+        BlockMatrix {
+            row_sizes: row_sizes.to_vec(),
+            column_sizes: column_sizes.to_vec(),
+            blocks: vec![None; row_sizes.len() * column_sizes.len()],
+        }
+    }
+
+    /// Square blocks on the diagonal, zero blocks elsewhere.
+    pub fn block_diagonal(blocks: Vec<MatrixBlock<V>>) -> Result<Self, String> {
+        let sizes: Vec<usize> = blocks
+            .iter()
+            .map(|block| block.get_dimensions().0)
+            .collect();
+        let mut block_matrix = BlockMatrix::create(&sizes, &sizes);
+        for (k, block) in blocks.into_iter().enumerate() {
+            block_matrix.set_block(k, k, block)?;
+        }
+        Ok(block_matrix)
+    }
+
+    pub fn get_row_sizes(&self) -> &[usize] {
+        &self.row_sizes
+    }
+
+    pub fn get_column_sizes(&self) -> &[usize] {
+        &self.column_sizes
+    }
+
+    /// Total `(rows, columns)`.
+    pub fn get_shape(&self) -> (usize, usize) {
+        (self.row_sizes.iter().sum(), self.column_sizes.iter().sum())
+    }
+
+    fn check_block_index(&self, i: usize, j: usize) -> Result<(), String> {
+        if i >= self.row_sizes.len() || j >= self.column_sizes.len() {
+            return Err(format!(
+                "BlockMatrix: block ({}, {}) is out of range for {}x{} blocks",
+                i,
+                j,
+                self.row_sizes.len(),
+                self.column_sizes.len()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Sets block `(i, j)`, whose dimensions must be `row_sizes[i] x column_sizes[j]`.
+    pub fn set_block(&mut self, i: usize, j: usize, block: MatrixBlock<V>) -> Result<(), String> {
+        self.check_block_index(i, j)?;
+        let (rows_number, columns_number) = block.get_dimensions();
+        if rows_number != self.row_sizes[i] || columns_number != self.column_sizes[j] {
+            return Err(format!(
+                "BlockMatrix: block ({}, {}) must be {}x{}, got {}x{}",
+                i, j, self.row_sizes[i], self.column_sizes[j], rows_number, columns_number
+            ));
+        }
+        self.blocks[i * self.column_sizes.len() + j] = Some(block);
+        Ok(())
+    }
+
+    /// Block `(i, j)`, `None` when it is zero or out of range.
+    pub fn get_block(&self, i: usize, j: usize) -> Option<&MatrixBlock<V>> {
+        if i >= self.row_sizes.len() || j >= self.column_sizes.len() {
+            return None;
+        }
+        self.blocks[i * self.column_sizes.len() + j].as_ref()
+    }
+
+    /// Nonzero blocks with their indices.
+    fn nonzero_blocks(&self) -> impl Iterator<Item = (usize, usize, &MatrixBlock<V>)> {
+        let columns = self.column_sizes.len();
+        self.blocks
+            .iter()
+            .enumerate()
+            .filter_map(move |(k, block)| {
+                block
+                    .as_ref()
+                    .map(|block| (k / columns, k % columns, block))
+            })
+    }
+
+    /// `y = A * x` block by block.
+    pub fn spmv(&self, x: &[V]) -> Result<Vec<V>, String> {
+        let (rows_number, columns_number) = self.get_shape();
+        if x.len() != columns_number {
+            return Err(format!(
+                "BlockMatrix::spmv: dimension mismatch: A is {}x{}, x has len {}",
+                rows_number,
+                columns_number,
+                x.len()
+            ));
+        }
+        let (row_offsets, column_offsets) = (offsets(&self.row_sizes), offsets(&self.column_sizes));
+        let mut y = vec![V::from(0f32); rows_number];
+        for (i, j, block) in self.nonzero_blocks() {
+            let y_block = block.apply(&x[column_offsets[j]..column_offsets[j + 1]])?;
+            for (y_k, value) in y[row_offsets[i]..row_offsets[i + 1]]
+                .iter_mut()
+                .zip(y_block)
+            {
+                *y_k += value;
+            }
+        }
+        Ok(y)
+    }
+
+    fn flat_triplets(&self) -> Vec<(usize, usize, V)> {
+        let (row_offsets, column_offsets) = (offsets(&self.row_sizes), offsets(&self.column_sizes));
+        self.nonzero_blocks()
+            .flat_map(|(i, j, block)| {
+                let (row_offset, column_offset) = (row_offsets[i], column_offsets[j]);
+                block
+                    .triplets()
+                    .into_iter()
+                    .map(move |(r, c, value)| (row_offset + r, column_offset + c, value))
+            })
+            .collect()
+    }
+
+    /// Flattens into a single `CsrMatrix`.
+    pub fn to_csr(&self) -> Result<CsrMatrix<V>, String> {
+        let (rows_number, columns_number) = self.get_shape();
+        CsrMatrix::from_coo(rows_number, columns_number, &self.flat_triplets())
+    }
+
+    /// Flattens into a single dense `Matrix`.
+    pub fn to_matrix(&self) -> Matrix<V> {
+        let (rows_number, columns_number) = self.get_shape();
+        let mut values = vec![V::from(0f32); rows_number * columns_number];
+        for (r, c, value) in self.flat_triplets() {
+            values[r * columns_number + c] += value;
+        }
+        Matrix::create(rows_number, columns_number, &values)
+    }
+
+    /// Schur complement `S = D - C A⁻¹ B` of the 2x2 block matrix `[[A, B], [C, D]]`, with a
+    /// nonsingular `A`. A zero `B` or `C` gives `S = D`.
+    pub fn schur_complement(&self) -> Result<Matrix<V>, String> {
+        if self.row_sizes.len() != 2 || self.column_sizes.len() != 2 {
+            return Err(format!(
+                "BlockMatrix: Schur complement needs 2x2 blocks, got {}x{}",
+                self.row_sizes.len(),
+                self.column_sizes.len()
+            ));
+        }
+        let (n, m) = (self.row_sizes[1], self.column_sizes[1]);
+        let mut s = vec![V::from(0f32); n * m];
+        if let Some(d) = self.get_block(1, 1) {
+            for (r, c, value) in d.triplets() {
+                s[r * m + c] += value;
+            }
+        }
+        if let (Some(b), Some(c)) = (self.get_block(0, 1), self.get_block(1, 0)) {
+            let Some(a) = self.get_block(0, 0) else {
+                return Err("BlockMatrix: Schur complement needs a nonzero block A".to_string());
+            };
+            let lu = a.to_csr()?.lu()?;
+            // Column k of C A⁻¹ B is C A⁻¹ (B e_k).
+            let mut unit = vec![V::from(0f32); m];
+            for k in 0..m {
+                unit[k] = V::from(1f32);
+                let column = c.apply(&lu.solve(&b.apply(&unit)?)?)?;
+                unit[k] = V::from(0f32);
+                for (r, value) in column.into_iter().enumerate() {
+                    s[r * m + k] -= value;
+                }
+            }
+        }
+        Ok(Matrix::create(n, m, &s))
+    }
+}
+
+impl<V> LinearOperatorTrait<V> for BlockMatrix<V>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    fn get_dimensions(&self) -> (usize, usize) {
+        self.get_shape()
+    }
+
+    fn apply(&self, x: &[V]) -> Result<Vec<V>, String> {
+        self.spmv(x)
+    }
+
+    fn apply_transpose(&self, y: &[V]) -> Result<Vec<V>, String> {
+        check_operand_length(self.get_shape().0, y.len(), self.get_shape())?;
+        let (row_offsets, column_offsets) = (offsets(&self.row_sizes), offsets(&self.column_sizes));
+        let mut x = vec![V::from(0f32); self.get_shape().1];
+        for (i, j, block) in self.nonzero_blocks() {
+            let x_block = block.apply_transpose(&y[row_offsets[i]..row_offsets[i + 1]])?;
+            for (x_k, value) in x[column_offsets[j]..column_offsets[j + 1]]
+                .iter_mut()
+                .zip(x_block)
+            {
+                *x_k += value;
+            }
+        }
+        Ok(x)
+    }
+}
@@ -0,0 +1,270 @@
+//! Dirichlet boundary conditions on assembled FEM systems `A u = b`.
+//!
+//! A constraint prescribes `u_c = ū_c` for a DOF `c`. Three ways of imposing it are provided, on
+//! `SquareMatrix` and `CsrMatrix` alike, all returning a new matrix and right-hand side:
+//!
+//! - elimination (`eliminate_dirichlet`): the constrained rows and columns are removed and
+//!   `b_f - A_fc ū_c` becomes the right-hand side of the free DOFs. The `DirichletMapping` of the
+//!   returned `ReducedSystem` numbers the free DOFs and expands a reduced solution to full size.
+//! - zeroing (`apply_dirichlet`): row and column `c` are zeroed, `d` is put on the diagonal and
+//!   `b_c = d ū_c`, where `d` is chosen by `ConstrainedDiagonal`. The columns are moved to the
+//!   right-hand side like in elimination, so a symmetric matrix stays symmetric, and the order of
+//!   the system does not change.
+//! - penalty (`apply_penalty`): `α` is added to `a_cc` and `α ū_c` to `b_c`. Nothing else changes,
+//!   and the constraint holds up to `O(1 / α)`.
+//!
+//! On `CsrMatrix` the zeroed entries stay stored, so the sparsity pattern (and any symbolic
+//! analysis done for it) is kept; a missing diagonal entry of a constrained row is added.
+
+use crate::{ConstrainedDiagonal, FloatTrait};
+
+/// `(row, column, value)` of a stored entry.
+pub(crate) type Triplet<V> = (usize, usize, V);
+
+/// System left by `eliminate_dirichlet` for the free DOFs.
+#[derive(Clone, Debug)]
+pub struct ReducedSystem<M, V> {
+    pub matrix: M,
+    pub rhs: Vec<V>,
+    pub mapping: DirichletMapping<V>,
+}
+
+/// Numbering of the free DOFs left by `eliminate_dirichlet`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DirichletMapping<V> {
+    order: usize,
+    free_dofs: Vec<usize>,
+    prescribed: Vec<(usize, V)>,
+}
+
+impl<V> DirichletMapping<V>
+where
+    V: FloatTrait<Output = V>,
+{
+    /// Order of the full system.
+    pub fn get_order(&self) -> usize {
+        self.order
+    }
+
+    /// Order of the reduced system.
+    pub fn get_reduced_order(&self) -> usize {
+        self.free_dofs.len()
+    }
+
+    /// `get_free_dofs()[k]` is the full index of reduced DOF `k`.
+    pub fn get_free_dofs(&self) -> &[usize] {
+        &self.free_dofs
+    }
+
+    /// Constrained DOFs with their prescribed values, sorted by DOF.
+    pub fn get_prescribed(&self) -> &[(usize, V)] {
+        &self.prescribed
+    }
+
+    /// Full solution from the solution of the reduced system.
+    pub fn expand(&self, reduced: &[V]) -> Result<Vec<V>, String> {
+        if reduced.len() != self.free_dofs.len() {
+            return Err(format!(
+                "DirichletMapping: reduced vector has len {}, expected {}",
+                reduced.len(),
+                self.free_dofs.len()
+            ));
+        }
+        let mut full = vec![V::from(0f32); self.order];
+        for (&dof, value) in self.free_dofs.iter().zip(reduced) {
+            full[dof] = *value;
+        }
+        for &(dof, value) in &self.prescribed {
+            full[dof] = value;
+        }
+        Ok(full)
+    }
+
+    /// Free components of a full vector.
+    pub fn restrict(&self, full: &[V]) -> Result<Vec<V>, String> {
+        if full.len() != self.order {
+            return Err(format!(
+                "DirichletMapping: full vector has len {}, expected {}",
+                full.len(),
+                self.order
+            ));
+        }
+        Ok(self.free_dofs.iter().map(|&dof| full[dof]).collect())
+    }
+}
+
+/// Prescribed value of every DOF, `None` for the free ones.
+fn prescribed_values<V>(
+    order: usize,
+    rhs: &[V],
+    prescribed: &[(usize, V)],
+) -> Result<Vec<Option<V>>, String>
+where
+    V: FloatTrait<Output = V>,
+{
+    if rhs.len() != order {
+        return Err(format!(
+            "Dirichlet: dimension mismatch: A is {}x{}, b has len {}",
+            order,
+            order,
+            rhs.len()
+        ));
+    }
+    let mut values = vec![None; order];
+    for &(dof, value) in prescribed {
+        if dof >= order {
+            return Err(format!(
+                "Dirichlet: DOF {} is out of range for order {}",
+                dof, order
+            ));
+        }
+        if values[dof].is_some() {
+            return Err(format!("Dirichlet: DOF {} is constrained twice", dof));
+        }
+        values[dof] = Some(value);
+    }
+    Ok(values)
+}
+
+/// Removes the constrained rows and columns from the `entries` of an `order x order` matrix.
+pub(crate) fn eliminate<V, I>(
+    order: usize,
+    entries: I,
+    rhs: &[V],
+    prescribed: &[(usize, V)],
+) -> Result<ReducedSystem<Vec<Triplet<V>>, V>, String>
+where
+    V: FloatTrait<Output = V>,
+    I: Iterator<Item = Triplet<V>>,
+{
+    let values = prescribed_values(order, rhs, prescribed)?;
+    let free_dofs: Vec<usize> = (0..order).filter(|&dof| values[dof].is_none()).collect();
+    let mut reduced_index = vec![usize::MAX; order];
+    for (k, &dof) in free_dofs.iter().enumerate() {
+        reduced_index[dof] = k;
+    }
+
+    let mut reduced_rhs: Vec<V> = free_dofs.iter().map(|&dof| rhs[dof]).collect();
+    let mut reduced_entries = Vec::new();
+    for (i, j, a_ij) in entries {
+        if values[i].is_some() {
+            continue;
+        }
+        match values[j] {
+            Some(value) => reduced_rhs[reduced_index[i]] -= a_ij * value,
+            None => reduced_entries.push((reduced_index[i], reduced_index[j], a_ij)),
+        }
+    }
+
+    let mut prescribed = prescribed.to_vec();
+    prescribed.sort_by_key(|&(dof, _)| dof);
+    let mapping = DirichletMapping {
+        order,
+        free_dofs,
+        prescribed,
+    };
+    Ok(ReducedSystem {
+        matrix: reduced_entries,
+        rhs: reduced_rhs,
+        mapping,
+    })
+}
+
+/// Zeroes the constrained rows and columns of the `entries` of an `order x order` matrix and sets
+/// their diagonal. Every entry is kept, and a diagonal entry is appended for the constrained rows
+/// that have none.
+pub(crate) fn zero_rows_and_columns<V, I>(
+    order: usize,
+    entries: I,
+    rhs: &[V],
+    prescribed: &[(usize, V)],
+    diagonal: ConstrainedDiagonal<V>,
+) -> Result<(Vec<Triplet<V>>, Vec<V>), String>
+where
+    V: FloatTrait<Output = V>,
+    I: Iterator<Item = Triplet<V>>,
+{
+    let values = prescribed_values(order, rhs, prescribed)?;
+    let zero = V::from(0f32);
+    let mut new_rhs = rhs.to_vec();
+    let mut new_entries: Vec<Triplet<V>> = Vec::new();
+    let mut original_diagonal: Vec<Option<V>> = vec![None; order];
+    let mut diagonal_entry = vec![usize::MAX; order];
+    for (i, j, a_ij) in entries {
+        match (values[i], values[j]) {
+            (None, None) => new_entries.push((i, j, a_ij)),
+            (None, Some(value)) => {
+                new_rhs[i] -= a_ij * value;
+                new_entries.push((i, j, zero));
+            }
+            (Some(_), _) => {
+                if i == j {
+                    let sum = original_diagonal[i].map_or(a_ij, |sum| sum + a_ij);
+                    original_diagonal[i] = Some(sum);
+                    diagonal_entry[i] = new_entries.len();
+                }
+                new_entries.push((i, j, zero));
+            }
+        }
+    }
+
+    for (c, value) in values.iter().enumerate() {
+        let Some(value) = value else {
+            continue;
+        };
+        let d = match diagonal {
+            ConstrainedDiagonal::Unit => V::from(1f32),
+            ConstrainedDiagonal::Scaled(factor) => {
+                let d = factor * original_diagonal[c].unwrap_or(zero);
+                if d == zero || d.my_is_nan() {
+                    return Err(format!("Dirichlet: zero diagonal entry in row {}", c));
+                }
+                d
+            }
+        };
+        if diagonal_entry[c] == usize::MAX {
+            new_entries.push((c, c, d));
+        } else {
+            new_entries[diagonal_entry[c]].2 = d;
+        }
+        new_rhs[c] = d * *value;
+    }
+    Ok((new_entries, new_rhs))
+}
+
+/// Adds `penalty` to the diagonal of the constrained rows of the `entries` of an `order x order`
+/// matrix, appending the diagonal entries that are missing.
+pub(crate) fn add_penalty<V, I>(
+    order: usize,
+    entries: I,
+    rhs: &[V],
+    prescribed: &[(usize, V)],
+    penalty: V,
+) -> Result<(Vec<Triplet<V>>, Vec<V>), String>
+where
+    V: FloatTrait<Output = V>,
+    I: Iterator<Item = Triplet<V>>,
+{
+    let values = prescribed_values(order, rhs, prescribed)?;
+    let mut new_rhs = rhs.to_vec();
+    let mut has_diagonal = vec![false; order];
+    let mut new_entries: Vec<Triplet<V>> = entries
+        .map(|(i, j, a_ij)| match values[i] {
+            Some(_) if i == j && !has_diagonal[i] => {
+                has_diagonal[i] = true;
+                (i, j, a_ij + penalty)
+            }
+            _ => (i, j, a_ij),
+        })
+        .collect();
+    for (c, value) in values.iter().enumerate() {
+        let Some(value) = value else {
+            continue;
+        };
+        if !has_diagonal[c] {
+            new_entries.push((c, c, penalty));
+        }
+        new_rhs[c] += penalty * *value;
+    }
+    Ok((new_entries, new_rhs))
+}
@@ -0,0 +1,120 @@
+//! Closed-form determinant and adjugate of small square matrices.
+//!
+//! For orders up to four the explicit cofactor formulas are both cheaper and more accurate than an
+//! elimination. The helpers work on row-major `n x n` slices without heap allocation (the pivot
+//! check before an inverse eliminates a stack copy) and return `None` for orders they do not
+//! cover, so callers can fall back to the LUP elimination.
+
+use crate::FieldTrait;
+use crate::matrix::traits::eliminate_gep_row_major;
+
+/// Largest order handled by the closed forms.
+pub(crate) const MAX_CLOSED_FORM_ORDER: usize = 4;
+
+/// 2x2 minors of the first two rows (`s`) and of the last two rows (`c`) of a 4x4 matrix, indexed
+/// by the column pairs (0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3).
+fn minors_4<V>(a: &[V]) -> ([V; 6], [V; 6])
+where
+    V: FieldTrait,
+{
+    let pairs = [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)];
+    let s = pairs.map(|(j, k)| a[j] * a[4 + k] - a[k] * a[4 + j]);
+    let c = pairs.map(|(j, k)| a[8 + j] * a[12 + k] - a[8 + k] * a[12 + j]);
+    (s, c)
+}
+
+pub(crate) fn determinant<V>(a: &[V], n: usize) -> Option<V>
+where
+    V: FieldTrait,
+{
+    let det = match n {
+        0 => V::from(1f32),
+        1 => a[0],
+        2 => a[0] * a[3] - a[1] * a[2],
+        3 => {
+            a[0] * (a[4] * a[8] - a[5] * a[7]) - a[1] * (a[3] * a[8] - a[5] * a[6])
+                + a[2] * (a[3] * a[7] - a[4] * a[6])
+        }
+        4 => {
+            // Laplace expansion along the first two rows.
+            let (s, c) = minors_4(a);
+            s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+        }
+        _ => return None,
+    };
+    Some(det)
+}
+
+/// Writes the adjugate (transposed cofactor matrix) of `a` into `adjugate`, so that
+/// `a * adjugate = det(a) * I`.
+pub(crate) fn adjugate<V>(a: &[V], n: usize, adjugate: &mut [V]) -> Option<()>
+where
+    V: FieldTrait,
+{
+    match n {
+        0 => (),
+        1 => adjugate[0] = V::from(1f32),
+        2 => {
+            adjugate[0] = a[3];
+            adjugate[1] = V::from(0f32) - a[1];
+            adjugate[2] = V::from(0f32) - a[2];
+            adjugate[3] = a[0];
+        }
+        3 => {
+            adjugate[0] = a[4] * a[8] - a[5] * a[7];
+            adjugate[1] = a[2] * a[7] - a[1] * a[8];
+            adjugate[2] = a[1] * a[5] - a[2] * a[4];
+            adjugate[3] = a[5] * a[6] - a[3] * a[8];
+            adjugate[4] = a[0] * a[8] - a[2] * a[6];
+            adjugate[5] = a[2] * a[3] - a[0] * a[5];
+            adjugate[6] = a[3] * a[7] - a[4] * a[6];
+            adjugate[7] = a[1] * a[6] - a[0] * a[7];
+            adjugate[8] = a[0] * a[4] - a[1] * a[3];
+        }
+        4 => {
+            let (s, c) = minors_4(a);
+            adjugate[0] = a[5] * c[5] - a[6] * c[4] + a[7] * c[3];
+            adjugate[1] = a[2] * c[4] - a[1] * c[5] - a[3] * c[3];
+            adjugate[2] = a[13] * s[5] - a[14] * s[4] + a[15] * s[3];
+            adjugate[3] = a[10] * s[4] - a[9] * s[5] - a[11] * s[3];
+            adjugate[4] = a[6] * c[2] - a[4] * c[5] - a[7] * c[1];
+            adjugate[5] = a[0] * c[5] - a[2] * c[2] + a[3] * c[1];
+            adjugate[6] = a[14] * s[2] - a[12] * s[5] - a[15] * s[1];
+            adjugate[7] = a[8] * s[5] - a[10] * s[2] + a[11] * s[1];
+            adjugate[8] = a[4] * c[4] - a[5] * c[2] + a[7] * c[0];
+            adjugate[9] = a[1] * c[2] - a[0] * c[4] - a[3] * c[0];
+            adjugate[10] = a[12] * s[4] - a[13] * s[2] + a[15] * s[0];
+            adjugate[11] = a[9] * s[2] - a[8] * s[4] - a[11] * s[0];
+            adjugate[12] = a[5] * c[1] - a[4] * c[3] - a[6] * c[0];
+            adjugate[13] = a[0] * c[3] - a[1] * c[1] + a[2] * c[0];
+            adjugate[14] = a[13] * s[1] - a[12] * s[3] - a[14] * s[0];
+            adjugate[15] = a[8] * s[3] - a[9] * s[1] + a[10] * s[0];
+        }
+        _ => return None,
+    }
+    Some(())
+}
+
+/// Rejects a matrix before its determinant is divided by: an exact zero determinant is
+/// "Singular matrix", otherwise `a` has to pass the pivot checks of an elimination with the same
+/// `rel_tol`. A row-scaled bound on the determinant itself would tighten with every order, so the
+/// closed forms accept exactly the matrices the elimination of larger orders would. The
+/// elimination runs on a stack copy of `a`, so the check does not allocate.
+pub(crate) fn check_determinant<V>(
+    det: V,
+    a: &[V],
+    n: usize,
+    rel_tol: V::Real,
+) -> Result<(), String>
+where
+    V: FieldTrait,
+{
+    if det == V::from(0f32) {
+        return Err("Singular matrix".to_string());
+    }
+    let mut lu = [V::from(0f32); MAX_CLOSED_FORM_ORDER * MAX_CLOSED_FORM_ORDER];
+    let mut s = [V::Real::from(0f32); MAX_CLOSED_FORM_ORDER];
+    lu[..n * n].copy_from_slice(a);
+    eliminate_gep_row_major(&mut lu[..n * n], &mut [], n, &mut s[..n], rel_tol)?;
+    Ok(())
+}
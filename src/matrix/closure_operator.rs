@@ -0,0 +1,85 @@
+//! `ClosureOperator` type + implementations.
+//!
+//! Gives closures the dimensions a `LinearOperatorTrait` needs. The results of the closures are
+//! checked against the dimensions, so a closure returning the wrong number of components is
+//! reported as an error by `apply` rather than failing later inside a solver.
+
+use crate::LinearOperatorTrait;
+use crate::matrix::traits::check_operand_length;
+
+#[derive(Clone, Debug)]
+pub struct ClosureOperator<F, FT> {
+    rows_number: usize,
+    columns_number: usize,
+    apply: F,
+    apply_transpose: Option<FT>,
+}
+
+impl<V, F> ClosureOperator<F, fn(&[V]) -> Vec<V>>
+where
+    F: Fn(&[V]) -> Vec<V>,
+{
+    /// Operator without a transpose product.
+    pub fn create(rows_number: usize, columns_number: usize, apply: F) -> Self {
+        ClosureOperator {
+            rows_number,
+            columns_number,
+            apply,
+            apply_transpose: None,
+        }
+    }
+}
+
+impl<F, FT> ClosureOperator<F, FT> {
+    pub fn create_with_transpose<V>(
+        rows_number: usize,
+        columns_number: usize,
+        apply: F,
+        apply_transpose: FT,
+    ) -> Self
+    where
+        F: Fn(&[V]) -> Vec<V>,
+        FT: Fn(&[V]) -> Vec<V>,
+    {
+        ClosureOperator {
+            rows_number,
+            columns_number,
+            apply,
+            apply_transpose: Some(apply_transpose),
+        }
+    }
+}
+
+fn check_result<V>(result: Vec<V>, expected: usize) -> Result<Vec<V>, String> {
+    if result.len() != expected {
+        return Err(format!(
+            "ClosureOperator: closure returned {} components, expected {}",
+            result.len(),
+            expected
+        ));
+    }
+    Ok(result)
+}
+
+impl<V, F, FT> LinearOperatorTrait<V> for ClosureOperator<F, FT>
+where
+    F: Fn(&[V]) -> Vec<V>,
+    FT: Fn(&[V]) -> Vec<V>,
+{
+    fn get_dimensions(&self) -> (usize, usize) {
+        (self.rows_number, self.columns_number)
+    }
+
+    fn apply(&self, x: &[V]) -> Result<Vec<V>, String> {
+        check_operand_length(self.columns_number, x.len(), self.get_dimensions())?;
+        check_result((self.apply)(x), self.rows_number)
+    }
+
+    fn apply_transpose(&self, y: &[V]) -> Result<Vec<V>, String> {
+        let Some(apply_transpose) = &self.apply_transpose else {
+            return Err("LinearOperator: transpose product is not available!".to_string());
+        };
+        check_operand_length(self.rows_number, y.len(), self.get_dimensions())?;
+        check_result(apply_transpose(y), self.columns_number)
+    }
+}
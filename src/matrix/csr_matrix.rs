@@ -1,4 +1,17 @@
-use crate::{BasicOperationsTrait, FloatTrait, Position, SquareMatrix};
+use crate::matrix::boundary_conditions::{Triplet, add_penalty, eliminate, zero_rows_and_columns};
+use crate::matrix::incomplete_cholesky::IncompleteCholesky;
+use crate::matrix::incomplete_lu::IncompleteLu;
+use crate::matrix::kernels::for_each_chunk_mut;
+use crate::matrix::ordering::{
+    approximate_minimum_degree, bandwidth, nested_dissection, ordering_report, profile,
+    reverse_cuthill_mckee, symmetric_adjacency,
+};
+use crate::matrix::sparse_cholesky::{SparseCholeskyFactorization, SparseCholeskySymbolic};
+use crate::matrix::sparse_lu::SparseLuFactorization;
+use crate::{
+    BasicOperationsTrait, ConstrainedDiagonal, FieldTrait, FloatTrait, LinearOperatorTrait,
+    OrderingReport, Permutation, Position, ReducedSystem, SquareMatrix, largest_singular_value,
+};
 
 #[derive(Clone, Debug)]
 pub struct CsrMatrix<V> {
@@ -59,6 +72,7 @@ where
         &self.row_ptr
     }
 
+    #[allow(clippy::clone_on_copy)]
     pub fn from_square_matrix(a: &SquareMatrix<V>) -> Result<Self, String> {
         let a_shape = a.get_shape();
         let (n_rows, n_cols) = (a_shape.0, a_shape.1);
@@ -114,6 +128,7 @@ where
         CsrMatrix::create(n_rows, n_cols, values, col_index, row_ptr)
     }
 
+    #[allow(clippy::unnecessary_sort_by, clippy::assign_op_pattern)]
     pub fn from_coo(
         n_rows: usize,
         n_cols: usize,
@@ -187,7 +202,7 @@ where
 
         let mut y = vec![V::from(0.0_f32); self.n_rows];
 
-        for i in 0..self.n_rows {
+        for_each_chunk_mut(&mut y, 1, |i, y_i| {
             let row_start = self.row_ptr[i];
             let row_end = self.row_ptr[i + 1];
 
@@ -197,12 +212,336 @@ where
                 let j = self.col_index[idx];
                 let a_ij = &self.values[idx];
                 let x_j = &x[j];
-                sum = sum + (*a_ij) * (*x_j);
+                sum += (*a_ij) * (*x_j);
             }
 
-            y[i] = sum;
+            y_i[0] = sum;
+        });
+
+        Ok(y)
+    }
+
+    /// `y = Aᵀ * x`, computed by scattering each stored row, without building the transpose.
+    pub fn spmv_transpose(&self, x: &[V]) -> Result<Vec<V>, String> {
+        if x.len() != self.n_rows {
+            return Err(format!(
+                "CsrMatrix::spmv_transpose: dimension mismatch: A is {}x{}, x has len {}",
+                self.n_rows,
+                self.n_cols,
+                x.len()
+            ));
+        }
+
+        let mut y = vec![V::from(0.0_f32); self.n_cols];
+
+        for (i, &x_i) in x.iter().enumerate() {
+            for idx in self.row_ptr[i]..self.row_ptr[i + 1] {
+                y[self.col_index[idx]] += self.values[idx] * x_i;
+            }
         }
 
         Ok(y)
     }
+
+    /// Maximum absolute column sum.
+    pub fn norm_1(&self) -> V {
+        let mut sums = vec![V::from(0.0_f32); self.n_cols];
+        for (value, j) in self.values.iter().zip(&self.col_index) {
+            sums[*j] += value.my_abs();
+        }
+        sums.into_iter().fold(
+            V::from(0.0_f32),
+            |max, sum| if sum > max { sum } else { max },
+        )
+    }
+
+    /// Maximum absolute row sum.
+    pub fn norm_inf(&self) -> V {
+        (0..self.n_rows)
+            .map(|i| {
+                self.values[self.row_ptr[i]..self.row_ptr[i + 1]]
+                    .iter()
+                    .fold(V::from(0.0_f32), |sum, value| sum + value.my_abs())
+            })
+            .fold(
+                V::from(0.0_f32),
+                |max, sum| if sum > max { sum } else { max },
+            )
+    }
+
+    pub fn norm_frobenius(&self) -> V {
+        self.values
+            .iter()
+            .fold(V::from(0.0_f32), |sum, value| sum + *value * *value)
+            .my_sqrt()
+    }
+
+    /// Largest absolute stored value.
+    pub fn norm_max(&self) -> V {
+        self.values.iter().fold(V::from(0.0_f32), |max, value| {
+            if value.my_abs() > max {
+                value.my_abs()
+            } else {
+                max
+            }
+        })
+    }
+
+    /// Spectral norm, estimated by power iteration on `AᵀA` (see `MatrixNormTrait::norm_2`).
+    pub fn norm_2(&self, rel_tol: V, max_iterations: usize) -> Result<V, String>
+    where
+        V: FieldTrait<Real = V>,
+    {
+        largest_singular_value(self, rel_tol, max_iterations)
+    }
+
+    /// Sparse Cholesky factorization of a symmetric positive definite matrix. Use
+    /// `SparseCholeskySymbolic` directly to keep the analysis for later matrices of the same
+    /// pattern.
+    pub fn cholesky(&self) -> Result<SparseCholeskyFactorization<V>, String> {
+        SparseCholeskySymbolic::create(self)?.factorize(self)
+    }
+
+    /// Sparse LU factorization with partial pivoting.
+    pub fn lu(&self) -> Result<SparseLuFactorization<V>, String> {
+        SparseLuFactorization::create(self)
+    }
+
+    fn entries(&self) -> impl Iterator<Item = Triplet<V>> + '_ {
+        (0..self.n_rows).flat_map(move |i| {
+            (self.row_ptr[i]..self.row_ptr[i + 1])
+                .map(move |idx| (i, self.col_index[idx], self.values[idx]))
+        })
+    }
+
+    fn check_square(&self) -> Result<(), String> {
+        if self.n_rows != self.n_cols {
+            return Err(format!(
+                "Dirichlet: matrix is not square: A is {}x{}",
+                self.n_rows, self.n_cols
+            ));
+        }
+        Ok(())
+    }
+
+    /// Removes the DOFs of `prescribed` (`(dof, value)` pairs) from `A u = b`; see
+    /// `DirichletMapping::expand` to recover the full solution. When every DOF is prescribed the
+    /// reduced matrix is an empty `0x0` one.
+    pub fn eliminate_dirichlet(
+        &self,
+        rhs: &[V],
+        prescribed: &[(usize, V)],
+    ) -> Result<ReducedSystem<Self, V>, String> {
+        self.check_square()?;
+        let reduced = eliminate(self.n_rows, self.entries(), rhs, prescribed)?;
+        let order = reduced.mapping.get_reduced_order();
+        let matrix = if order == 0 {
+            CsrMatrix::create(0, 0, Vec::new(), Vec::new(), vec![0])?
+        } else {
+            CsrMatrix::from_coo(order, order, &reduced.matrix)?
+        };
+        Ok(ReducedSystem {
+            matrix,
+            rhs: reduced.rhs,
+            mapping: reduced.mapping,
+        })
+    }
+
+    /// Zeroes the rows and columns of the DOFs of `prescribed` and puts `diagonal` on their
+    /// diagonal. The zeroed entries stay stored, so the sparsity pattern only grows by the
+    /// diagonal entries that were missing.
+    pub fn apply_dirichlet(
+        &self,
+        rhs: &[V],
+        prescribed: &[(usize, V)],
+        diagonal: ConstrainedDiagonal<V>,
+    ) -> Result<(Self, Vec<V>), String> {
+        self.check_square()?;
+        let (entries, new_rhs) =
+            zero_rows_and_columns(self.n_rows, self.entries(), rhs, prescribed, diagonal)?;
+        Ok((
+            CsrMatrix::from_coo(self.n_rows, self.n_cols, &entries)?,
+            new_rhs,
+        ))
+    }
+
+    /// Imposes `prescribed` with the penalty `penalty` on the diagonal.
+    pub fn apply_penalty(
+        &self,
+        rhs: &[V],
+        prescribed: &[(usize, V)],
+        penalty: V,
+    ) -> Result<(Self, Vec<V>), String> {
+        self.check_square()?;
+        let (entries, new_rhs) =
+            add_penalty(self.n_rows, self.entries(), rhs, prescribed, penalty)?;
+        Ok((
+            CsrMatrix::from_coo(self.n_rows, self.n_cols, &entries)?,
+            new_rhs,
+        ))
+    }
+
+    /// ILU(0) preconditioner (see `IncompleteLu`).
+    pub fn ilu0(&self) -> Result<IncompleteLu<V>, String> {
+        IncompleteLu::ilu0(self)
+    }
+
+    /// ILU(k) preconditioner with fill up to level `level`.
+    pub fn iluk(&self, level: usize) -> Result<IncompleteLu<V>, String> {
+        IncompleteLu::iluk(self, level)
+    }
+
+    /// ILUT preconditioner with a relative drop tolerance and at most `max_fill` entries per row
+    /// in each factor.
+    pub fn ilut(&self, drop_tolerance: V, max_fill: usize) -> Result<IncompleteLu<V>, String> {
+        IncompleteLu::ilut(self, drop_tolerance, max_fill)
+    }
+
+    /// IC(0) preconditioner of a symmetric positive definite matrix.
+    pub fn ic0(&self) -> Result<IncompleteCholesky<V>, String> {
+        IncompleteCholesky::ic0(self)
+    }
+
+    /// Positions of the stored entries that are not exactly zero.
+    fn nonzero_positions(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.n_rows).flat_map(move |i| {
+            (self.row_ptr[i]..self.row_ptr[i + 1])
+                .filter(|&idx| self.values[idx] != V::from(0f32))
+                .map(move |idx| (i, self.col_index[idx]))
+        })
+    }
+
+    /// Graph of the stored pattern of `A + Aᵀ`.
+    fn sparsity_graph(&self) -> Result<Vec<Vec<usize>>, String> {
+        if self.n_rows != self.n_cols {
+            return Err(format!(
+                "CsrMatrix::ordering: matrix is not square: A is {}x{}",
+                self.n_rows, self.n_cols
+            ));
+        }
+        Ok(symmetric_adjacency(
+            self.n_rows,
+            (0..self.n_rows).flat_map(|i| {
+                self.col_index[self.row_ptr[i]..self.row_ptr[i + 1]]
+                    .iter()
+                    .map(move |&j| (i, j))
+            }),
+        ))
+    }
+
+    /// Reverse Cuthill–McKee ordering (small bandwidth and profile).
+    pub fn reverse_cuthill_mckee(&self) -> Result<Permutation, String> {
+        Ok(Permutation::from_indices_unchecked(reverse_cuthill_mckee(
+            &self.sparsity_graph()?,
+        )))
+    }
+
+    /// Approximate minimum degree ordering (little fill in a factorization).
+    pub fn approximate_minimum_degree(&self) -> Result<Permutation, String> {
+        Ok(Permutation::from_indices_unchecked(
+            approximate_minimum_degree(&self.sparsity_graph()?),
+        ))
+    }
+
+    /// Nested dissection ordering (little fill on mesh-like graphs).
+    pub fn nested_dissection(&self) -> Result<Permutation, String> {
+        Ok(Permutation::from_indices_unchecked(nested_dissection(
+            &self.sparsity_graph()?,
+        )))
+    }
+
+    /// `P A Pᵀ`: entry `(i, j)` of the result is entry `(p[i], p[j])` of `A`.
+    pub fn permute_symmetric(&self, permutation: &Permutation) -> Result<Self, String> {
+        if self.n_rows != self.n_cols {
+            return Err(format!(
+                "CsrMatrix::permute_symmetric: matrix is not square: A is {}x{}",
+                self.n_rows, self.n_cols
+            ));
+        }
+        self.permute_rows(permutation)?.permute_columns(permutation)
+    }
+
+    /// Largest `|i - j|` over the nonzero entries.
+    pub fn bandwidth(&self) -> usize {
+        bandwidth(self.nonzero_positions())
+    }
+
+    /// Off-diagonal positions under the skyline of the symmetrized nonzero pattern.
+    pub fn profile(&self) -> usize {
+        profile(self.n_rows.max(self.n_cols), self.nonzero_positions())
+    }
+
+    /// Bandwidth and profile before and after `permute_symmetric(permutation)`.
+    pub fn ordering_report(&self, permutation: &Permutation) -> Result<OrderingReport, String> {
+        if self.n_rows != self.n_cols {
+            return Err(format!(
+                "CsrMatrix::ordering_report: matrix is not square: A is {}x{}",
+                self.n_rows, self.n_cols
+            ));
+        }
+        permutation.check_order(self.n_rows)?;
+        let positions: Vec<(usize, usize)> = self.nonzero_positions().collect();
+        Ok(ordering_report(
+            self.n_rows,
+            &positions,
+            &permutation.inverse_indices(),
+        ))
+    }
+
+    /// `P * A`: row `i` of the result is row `p[i]` of `A`.
+    pub fn permute_rows(&self, permutation: &Permutation) -> Result<Self, String> {
+        permutation.check_order(self.n_rows)?;
+        let mut values = Vec::with_capacity(self.values.len());
+        let mut col_index = Vec::with_capacity(self.col_index.len());
+        let mut row_ptr = vec![0usize];
+        for &old_row in permutation.get_indices() {
+            let range = self.row_ptr[old_row]..self.row_ptr[old_row + 1];
+            values.extend_from_slice(&self.values[range.clone()]);
+            col_index.extend_from_slice(&self.col_index[range]);
+            row_ptr.push(col_index.len());
+        }
+        CsrMatrix::create(self.n_rows, self.n_cols, values, col_index, row_ptr)
+    }
+
+    /// `A * Pᵀ`: column `j` of the result is column `p[j]` of `A`.
+    pub fn permute_columns(&self, permutation: &Permutation) -> Result<Self, String> {
+        permutation.check_order(self.n_cols)?;
+        let inverse = permutation.inverse_indices();
+        let mut values = Vec::with_capacity(self.values.len());
+        let mut col_index = Vec::with_capacity(self.col_index.len());
+        for i in 0..self.n_rows {
+            let mut row: Vec<(usize, V)> = (self.row_ptr[i]..self.row_ptr[i + 1])
+                .map(|idx| (inverse[self.col_index[idx]], self.values[idx]))
+                .collect();
+            row.sort_by_key(|&(j, _)| j);
+            for (j, value) in row {
+                col_index.push(j);
+                values.push(value);
+            }
+        }
+        CsrMatrix::create(
+            self.n_rows,
+            self.n_cols,
+            values,
+            col_index,
+            self.row_ptr.clone(),
+        )
+    }
+}
+
+impl<V> LinearOperatorTrait<V> for CsrMatrix<V>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    fn get_dimensions(&self) -> (usize, usize) {
+        (self.n_rows, self.n_cols)
+    }
+
+    fn apply(&self, x: &[V]) -> Result<Vec<V>, String> {
+        self.spmv(x)
+    }
+
+    fn apply_transpose(&self, y: &[V]) -> Result<Vec<V>, String> {
+        self.spmv_transpose(y)
+    }
 }
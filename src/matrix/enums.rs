@@ -1,3 +1,15 @@
+mod assembly_storage;
+mod band_storage;
+mod constrained_diagonal;
+mod euler_sequence;
 mod operation;
+mod pivoting;
+mod precision;
 
+pub use assembly_storage::AssemblyStorage;
+pub use band_storage::BandStorage;
+pub use constrained_diagonal::ConstrainedDiagonal;
+pub use euler_sequence::EulerSequence;
 pub use operation::Operation;
+pub use pivoting::Pivoting;
+pub use precision::Precision;
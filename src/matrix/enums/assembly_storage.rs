@@ -0,0 +1,9 @@
+/// Which entries of the element matrices an assembler stores.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AssemblyStorage {
+    /// Every entry.
+    Full,
+    /// Only the upper triangle (`row <= column` in global numbering), for symmetric matrices.
+    /// The element matrices are assumed symmetric and their lower-triangle entries are ignored.
+    Upper,
+}
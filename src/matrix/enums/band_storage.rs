@@ -0,0 +1,9 @@
+/// Layout of a `BandedMatrix`, as in the LAPACK band routines.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BandStorage {
+    /// `kl` sub- and `ku` superdiagonals: `a_ij` at `ku + i - j + j * (kl + ku + 1)` (`gbmv`).
+    General,
+    /// Upper band of a symmetric matrix, `kd` superdiagonals: `a_ij` (`i <= j`) at
+    /// `kd + i - j + j * (kd + 1)` (`sbmv` / `pbtrf` with `'U'`).
+    Symmetric,
+}
@@ -0,0 +1,10 @@
+/// Diagonal entry `apply_dirichlet` puts on the row of a constrained DOF, whose equation then
+/// reads `d * u = d * ū`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ConstrainedDiagonal<V> {
+    /// `d = 1`.
+    Unit,
+    /// `d = factor * a_cc`, the original diagonal entry scaled, which keeps the constrained
+    /// equations on the scale of the others. A zero diagonal entry is an error.
+    Scaled(V),
+}
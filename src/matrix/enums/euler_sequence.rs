@@ -0,0 +1,44 @@
+/// Axis order of Euler angles `[a, b, c]`.
+///
+/// Angles are intrinsic: the frame is turned by `a` about the first axis, then by `b` about the
+/// (already rotated) second axis and finally by `c` about the third one, i.e.
+/// `R = R_first(a) * R_second(b) * R_third(c)`. The same angles read in reverse order describe the
+/// extrinsic rotation about the fixed axes.
+///
+/// Tait-Bryan sequences (`Xyz`, ...) use three different axes; proper Euler sequences (`Zxz`, ...)
+/// repeat the first axis.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum EulerSequence {
+    Xyz,
+    Xzy,
+    Yxz,
+    Yzx,
+    Zxy,
+    Zyx,
+    Xyx,
+    Xzx,
+    Yxy,
+    Yzy,
+    Zxz,
+    Zyz,
+}
+
+impl EulerSequence {
+    /// Axis indices (`0` = x, `1` = y, `2` = z) in rotation order.
+    pub fn get_axes(&self) -> [usize; 3] {
+        match self {
+            EulerSequence::Xyz => [0, 1, 2],
+            EulerSequence::Xzy => [0, 2, 1],
+            EulerSequence::Yxz => [1, 0, 2],
+            EulerSequence::Yzx => [1, 2, 0],
+            EulerSequence::Zxy => [2, 0, 1],
+            EulerSequence::Zyx => [2, 1, 0],
+            EulerSequence::Xyx => [0, 1, 0],
+            EulerSequence::Xzx => [0, 2, 0],
+            EulerSequence::Yxy => [1, 0, 1],
+            EulerSequence::Yzy => [1, 2, 1],
+            EulerSequence::Zxz => [2, 0, 2],
+            EulerSequence::Zyz => [2, 1, 2],
+        }
+    }
+}
@@ -0,0 +1,10 @@
+/// How elimination routines choose the pivot row in a column.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Pivoting {
+    /// Scaled partial pivoting: the entry with the largest `modulus` relative to its row scale.
+    /// Appropriate for floating-point types, where it limits rounding error growth.
+    LargestMagnitude,
+    /// The first non-zero entry. Appropriate for exact types, where rounding is not an issue
+    /// and a magnitude ordering is either meaningless or not worth computing.
+    FirstNonZero,
+}
@@ -0,0 +1,8 @@
+/// Floating-point precision used for a factorization.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Precision {
+    /// Factor in `f32`: half the memory traffic, about seven significant digits per solve.
+    Single,
+    /// Factor in `f64`.
+    Double,
+}
@@ -0,0 +1,108 @@
+//! General matrix-matrix multiplication (`gemm`).
+//!
+//! BLAS-style update `C <- alpha * op(A) * op(B) + beta * C` for any of the `HashMap`-backed matrix
+//! types. The variants only differ in which operand is read transposed:
+//! - `gemm`: `op(A) = A`, `op(B) = B`
+//! - `gemm_tn`: `op(A) = Aᵀ`, `op(B) = B`
+//! - `gemm_nt`: `op(A) = A`, `op(B) = Bᵀ`
+//!
+//! Transposed operands are read in place by the tiled kernel; no transposed copy is built.
+//! As in BLAS, `C` is not read when `beta` is zero, so it may hold any values (or be sparse).
+
+// external imports
+use std::ops::{AddAssign, Mul};
+
+use crate::matrix::kernels::{gemm_row_major, row_major_values};
+use crate::{BasicOperationsTrait, MaybeSendSyncTrait, Position};
+
+fn gemm_with_transposition<V, A, B, C>(
+    alpha: V,
+    a: &A,
+    a_transposed: bool,
+    b: &B,
+    b_transposed: bool,
+    beta: V,
+    c: &mut C,
+) -> Result<(), String>
+where
+    V: Copy + PartialEq + AddAssign + Mul<Output = V> + From<f32> + MaybeSendSyncTrait,
+    A: BasicOperationsTrait<Value = V>,
+    B: BasicOperationsTrait<Value = V>,
+    C: BasicOperationsTrait<Value = V>,
+{
+    let (a_rows_number, a_columns_number) = (a.get_shape().0, a.get_shape().1);
+    let (b_rows_number, b_columns_number) = (b.get_shape().0, b.get_shape().1);
+    let (rows_number, inner) = if a_transposed {
+        (a_columns_number, a_rows_number)
+    } else {
+        (a_rows_number, a_columns_number)
+    };
+    let (b_inner, columns_number) = if b_transposed {
+        (b_columns_number, b_rows_number)
+    } else {
+        (b_rows_number, b_columns_number)
+    };
+    if inner != b_inner || c.get_shape().0 != rows_number || c.get_shape().1 != columns_number {
+        return Err("Shapes of matrices do not conform to each other!".to_string());
+    }
+
+    let product = gemm_row_major(
+        &row_major_values(a),
+        a_transposed,
+        &row_major_values(b),
+        b_transposed,
+        rows_number,
+        inner,
+        columns_number,
+    );
+
+    let zero = V::from(0f32);
+    let c_values = if beta == zero {
+        None
+    } else {
+        Some(row_major_values(c))
+    };
+    for (i, p) in product.into_iter().enumerate() {
+        let mut value = alpha * p;
+        if let Some(c_values) = &c_values {
+            value += beta * c_values[i];
+        }
+        c.get_mut_elements()
+            .insert(Position(i / columns_number, i % columns_number), value);
+    }
+
+    Ok(())
+}
+
+/// `C <- alpha * A * B + beta * C`.
+pub fn gemm<V, A, B, C>(alpha: V, a: &A, b: &B, beta: V, c: &mut C) -> Result<(), String>
+where
+    V: Copy + PartialEq + AddAssign + Mul<Output = V> + From<f32> + MaybeSendSyncTrait,
+    A: BasicOperationsTrait<Value = V>,
+    B: BasicOperationsTrait<Value = V>,
+    C: BasicOperationsTrait<Value = V>,
+{
+    gemm_with_transposition(alpha, a, false, b, false, beta, c)
+}
+
+/// `C <- alpha * Aᵀ * B + beta * C`.
+pub fn gemm_tn<V, A, B, C>(alpha: V, a: &A, b: &B, beta: V, c: &mut C) -> Result<(), String>
+where
+    V: Copy + PartialEq + AddAssign + Mul<Output = V> + From<f32> + MaybeSendSyncTrait,
+    A: BasicOperationsTrait<Value = V>,
+    B: BasicOperationsTrait<Value = V>,
+    C: BasicOperationsTrait<Value = V>,
+{
+    gemm_with_transposition(alpha, a, true, b, false, beta, c)
+}
+
+/// `C <- alpha * A * Bᵀ + beta * C`.
+pub fn gemm_nt<V, A, B, C>(alpha: V, a: &A, b: &B, beta: V, c: &mut C) -> Result<(), String>
+where
+    V: Copy + PartialEq + AddAssign + Mul<Output = V> + From<f32> + MaybeSendSyncTrait,
+    A: BasicOperationsTrait<Value = V>,
+    B: BasicOperationsTrait<Value = V>,
+    C: BasicOperationsTrait<Value = V>,
+{
+    gemm_with_transposition(alpha, a, false, b, true, beta, c)
+}
@@ -0,0 +1,150 @@
+//! Incomplete Cholesky factorization IC(0), `A ≈ L Lᵀ` on the pattern of the lower triangle of a
+//! symmetric positive definite `CsrMatrix`, used as a preconditioner.
+//!
+//! Only the lower triangle of each row (`j <= i`) is read. Row `i` of `L` is computed from the rows
+//! above it with sparse dot products, and every entry outside the pattern of `A` is dropped. The
+//! factorization can break down on SPD matrices that are not diagonally dominant (an M-matrix
+//! never does); a non-positive pivot is reported as an error.
+
+// external imports
+use std::cmp::Ordering;
+
+use crate::{CsrMatrix, FloatTrait, PreconditionerTrait};
+
+#[derive(Clone, Debug)]
+pub struct IncompleteCholesky<V> {
+    n: usize,
+    // Rows of L with the columns sorted, the diagonal last.
+    row_ptr: Vec<usize>,
+    col_index: Vec<usize>,
+    values: Vec<V>,
+}
+
+impl<V> IncompleteCholesky<V>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    pub fn ic0(a: &CsrMatrix<V>) -> Result<Self, String> {
+        if a.get_n_rows() != a.get_n_cols() {
+            return Err(format!(
+                "IncompleteCholesky: matrix is not square: A is {}x{}",
+                a.get_n_rows(),
+                a.get_n_cols()
+            ));
+        }
+        let n = a.get_n_rows();
+        let (a_row_ptr, a_col_index, a_values) =
+            (a.get_row_ptr(), a.get_col_index(), a.get_values());
+
+        let mut row_ptr = vec![0usize];
+        let mut col_index: Vec<usize> = Vec::new();
+        let mut values: Vec<V> = Vec::new();
+        for i in 0..n {
+            let mut row: Vec<(usize, V)> = (a_row_ptr[i]..a_row_ptr[i + 1])
+                .filter(|&idx| a_col_index[idx] <= i)
+                .map(|idx| (a_col_index[idx], a_values[idx]))
+                .collect();
+            row.sort_by_key(|&(j, _)| j);
+            if row.last().map(|&(j, _)| j) != Some(i) {
+                return Err(format!("IncompleteCholesky: missing diagonal in row {}", i));
+            }
+
+            let start = col_index.len();
+            for &(j, a_ij) in &row {
+                // Dot product of the computed part of row i with row j, both sorted by column.
+                let mut sum = a_ij;
+                let (mut p, mut q) = (start, row_ptr[j]);
+                let q_end = if j < i { row_ptr[j + 1] - 1 } else { q };
+                while p < col_index.len() && q < q_end {
+                    match col_index[p].cmp(&col_index[q]) {
+                        Ordering::Less => p += 1,
+                        Ordering::Greater => q += 1,
+                        Ordering::Equal => {
+                            sum -= values[p] * values[q];
+                            p += 1;
+                            q += 1;
+                        }
+                    }
+                }
+                if j < i {
+                    col_index.push(j);
+                    values.push(sum / values[row_ptr[j + 1] - 1]);
+                } else {
+                    let mut diagonal = sum;
+                    for value in &values[start..] {
+                        diagonal -= *value * *value;
+                    }
+                    if diagonal.my_is_nan() || diagonal <= V::from(0f32) {
+                        return Err(format!(
+                            "IncompleteCholesky: non-positive pivot in row {}",
+                            i
+                        ));
+                    }
+                    col_index.push(i);
+                    values.push(diagonal.my_sqrt());
+                }
+            }
+            row_ptr.push(col_index.len());
+        }
+        Ok(IncompleteCholesky {
+            n,
+            row_ptr,
+            col_index,
+            values,
+        })
+    }
+
+    pub fn get_order(&self) -> usize {
+        self.n
+    }
+
+    /// Number of stored entries of `L`, diagonal included.
+    pub fn get_nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Solves `L Lᵀ x = r`.
+    pub fn solve(&self, r: &[V]) -> Result<Vec<V>, String> {
+        if r.len() != self.n {
+            return Err(format!(
+                "IncompleteCholesky: dimension mismatch: M is {}x{}, r has len {}",
+                self.n,
+                self.n,
+                r.len()
+            ));
+        }
+        let mut x = r.to_vec();
+        for i in 0..self.n {
+            let diagonal = self.row_ptr[i + 1] - 1;
+            for p in self.row_ptr[i]..diagonal {
+                let update = self.values[p] * x[self.col_index[p]];
+                x[i] -= update;
+            }
+            x[i] = x[i] / self.values[diagonal];
+        }
+        for i in (0..self.n).rev() {
+            let diagonal = self.row_ptr[i + 1] - 1;
+            x[i] = x[i] / self.values[diagonal];
+            for p in self.row_ptr[i]..diagonal {
+                let update = self.values[p] * x[i];
+                x[self.col_index[p]] -= update;
+            }
+        }
+        Ok(x)
+    }
+}
+
+/// Panics if `r` does not match the order of the factorization.
+impl<V> PreconditionerTrait<V> for IncompleteCholesky<V>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    fn apply(&self, r: &[V]) -> Vec<V> {
+        self.solve(r)
+            .expect("Dimensions must match the factorized matrix")
+    }
+
+    fn get_order(&self) -> Option<usize> {
+        Some(self.n)
+    }
+}
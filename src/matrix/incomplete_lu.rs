@@ -0,0 +1,264 @@
+//! Incomplete LU factorizations `A ≈ L U` of a square `CsrMatrix`, used as preconditioners.
+//!
+//! All variants run the row-wise (IKJ) Gaussian elimination without pivoting and differ only in
+//! which entries of the factors they keep:
+//!
+//! - ILU(0): the pattern of `A`.
+//! - ILU(k): entries of fill level up to `k`, where entries of `A` have level 0 and an update
+//!   through pivot `j` creates level `level(i, j) + level(j, m) + 1`. The pattern depends only on
+//!   the pattern of `A`.
+//! - ILUT(τ, p): entries larger than `τ` times the norm of their row of `A`, at most `p` of them
+//!   per row in each factor (the diagonal is always kept). The pattern depends on the values.
+//!
+//! `L` (unit diagonal implied) and the strictly upper part of `U` are stored by rows, the diagonal
+//! of `U` separately. A zero pivot is reported as an error; no pivoting or diagonal shift is done.
+
+// external imports
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+
+use crate::{CsrMatrix, FloatTrait, PreconditionerTrait};
+
+#[derive(Clone, Debug)]
+pub struct IncompleteLu<V> {
+    n: usize,
+    l_row_ptr: Vec<usize>,
+    l_col_index: Vec<usize>,
+    l_values: Vec<V>,
+    u_row_ptr: Vec<usize>,
+    u_col_index: Vec<usize>,
+    u_values: Vec<V>,
+    u_diagonal: Vec<V>,
+}
+
+fn check_square<V>(a: &CsrMatrix<V>) -> Result<(), String>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    if a.get_n_rows() != a.get_n_cols() {
+        return Err(format!(
+            "IncompleteLu: matrix is not square: A is {}x{}",
+            a.get_n_rows(),
+            a.get_n_cols()
+        ));
+    }
+    Ok(())
+}
+
+impl<V> IncompleteLu<V>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    fn empty(n: usize) -> Self {
+        IncompleteLu {
+            n,
+            l_row_ptr: vec![0],
+            l_col_index: Vec::new(),
+            l_values: Vec::new(),
+            u_row_ptr: vec![0],
+            u_col_index: Vec::new(),
+            u_values: Vec::new(),
+            u_diagonal: Vec::with_capacity(n),
+        }
+    }
+
+    /// Appends row `i` of the factors from the eliminated work row `w` restricted to `columns`
+    /// (sorted).
+    fn push_row(&mut self, i: usize, columns: &[usize], w: &[V]) -> Result<(), String> {
+        let mut diagonal = V::from(0f32);
+        for &j in columns {
+            if j < i {
+                self.l_col_index.push(j);
+                self.l_values.push(w[j]);
+            } else if j == i {
+                diagonal = w[j];
+            } else {
+                self.u_col_index.push(j);
+                self.u_values.push(w[j]);
+            }
+        }
+        if diagonal == V::from(0f32) || diagonal.my_is_nan() {
+            return Err(format!("IncompleteLu: zero pivot in row {}", i));
+        }
+        self.u_diagonal.push(diagonal);
+        self.l_row_ptr.push(self.l_col_index.len());
+        self.u_row_ptr.push(self.u_col_index.len());
+        Ok(())
+    }
+
+    /// ILU(0): no fill outside the pattern of `a`.
+    pub fn ilu0(a: &CsrMatrix<V>) -> Result<Self, String> {
+        Self::iluk(a, 0)
+    }
+
+    /// ILU(k): fill up to level `level`.
+    pub fn iluk(a: &CsrMatrix<V>, level: usize) -> Result<Self, String> {
+        check_square(a)?;
+        let n = a.get_n_rows();
+        let (row_ptr, col_index, values) = (a.get_row_ptr(), a.get_col_index(), a.get_values());
+
+        let mut factors = Self::empty(n);
+        // Fill level of every stored entry of U, laid out as `u_col_index`.
+        let mut u_levels: Vec<usize> = Vec::new();
+        let mut w = vec![V::from(0f32); n];
+        let mut row_level = vec![usize::MAX; n];
+        for i in 0..n {
+            let mut pattern = BTreeSet::new();
+            for idx in row_ptr[i]..row_ptr[i + 1] {
+                let j = col_index[idx];
+                w[j] += values[idx];
+                row_level[j] = 0;
+                pattern.insert(j);
+            }
+
+            let mut next = pattern.range(..i).next().copied();
+            while let Some(j) = next {
+                let l_ij = w[j] / factors.u_diagonal[j];
+                w[j] = l_ij;
+                let row = factors.u_row_ptr[j]..factors.u_row_ptr[j + 1];
+                for (p, &u_level) in row.clone().zip(&u_levels[row]) {
+                    let m = factors.u_col_index[p];
+                    let fill_level = row_level[j] + u_level + 1;
+                    if row_level[m] == usize::MAX {
+                        if fill_level > level {
+                            continue;
+                        }
+                        pattern.insert(m);
+                        row_level[m] = fill_level;
+                    } else if fill_level < row_level[m] {
+                        row_level[m] = fill_level;
+                    }
+                    let update = l_ij * factors.u_values[p];
+                    w[m] -= update;
+                }
+                next = pattern.range(j + 1..i).next().copied();
+            }
+
+            let columns: Vec<usize> = pattern.into_iter().collect();
+            factors.push_row(i, &columns, &w)?;
+            for &j in &columns {
+                if j > i {
+                    u_levels.push(row_level[j]);
+                }
+                w[j] = V::from(0f32);
+                row_level[j] = usize::MAX;
+            }
+        }
+        Ok(factors)
+    }
+
+    /// ILUT: drops entries below `drop_tolerance` times the 2-norm of their row of `a` and keeps
+    /// at most `max_fill` of the largest entries per row in each factor.
+    pub fn ilut(a: &CsrMatrix<V>, drop_tolerance: V, max_fill: usize) -> Result<Self, String> {
+        check_square(a)?;
+        let n = a.get_n_rows();
+        let (row_ptr, col_index, values) = (a.get_row_ptr(), a.get_col_index(), a.get_values());
+
+        let mut factors = Self::empty(n);
+        let mut w = vec![V::from(0f32); n];
+        for i in 0..n {
+            let mut pattern = BTreeSet::new();
+            pattern.insert(i);
+            let mut row_norm = V::from(0f32);
+            for idx in row_ptr[i]..row_ptr[i + 1] {
+                let j = col_index[idx];
+                w[j] += values[idx];
+                row_norm += values[idx] * values[idx];
+                pattern.insert(j);
+            }
+            let tolerance = drop_tolerance * row_norm.my_sqrt();
+
+            let mut next = pattern.range(..i).next().copied();
+            while let Some(j) = next {
+                let l_ij = w[j] / factors.u_diagonal[j];
+                w[j] = l_ij;
+                if l_ij.my_abs() >= tolerance {
+                    for p in factors.u_row_ptr[j]..factors.u_row_ptr[j + 1] {
+                        let m = factors.u_col_index[p];
+                        pattern.insert(m);
+                        let update = l_ij * factors.u_values[p];
+                        w[m] -= update;
+                    }
+                }
+                next = pattern.range(j + 1..i).next().copied();
+            }
+
+            // Keep the largest entries above the tolerance in each factor, then restore the column
+            // order.
+            let largest = |columns: Vec<usize>| {
+                let mut kept: Vec<usize> = columns
+                    .into_iter()
+                    .filter(|&j| w[j].my_abs() >= tolerance && w[j] != V::from(0f32))
+                    .collect();
+                kept.sort_by(|&j, &m| {
+                    w[m].my_abs()
+                        .partial_cmp(&w[j].my_abs())
+                        .unwrap_or(Ordering::Equal)
+                        .then(j.cmp(&m))
+                });
+                kept.truncate(max_fill);
+                kept
+            };
+            let lower = largest(pattern.range(..i).copied().collect());
+            let upper = largest(pattern.range(i + 1..).copied().collect());
+            let mut columns: Vec<usize> = lower.into_iter().chain([i]).chain(upper).collect();
+            columns.sort_unstable();
+            factors.push_row(i, &columns, &w)?;
+            for j in pattern {
+                w[j] = V::from(0f32);
+            }
+        }
+        Ok(factors)
+    }
+
+    pub fn get_order(&self) -> usize {
+        self.n
+    }
+
+    /// Number of stored entries of `L` and `U` (the unit diagonal of `L` not included).
+    pub fn get_nnz(&self) -> usize {
+        self.l_values.len() + self.u_values.len() + self.u_diagonal.len()
+    }
+
+    /// Solves `L U x = r`.
+    pub fn solve(&self, r: &[V]) -> Result<Vec<V>, String> {
+        if r.len() != self.n {
+            return Err(format!(
+                "IncompleteLu: dimension mismatch: M is {}x{}, r has len {}",
+                self.n,
+                self.n,
+                r.len()
+            ));
+        }
+        let mut x = r.to_vec();
+        for i in 0..self.n {
+            for p in self.l_row_ptr[i]..self.l_row_ptr[i + 1] {
+                let update = self.l_values[p] * x[self.l_col_index[p]];
+                x[i] -= update;
+            }
+        }
+        for i in (0..self.n).rev() {
+            for p in self.u_row_ptr[i]..self.u_row_ptr[i + 1] {
+                let update = self.u_values[p] * x[self.u_col_index[p]];
+                x[i] -= update;
+            }
+            x[i] = x[i] / self.u_diagonal[i];
+        }
+        Ok(x)
+    }
+}
+
+/// Panics if `r` does not match the order of the factorization.
+impl<V> PreconditionerTrait<V> for IncompleteLu<V>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    fn apply(&self, r: &[V]) -> Vec<V> {
+        self.solve(r)
+            .expect("Dimensions must match the factorized matrix")
+    }
+
+    fn get_order(&self) -> Option<usize> {
+        Some(self.n)
+    }
+}
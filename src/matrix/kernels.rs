@@ -0,0 +1,215 @@
+//! Dense row-major kernels shared by the `HashMap`-backed matrix types.
+//!
+//! `Matrix`, `SquareMatrix` and friends keep their elements in a `HashMap<Position, V>`, which is
+//! easy to inspect but slow to walk in the inner loops of products and eliminations. The helpers
+//! here work on contiguous row-major buffers instead.
+//!
+//! With the `rayon` feature the outer row loops run on the rayon thread pool. Each output entry is
+//! still produced by exactly the same sequence of operations as in the serial build, so results
+//! are bit-identical whichever path is compiled.
+
+// external imports
+use std::collections::HashMap;
+use std::ops::{AddAssign, Mul};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{BasicOperationsTrait, MaybeSendSyncTrait, Position};
+
+/// Number of result rows handed to a single task of the tiled product.
+const ROW_BLOCK: usize = 32;
+/// Number of result columns in a tile.
+const COLUMN_BLOCK: usize = 256;
+/// Number of inner-dimension entries in a tile.
+const INNER_BLOCK: usize = 128;
+
+/// Collects the elements of `m` into a row-major buffer.
+///
+/// Panics with "Element is absent" when `m` does not store every position of its shape.
+pub(crate) fn row_major_values<M>(m: &M) -> Vec<M::Value>
+where
+    M: BasicOperationsTrait + ?Sized,
+    M::Value: Copy,
+{
+    let (rows_number, columns_number) = (m.get_shape().0, m.get_shape().1);
+    let mut values = Vec::with_capacity(rows_number * columns_number);
+    for row in 0..rows_number {
+        for column in 0..columns_number {
+            values.push(
+                *m.get_element_value(&Position(row, column))
+                    .expect("Element is absent"),
+            );
+        }
+    }
+    values
+}
+
+/// Calls `f(chunk_index, chunk)` for every `chunk_len`-sized chunk of `data`.
+pub(crate) fn for_each_chunk_mut<V, F>(data: &mut [V], chunk_len: usize, f: F)
+where
+    V: MaybeSendSyncTrait,
+    F: Fn(usize, &mut [V]) + MaybeSendSyncTrait,
+{
+    if chunk_len == 0 {
+        return;
+    }
+
+    #[cfg(feature = "rayon")]
+    data.par_chunks_mut(chunk_len)
+        .enumerate()
+        .for_each(|(index, chunk)| f(index, chunk));
+
+    #[cfg(not(feature = "rayon"))]
+    data.chunks_mut(chunk_len)
+        .enumerate()
+        .for_each(|(index, chunk)| f(index, chunk));
+}
+
+/// Calls `f(column)` for every column of the row-major `rows_number x columns_number` buffer
+/// `values`, handing each column over as a contiguous slice.
+///
+/// The columns are gathered into a column-major copy first, so with `rayon` every column is its
+/// own task.
+pub(crate) fn for_each_column_mut<V, F>(values: &mut [V], rows_number: usize, f: F)
+where
+    V: Copy + MaybeSendSyncTrait,
+    F: Fn(&mut [V]) + MaybeSendSyncTrait,
+{
+    if rows_number == 0 {
+        return;
+    }
+    let columns_number = values.len() / rows_number;
+    let mut columns = Vec::with_capacity(values.len());
+    for column in 0..columns_number {
+        for row in 0..rows_number {
+            columns.push(values[row * columns_number + column]);
+        }
+    }
+    for_each_chunk_mut(&mut columns, rows_number, |_, column| f(column));
+    for (i, value) in columns.into_iter().enumerate() {
+        values[(i % rows_number) * columns_number + i / rows_number] = value;
+    }
+}
+
+/// Calls `f(value)` for every element value.
+pub(crate) fn for_each_value_mut<V, F>(elements: &mut HashMap<Position, V>, f: F)
+where
+    V: MaybeSendSyncTrait,
+    F: Fn(&mut V) + MaybeSendSyncTrait,
+{
+    #[cfg(feature = "rayon")]
+    elements.par_iter_mut().for_each(|(_, value)| f(value));
+
+    #[cfg(not(feature = "rayon"))]
+    elements.values_mut().for_each(f);
+}
+
+/// Calls `f(value, other_value)` for every position of `other`.
+///
+/// Panics with "Element is absent" when `other` holds a position `elements` does not have.
+pub(crate) fn zip_values_mut<V, F>(
+    elements: &mut HashMap<Position, V>,
+    other: &HashMap<Position, V>,
+    f: F,
+) where
+    V: Copy + MaybeSendSyncTrait,
+    F: Fn(&mut V, V) + MaybeSendSyncTrait,
+{
+    #[cfg(feature = "rayon")]
+    {
+        if other
+            .keys()
+            .any(|position| !elements.contains_key(position))
+        {
+            panic!("Element is absent");
+        }
+        elements.par_iter_mut().for_each(|(position, value)| {
+            if let Some(other_value) = other.get(position) {
+                f(value, *other_value);
+            }
+        });
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    for (position, other_value) in other {
+        f(
+            elements.get_mut(position).expect("Element is absent"),
+            *other_value,
+        );
+    }
+}
+
+/// Tiled row-major product `op(lhs) * op(rhs)`.
+///
+/// `op(lhs)` is `rows_number x inner` and `op(rhs)` is `inner x columns_number`. When
+/// `lhs_transposed` is set, `lhs` holds the `inner x rows_number` buffer and is read column-wise;
+/// when `rhs_transposed` is set, `rhs` holds the `columns_number x inner` buffer and each tile is
+/// packed into a small row-major panel, so the transpose is never materialized.
+///
+/// The result is split into blocks of `ROW_BLOCK` rows (one task each with `rayon`), and every
+/// block is walked tile by tile (`COLUMN_BLOCK x INNER_BLOCK`). The innermost loop is an `axpy` over
+/// contiguous slices that the compiler can vectorize. Every result entry starts at zero and
+/// accumulates `op(lhs)[i][k] * op(rhs)[k][j]` for ascending `k`, i.e. in the same order as the
+/// textbook triple loop.
+pub(crate) fn gemm_row_major<V>(
+    lhs: &[V],
+    lhs_transposed: bool,
+    rhs: &[V],
+    rhs_transposed: bool,
+    rows_number: usize,
+    inner: usize,
+    columns_number: usize,
+) -> Vec<V>
+where
+    V: Copy + AddAssign + Mul<Output = V> + From<f32> + MaybeSendSyncTrait,
+{
+    let mut result = vec![V::from(0f32); rows_number * columns_number];
+
+    let lhs_value = |i: usize, k: usize| {
+        if lhs_transposed {
+            lhs[k * rows_number + i]
+        } else {
+            lhs[i * inner + k]
+        }
+    };
+
+    for_each_chunk_mut(
+        &mut result,
+        ROW_BLOCK * columns_number,
+        |block, result_rows| {
+            let first_row = block * ROW_BLOCK;
+            let mut panel = Vec::new();
+            for j_start in (0..columns_number).step_by(COLUMN_BLOCK) {
+                let j_end = (j_start + COLUMN_BLOCK).min(columns_number);
+                let width = j_end - j_start;
+                for k_start in (0..inner).step_by(INNER_BLOCK) {
+                    let k_end = (k_start + INNER_BLOCK).min(inner);
+                    if rhs_transposed {
+                        panel.clear();
+                        for k in k_start..k_end {
+                            panel.extend((j_start..j_end).map(|j| rhs[j * inner + k]));
+                        }
+                    }
+                    for (r, result_row) in result_rows.chunks_mut(columns_number).enumerate() {
+                        let i = first_row + r;
+                        let result_tile = &mut result_row[j_start..j_end];
+                        for k in k_start..k_end {
+                            let a_ik = lhs_value(i, k);
+                            let rhs_tile = if rhs_transposed {
+                                &panel[(k - k_start) * width..(k - k_start + 1) * width]
+                            } else {
+                                &rhs[k * columns_number + j_start..k * columns_number + j_end]
+                            };
+                            for (result_value, rhs_value) in result_tile.iter_mut().zip(rhs_tile) {
+                                *result_value += a_ik * *rhs_value;
+                            }
+                        }
+                    }
+                }
+            }
+        },
+    );
+
+    result
+}
@@ -0,0 +1,238 @@
+//! Preconditioned Krylov solvers for `A x = b`.
+//!
+//! `A` is only used through `LinearOperatorTrait::apply`, so it can be a `CsrMatrix`, a dense
+//! matrix or a matrix-free `ClosureOperator`.
+//!
+//! - `conjugate_gradient`: symmetric positive definite `A` and preconditioner.
+//! - `bicgstab`: general square `A`, preconditioned from the right, so the residual that is
+//!   checked is the one of the original system.
+//!
+//! Both start from `x = 0`, stop once the updated residual satisfies `‖r‖₂ <= rel_tol * ‖b‖₂`
+//! (`r = b - A x` up to rounding) and report the iterations taken in a `ConvergenceInfo`. Running
+//! out of iterations or a breakdown is an error, as in the other iterative routines of the crate.
+//! So is a preconditioner whose order or output length does not match `b`.
+
+use crate::{ConvergenceInfo, FloatTrait, LinearOperatorTrait, PreconditionerTrait};
+
+fn dot<V>(x: &[V], y: &[V]) -> V
+where
+    V: FloatTrait<Output = V>,
+{
+    x.iter()
+        .zip(y)
+        .fold(V::from(0f32), |sum, (a, b)| sum + *a * *b)
+}
+
+fn norm<V>(x: &[V]) -> V
+where
+    V: FloatTrait<Output = V>,
+{
+    dot(x, x).my_sqrt()
+}
+
+fn check_system<V, A>(a: &A, b: &[V], solver: &str) -> Result<(), String>
+where
+    A: LinearOperatorTrait<V> + ?Sized,
+{
+    let (rows_number, columns_number) = a.get_dimensions();
+    if rows_number != columns_number || b.len() != rows_number {
+        return Err(format!(
+            "{}: dimension mismatch: A is {}x{}, b has len {}",
+            solver,
+            rows_number,
+            columns_number,
+            b.len()
+        ));
+    }
+    Ok(())
+}
+
+/// `M⁻¹ r`, or `Err` if the preconditioner does not fit the system.
+fn precondition<V, P>(preconditioner: &P, r: &[V], solver: &str) -> Result<Vec<V>, String>
+where
+    P: PreconditionerTrait<V> + ?Sized,
+{
+    if let Some(order) = preconditioner.get_order()
+        && order != r.len()
+    {
+        return Err(format!(
+            "{}: dimension mismatch: preconditioner is of order {}, b has len {}",
+            solver,
+            order,
+            r.len()
+        ));
+    }
+    let z = preconditioner.apply(r);
+    if z.len() != r.len() {
+        return Err(format!(
+            "{}: dimension mismatch: preconditioner returned {} components, b has len {}",
+            solver,
+            z.len(),
+            r.len()
+        ));
+    }
+    Ok(z)
+}
+
+/// Preconditioned conjugate gradient.
+pub fn conjugate_gradient<V, A, P>(
+    a: &A,
+    b: &[V],
+    preconditioner: &P,
+    rel_tol: V,
+    max_iterations: usize,
+) -> Result<(Vec<V>, ConvergenceInfo<V>), String>
+where
+    V: FloatTrait<Output = V>,
+    A: LinearOperatorTrait<V> + ?Sized,
+    P: PreconditionerTrait<V> + ?Sized,
+{
+    check_system(a, b, "Conjugate gradient")?;
+    let zero = V::from(0f32);
+    let mut x = vec![zero; b.len()];
+    let b_norm = norm(b);
+    if b_norm == zero {
+        return Ok((
+            x,
+            ConvergenceInfo {
+                iterations: 0,
+                relative_residual: zero,
+            },
+        ));
+    }
+
+    let mut r = b.to_vec();
+    let mut z = precondition(preconditioner, &r, "Conjugate gradient")?;
+    let mut p = z.clone();
+    let mut rz = dot(&r, &z);
+    for iteration in 1..=max_iterations {
+        let q = a.apply(&p)?;
+        let pq = dot(&p, &q);
+        if pq.my_is_nan() || pq <= zero {
+            return Err("Conjugate gradient: matrix is not positive definite!".to_string());
+        }
+        let alpha = rz / pq;
+        for i in 0..x.len() {
+            x[i] += alpha * p[i];
+            r[i] -= alpha * q[i];
+        }
+        let relative_residual = norm(&r) / b_norm;
+        if relative_residual <= rel_tol {
+            return Ok((
+                x,
+                ConvergenceInfo {
+                    iterations: iteration,
+                    relative_residual,
+                },
+            ));
+        }
+        z = precondition(preconditioner, &r, "Conjugate gradient")?;
+        let rz_new = dot(&r, &z);
+        let beta = rz_new / rz;
+        rz = rz_new;
+        for i in 0..p.len() {
+            p[i] = z[i] + beta * p[i];
+        }
+    }
+
+    Err(format!(
+        "Conjugate gradient: no convergence within {max_iterations} iterations!"
+    ))
+}
+
+/// Right-preconditioned BiCGSTAB.
+pub fn bicgstab<V, A, P>(
+    a: &A,
+    b: &[V],
+    preconditioner: &P,
+    rel_tol: V,
+    max_iterations: usize,
+) -> Result<(Vec<V>, ConvergenceInfo<V>), String>
+where
+    V: FloatTrait<Output = V>,
+    A: LinearOperatorTrait<V> + ?Sized,
+    P: PreconditionerTrait<V> + ?Sized,
+{
+    check_system(a, b, "BiCGSTAB")?;
+    let zero = V::from(0f32);
+    let n = b.len();
+    let mut x = vec![zero; n];
+    let b_norm = norm(b);
+    if b_norm == zero {
+        return Ok((
+            x,
+            ConvergenceInfo {
+                iterations: 0,
+                relative_residual: zero,
+            },
+        ));
+    }
+
+    let mut r = b.to_vec();
+    let r_hat = r.clone();
+    let (mut rho, mut alpha, mut omega) = (V::from(1f32), V::from(1f32), V::from(1f32));
+    let mut v = vec![zero; n];
+    let mut p = vec![zero; n];
+    for iteration in 1..=max_iterations {
+        let rho_new = dot(&r_hat, &r);
+        if rho_new == zero || rho_new.my_is_nan() {
+            return Err("BiCGSTAB: breakdown (rho = 0)!".to_string());
+        }
+        let beta = (rho_new / rho) * (alpha / omega);
+        rho = rho_new;
+        for i in 0..n {
+            p[i] = r[i] + beta * (p[i] - omega * v[i]);
+        }
+        let p_hat = precondition(preconditioner, &p, "BiCGSTAB")?;
+        v = a.apply(&p_hat)?;
+        let r_hat_v = dot(&r_hat, &v);
+        if r_hat_v == zero || r_hat_v.my_is_nan() {
+            return Err("BiCGSTAB: breakdown (r_hat · v = 0)!".to_string());
+        }
+        alpha = rho / r_hat_v;
+
+        let s: Vec<V> = r
+            .iter()
+            .zip(&v)
+            .map(|(r_i, v_i)| *r_i - alpha * *v_i)
+            .collect();
+        let relative_residual = norm(&s) / b_norm;
+        if relative_residual <= rel_tol {
+            for i in 0..n {
+                x[i] += alpha * p_hat[i];
+            }
+            return Ok((
+                x,
+                ConvergenceInfo {
+                    iterations: iteration,
+                    relative_residual,
+                },
+            ));
+        }
+
+        let s_hat = precondition(preconditioner, &s, "BiCGSTAB")?;
+        let t = a.apply(&s_hat)?;
+        omega = dot(&t, &s) / dot(&t, &t);
+        if omega == zero || omega.my_is_nan() {
+            return Err("BiCGSTAB: breakdown (omega = 0)!".to_string());
+        }
+        for i in 0..n {
+            x[i] += alpha * p_hat[i] + omega * s_hat[i];
+            r[i] = s[i] - omega * t[i];
+        }
+        let relative_residual = norm(&r) / b_norm;
+        if relative_residual <= rel_tol {
+            return Ok((
+                x,
+                ConvergenceInfo {
+                    iterations: iteration,
+                    relative_residual,
+                },
+            ));
+        }
+    }
+
+    Err(format!(
+        "BiCGSTAB: no convergence within {max_iterations} iterations!"
+    ))
+}
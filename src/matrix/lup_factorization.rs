@@ -0,0 +1,246 @@
+//! Reusable LUP factors of a square matrix.
+//!
+//! `SquareMatrixTrait::lup_factorization` runs the elimination once; the factors can then solve
+//! any number of right-hand sides (with `A` or with `Aᴴ`, one by one or as the columns of a
+//! matrix), estimate `‖A⁻¹‖₁` without ever forming the inverse, and give the determinant (also as
+//! sign and `log|det|`, which does not overflow for large stiffness matrices).
+//!
+//! Storage follows the elimination in `SquareMatrixTrait`: rows stay in their original physical
+//! order and the row `Permutation` `p` records the pivot order, so row `p[i]` of `lu` holds row `i`
+//! of `L` (strictly below the diagonal, unit diagonal implied) and of `U` (on and above the
+//! diagonal): `P * A = L * U`.
+
+use crate::matrix::kernels::{for_each_column_mut, row_major_values};
+use crate::{BasicOperationsTrait, FieldTrait, FloatTrait, Matrix, Permutation, Shape};
+
+#[derive(Clone, Debug)]
+pub struct LupFactorization<V> {
+    pub(crate) n: usize,
+    pub(crate) lu: Vec<V>,
+    pub(crate) permutation: Permutation,
+}
+
+impl<V> LupFactorization<V>
+where
+    V: FieldTrait,
+{
+    pub fn get_order(&self) -> usize {
+        self.n
+    }
+
+    /// Row-major `n x n` buffer with `L` and `U` packed in the physical rows.
+    pub fn get_lu(&self) -> &[V] {
+        &self.lu
+    }
+
+    /// `get_pivot_order()[i]` is the physical row that became row `i` of `L` and `U`.
+    pub fn get_pivot_order(&self) -> &[usize] {
+        self.permutation.get_indices()
+    }
+
+    /// Row permutation `P` with `P * A = L * U`.
+    pub fn get_permutation(&self) -> &Permutation {
+        &self.permutation
+    }
+
+    /// Physical row of factor row `i`.
+    fn row(&self, i: usize) -> usize {
+        self.permutation.get_indices()[i]
+    }
+
+    /// Entry `(i, j)` of `L` (for `j < i`) or `U` (for `j >= i`).
+    fn factor(&self, i: usize, j: usize) -> V {
+        self.lu[self.row(i) * self.n + j]
+    }
+
+    /// Product of the pivots of `U`, with the sign of the row permutation.
+    pub fn determinant(&self) -> V {
+        let mut det = V::from(self.permutation.sign() as f32);
+        for i in 0..self.n {
+            det *= self.factor(i, i);
+        }
+        det
+    }
+
+    /// `(sign, log|det|)` with `det = sign * exp(log|det|)`.
+    ///
+    /// `sign` has unit modulus (`±1` for real types, a phase for complex ones) unless a pivot is
+    /// exactly zero, in which case it is zero and `log|det|` is `-∞`.
+    pub fn log_determinant(&self) -> (V, V::Real)
+    where
+        V::Real: FloatTrait<Output = V::Real>,
+    {
+        let zero = V::Real::from(0f32);
+        let mut sign = V::from(self.permutation.sign() as f32);
+        let mut log_modulus = zero;
+        for i in 0..self.n {
+            let pivot = self.factor(i, i);
+            let modulus = pivot.modulus();
+            if modulus == zero {
+                return (V::from(0f32), V::Real::from_f64(f64::NEG_INFINITY));
+            }
+            sign *= pivot / V::from_real(modulus);
+            log_modulus += V::Real::from_f64(modulus.into().ln());
+        }
+        (sign, log_modulus)
+    }
+
+    fn rhs_conformity_check(&self, b: &[V]) -> Result<(), String> {
+        if b.len() != self.n {
+            return Err(format!(
+                "LupFactorization: dimension mismatch: A is {}x{}, b has len {}",
+                self.n,
+                self.n,
+                b.len()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Solves `A * x = b`.
+    pub fn solve(&self, b: &[V]) -> Result<Vec<V>, String> {
+        self.rhs_conformity_check(b)?;
+        let n = self.n;
+        let mut y = vec![V::from(0f32); n];
+        for i in 0..n {
+            let mut sum = b[self.row(i)];
+            for (j, &y_j) in y[..i].iter().enumerate() {
+                sum -= self.factor(i, j) * y_j;
+            }
+            y[i] = sum;
+        }
+        let mut x = vec![V::from(0f32); n];
+        for i in (0..n).rev() {
+            let mut sum = V::from(0f32);
+            for (j, &x_j) in x.iter().enumerate().skip(i + 1) {
+                sum += self.factor(i, j) * x_j;
+            }
+            x[i] = (y[i] - sum) / self.factor(i, i);
+        }
+        Ok(x)
+    }
+
+    /// Solves `A * X = B` for every column of `B` (one load case per column). With the `rayon`
+    /// feature the columns are solved in parallel.
+    pub fn solve_matrix<M>(&self, b: &M) -> Result<Matrix<V>, String>
+    where
+        M: BasicOperationsTrait<Value = V>,
+    {
+        let Shape(rows_number, columns_number) = *b.get_shape();
+        if rows_number != self.n {
+            return Err(format!(
+                "LupFactorization: dimension mismatch: A is {}x{}, B is {}x{}",
+                self.n, self.n, rows_number, columns_number
+            ));
+        }
+        let mut x = row_major_values(b);
+        self.solve_columns(&mut x);
+        Ok(Matrix::from_row_major(rows_number, columns_number, x))
+    }
+
+    /// Overwrites every column of the row-major `n x m` buffer `x` with the solution of
+    /// `A * x_j = x_j`.
+    pub(crate) fn solve_columns(&self, x: &mut [V]) {
+        for_each_column_mut(x, self.n, |column| {
+            let solution = self.solve(column).expect("Dimensions are checked");
+            column.copy_from_slice(&solution);
+        });
+    }
+
+    /// Solves `Aᴴ * x = b` (`Aᵀ * x = b` for real and exact types).
+    pub fn solve_adjoint(&self, b: &[V]) -> Result<Vec<V>, String> {
+        self.rhs_conformity_check(b)?;
+        let n = self.n;
+        // Aᴴ = Uᴴ Lᴴ P: forward substitution with Uᴴ, back substitution with Lᴴ, then undo P.
+        let mut w = vec![V::from(0f32); n];
+        for i in 0..n {
+            let mut sum = b[i];
+            for (j, &w_j) in w[..i].iter().enumerate() {
+                sum -= self.factor(j, i).conjugate() * w_j;
+            }
+            w[i] = sum / self.factor(i, i).conjugate();
+        }
+        let mut x = vec![V::from(0f32); n];
+        for i in (0..n).rev() {
+            let mut sum = w[i];
+            for j in i + 1..n {
+                sum -= self.factor(j, i).conjugate() * x[self.row(j)];
+            }
+            x[self.row(i)] = sum;
+        }
+        Ok(x)
+    }
+
+    /// Lower bound for `‖A⁻¹‖₁` by Hager's method with Higham's refinements.
+    ///
+    /// Each step costs one solve with `A` and one with `Aᴴ`; at most five steps are taken. The
+    /// result is finally compared with the norm of `A⁻¹` applied to an alternating-sign vector,
+    /// which guards against the known worst cases of the plain iteration. The estimate is exact
+    /// surprisingly often and rarely off by more than a factor of three.
+    pub fn inverse_norm_1_estimate(&self) -> V::Real {
+        let n = self.n;
+        let zero = V::Real::from(0f32);
+        if n == 0 {
+            return zero;
+        }
+        let norm_1 = |x: &[V]| x.iter().fold(zero, |sum, value| sum + value.modulus());
+
+        let mut x = vec![V::from(1f32) / V::from(n as f32); n];
+        let mut estimate = zero;
+        let mut previous_j = None;
+        for iteration in 0..5 {
+            let y = self.solve(&x).expect("Dimensions are checked");
+            let new_estimate = norm_1(&y);
+            if iteration > 0 && new_estimate <= estimate {
+                break;
+            }
+            estimate = new_estimate;
+
+            let signs = y
+                .iter()
+                .map(|value| {
+                    let modulus = value.modulus();
+                    if modulus == zero {
+                        V::from(1f32)
+                    } else {
+                        *value / V::from_real(modulus)
+                    }
+                })
+                .collect::<Vec<V>>();
+            let z = self.solve_adjoint(&signs).expect("Dimensions are checked");
+            let mut j = 0;
+            for i in 1..n {
+                if z[i].modulus() > z[j].modulus() {
+                    j = i;
+                }
+            }
+            if previous_j == Some(j) {
+                break;
+            }
+            previous_j = Some(j);
+            x = vec![V::from(0f32); n];
+            x[j] = V::from(1f32);
+        }
+
+        if n > 1 {
+            let alternating = (0..n)
+                .map(|i| {
+                    let value = V::from(1f32) + V::from(i as f32) / V::from((n - 1) as f32);
+                    if i % 2 == 0 {
+                        value
+                    } else {
+                        V::from(0f32) - value
+                    }
+                })
+                .collect::<Vec<V>>();
+            let y = self.solve(&alternating).expect("Dimensions are checked");
+            let alternative_estimate =
+                V::Real::from(2f32) * norm_1(&y) / V::Real::from(3f32) / V::Real::from(n as f32);
+            if alternative_estimate > estimate {
+                estimate = alternative_estimate;
+            }
+        }
+
+        estimate
+    }
+}
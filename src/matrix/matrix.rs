@@ -1,8 +1,13 @@
 // external imports
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::ops::{AddAssign, Mul};
 
-use crate::{BasicOperationsTrait, IntoMatrixTrait, TryIntoSquareMatrixTrait};
+use crate::matrix::traits::apply_elements;
+use crate::{
+    BasicOperationsTrait, IntoMatrixTrait, LinearOperatorTrait, MatrixNormTrait, PermuteTrait,
+    TryIntoSquareMatrixTrait,
+};
 use crate::{Position, Shape};
 
 #[derive(Debug, PartialEq, Clone)]
@@ -35,6 +40,28 @@ impl<V> IntoMatrixTrait for Matrix<V> {}
 
 impl<V> TryIntoSquareMatrixTrait for Matrix<V> {}
 
+impl<V> MatrixNormTrait for Matrix<V> {}
+
+impl<V> PermuteTrait for Matrix<V> {}
+
+/// Missing entries of a sparse storage count as zeros.
+impl<V> LinearOperatorTrait<V> for Matrix<V>
+where
+    V: Copy + From<f32> + AddAssign + Mul<Output = V>,
+{
+    fn get_dimensions(&self) -> (usize, usize) {
+        (self.shape.0, self.shape.1)
+    }
+
+    fn apply(&self, x: &[V]) -> Result<Vec<V>, String> {
+        apply_elements(&self.elements, self.get_dimensions(), x, false)
+    }
+
+    fn apply_transpose(&self, y: &[V]) -> Result<Vec<V>, String> {
+        apply_elements(&self.elements, self.get_dimensions(), y, true)
+    }
+}
+
 impl<V> Matrix<V>
 where
     V: Debug + Copy + From<f32>,
@@ -55,4 +82,40 @@ where
 
         Matrix { shape, elements }
     }
+
+    /// Block-diagonal matrix with `copies` copies of `self` on the diagonal.
+    ///
+    /// Expands a 3x3 direction cosine matrix into the 6x6 (truss: two nodes) or 12x12 (beam: two
+    /// nodes with translations and rotations) transformation `T` of an element, so that the
+    /// global stiffness is `Tᵀ K T`.
+    pub fn expand_block_diagonal(&self, copies: usize) -> Self {
+        let (rows_number, columns_number) = (self.shape.0, self.shape.1);
+        let mut result = Matrix::create(rows_number * copies, columns_number * copies, &[]);
+        for block in 0..copies {
+            for (Position(row, column), value) in self.elements.iter() {
+                result.elements.insert(
+                    Position(block * rows_number + row, block * columns_number + column),
+                    *value,
+                );
+            }
+        }
+        result
+    }
+}
+
+impl<V> Matrix<V> {
+    pub(crate) fn from_row_major(
+        rows_number: usize,
+        columns_number: usize,
+        values: Vec<V>,
+    ) -> Self {
+        let shape = Shape(rows_number, columns_number);
+        let elements = values
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| (Position(i / columns_number, i % columns_number), v))
+            .collect::<HashMap<Position, V>>();
+
+        Matrix { shape, elements }
+    }
 }
@@ -0,0 +1,357 @@
+//! Functions of dense square matrices on row-major `n x n` buffers.
+//!
+//! - `powi`: binary powering; negative exponents invert first
+//! - `expm`: scaling and squaring with a diagonal Padé approximant of degree 3, 5, 7, 9 or 13,
+//!   chosen from `‖A‖₁` as in Higham, "The scaling and squaring method for the matrix exponential
+//!   revisited" (2005); the degree and scaling keep the truncation error at double precision
+//! - `sqrtm`: principal square root by the Denman–Beavers iteration with determinantal scaling;
+//!   requires that no eigenvalue lies on the closed negative real axis
+//! - `logm`: principal logarithm by inverse scaling and squaring: square roots are taken until
+//!   `‖A - I‖₁ <= 1/4`, then `log A = 2 artanh((A - I)(A + I)⁻¹)` is summed as a series
+//!
+//! The `SquareMatrixTrait` methods of the same names wrap these helpers.
+
+use crate::matrix::kernels::gemm_row_major;
+use crate::matrix::traits::factorize_row_major;
+use crate::{FieldTrait, FloatTrait};
+
+/// Largest `‖A‖₁` for which the Padé approximant of the given degree is accurate to double
+/// precision without scaling.
+const PADE_THETAS: [(usize, f64); 4] = [
+    (3, 1.495585217958292e-2),
+    (5, 2.53939833006323e-1),
+    (7, 9.504178996162932e-1),
+    (9, 2.097847961257068),
+];
+const PADE_13_THETA: f64 = 5.371920351148152;
+
+/// Numerator coefficients `b_k` of the diagonal Padé approximant of degree `m` to `exp`.
+fn pade_coefficients(m: usize) -> &'static [f64] {
+    match m {
+        3 => &[120.0, 60.0, 12.0, 1.0],
+        5 => &[30240.0, 15120.0, 3360.0, 420.0, 30.0, 1.0],
+        7 => &[
+            17297280.0, 8648640.0, 1995840.0, 277200.0, 25200.0, 1512.0, 56.0, 1.0,
+        ],
+        9 => &[
+            17643225600.0,
+            8821612800.0,
+            2075673600.0,
+            302702400.0,
+            30270240.0,
+            2162160.0,
+            110880.0,
+            3960.0,
+            90.0,
+            1.0,
+        ],
+        _ => &[
+            64764752532480000.0,
+            32382376266240000.0,
+            7771770303897600.0,
+            1187353796428800.0,
+            129060195264000.0,
+            10559470521600.0,
+            670442572800.0,
+            33522128640.0,
+            1323241920.0,
+            40840800.0,
+            960960.0,
+            16380.0,
+            182.0,
+            1.0,
+        ],
+    }
+}
+
+fn from_f64<V>(value: f64) -> V
+where
+    V: FieldTrait,
+    V::Real: FloatTrait<Output = V::Real>,
+{
+    V::from_real(V::Real::from_f64(value))
+}
+
+fn identity<V>(n: usize) -> Vec<V>
+where
+    V: FieldTrait,
+{
+    let mut values = vec![V::from(0f32); n * n];
+    for i in 0..n {
+        values[i * n + i] = V::from(1f32);
+    }
+    values
+}
+
+fn multiply<V>(lhs: &[V], rhs: &[V], n: usize) -> Vec<V>
+where
+    V: FieldTrait,
+{
+    gemm_row_major(lhs, false, rhs, false, n, n, n)
+}
+
+/// `y += alpha * x`.
+fn add_scaled<V>(y: &mut [V], alpha: V, x: &[V])
+where
+    V: FieldTrait,
+{
+    for (y_value, x_value) in y.iter_mut().zip(x) {
+        *y_value += alpha * *x_value;
+    }
+}
+
+fn scale<V>(x: &[V], alpha: V) -> Vec<V>
+where
+    V: FieldTrait,
+{
+    x.iter().map(|value| alpha * *value).collect()
+}
+
+/// `‖a‖₁`, NaN when any entry is NaN.
+fn norm_1<V>(a: &[V], n: usize) -> V::Real
+where
+    V: FieldTrait,
+{
+    let zero = V::Real::from(0f32);
+    (0..n)
+        .map(|j| (0..n).fold(zero, |sum, i| sum + a[i * n + j].modulus()))
+        .fold(
+            zero,
+            |max, sum| if sum > max || sum.is_nan() { sum } else { max },
+        )
+}
+
+/// `‖a - I‖₁`.
+fn distance_to_identity<V>(a: &[V], n: usize) -> V::Real
+where
+    V: FieldTrait,
+{
+    let mut difference = a.to_vec();
+    for i in 0..n {
+        difference[i * n + i] -= V::from(1f32);
+    }
+    norm_1(&difference, n)
+}
+
+/// `lhs⁻¹ * rhs`, overwriting `rhs`.
+fn solve_in_place<V>(lhs: Vec<V>, rhs: &mut [V], n: usize, rel_tol: V::Real) -> Result<(), String>
+where
+    V: FieldTrait,
+{
+    factorize_row_major(lhs, n, rel_tol)?.solve_columns(rhs);
+    Ok(())
+}
+
+pub(crate) fn powi<V>(a: &[V], n: usize, exponent: i32, rel_tol: V::Real) -> Result<Vec<V>, String>
+where
+    V: FieldTrait,
+{
+    let mut base = a.to_vec();
+    if exponent < 0 {
+        base = identity(n);
+        solve_in_place(a.to_vec(), &mut base, n, rel_tol)?;
+    }
+    let mut result: Option<Vec<V>> = None;
+    let mut e = exponent.unsigned_abs();
+    while e > 0 {
+        if e & 1 == 1 {
+            result = Some(match result {
+                Some(result) => multiply(&result, &base, n),
+                None => base.clone(),
+            });
+        }
+        e >>= 1;
+        if e > 0 {
+            base = multiply(&base, &base, n);
+        }
+    }
+    Ok(result.unwrap_or_else(|| identity(n)))
+}
+
+/// `r_m(a) = q_m(a)⁻¹ p_m(a)` with `p_m(a) = v + u`, `q_m(a) = v - u` (`u` odd, `v` even part).
+fn pade<V>(a: &[V], n: usize, m: usize) -> Result<Vec<V>, String>
+where
+    V: FieldTrait,
+    V::Real: FloatTrait<Output = V::Real>,
+{
+    let b = pade_coefficients(m)
+        .iter()
+        .map(|coefficient| from_f64::<V>(*coefficient))
+        .collect::<Vec<V>>();
+    let zero = vec![V::from(0f32); n * n];
+    let a_2 = multiply(a, a, n);
+    let (u, v) = if m < 13 {
+        // Even powers I, A², A⁴, ... up to A^(m - 1).
+        let mut even_powers = vec![identity(n), a_2.clone()];
+        while even_powers.len() < m.div_ceil(2) {
+            let next = multiply(even_powers.last().expect("Powers are not empty"), &a_2, n);
+            even_powers.push(next);
+        }
+        let (mut u, mut v) = (zero.clone(), zero);
+        for (k, power) in even_powers.iter().enumerate() {
+            add_scaled(&mut u, b[2 * k + 1], power);
+            add_scaled(&mut v, b[2 * k], power);
+        }
+        (multiply(a, &u, n), v)
+    } else {
+        let a_4 = multiply(&a_2, &a_2, n);
+        let a_6 = multiply(&a_4, &a_2, n);
+        let mut u_high = zero.clone();
+        add_scaled(&mut u_high, b[13], &a_6);
+        add_scaled(&mut u_high, b[11], &a_4);
+        add_scaled(&mut u_high, b[9], &a_2);
+        let mut u = multiply(&a_6, &u_high, n);
+        add_scaled(&mut u, b[7], &a_6);
+        add_scaled(&mut u, b[5], &a_4);
+        add_scaled(&mut u, b[3], &a_2);
+        for i in 0..n {
+            u[i * n + i] += b[1];
+        }
+        let mut v_high = zero;
+        add_scaled(&mut v_high, b[12], &a_6);
+        add_scaled(&mut v_high, b[10], &a_4);
+        add_scaled(&mut v_high, b[8], &a_2);
+        let mut v = multiply(&a_6, &v_high, n);
+        add_scaled(&mut v, b[6], &a_6);
+        add_scaled(&mut v, b[4], &a_4);
+        add_scaled(&mut v, b[2], &a_2);
+        for i in 0..n {
+            v[i * n + i] += b[0];
+        }
+        (multiply(a, &u, n), v)
+    };
+    let mut p = v.clone();
+    let mut q = v;
+    add_scaled(&mut p, V::from(1f32), &u);
+    add_scaled(&mut q, V::from(-1f32), &u);
+    // q_m(A) is well conditioned for the admitted norms, every pivot is accepted.
+    solve_in_place(q, &mut p, n, V::Real::from(0f32))?;
+    Ok(p)
+}
+
+pub(crate) fn expm<V>(a: &[V], n: usize) -> Result<Vec<V>, String>
+where
+    V: FieldTrait,
+    V::Real: FloatTrait<Output = V::Real>,
+{
+    let norm = norm_1(a, n);
+    if norm.is_nan() {
+        return Err("Matrix exponential: matrix has NaN entries!".to_string());
+    }
+    for (m, theta) in PADE_THETAS {
+        if norm <= V::Real::from_f64(theta) {
+            return pade(a, n, m);
+        }
+    }
+    let ratio: f64 = (norm / V::Real::from_f64(PADE_13_THETA)).into();
+    let squarings = ratio.log2().ceil().max(0.0) as i32;
+    let mut result = pade(&scale(a, from_f64(2f64.powi(-squarings))), n, 13)?;
+    for _ in 0..squarings {
+        result = multiply(&result, &result, n);
+    }
+    let result_norm: f64 = norm_1(&result, n).into();
+    if !result_norm.is_finite() {
+        return Err("Matrix exponential: result overflows!".to_string());
+    }
+    Ok(result)
+}
+
+pub(crate) fn sqrtm<V>(
+    a: &[V],
+    n: usize,
+    tolerance: V::Real,
+    max_iterations: usize,
+) -> Result<Vec<V>, String>
+where
+    V: FieldTrait,
+    V::Real: FloatTrait<Output = V::Real>,
+{
+    let zero = V::Real::from(0f32);
+    let half = V::from(0.5f32);
+    let mut y = a.to_vec();
+    let mut z = identity(n);
+    for _ in 0..max_iterations {
+        let y_lup = factorize_row_major(y.clone(), n, zero)?;
+        let z_lup = factorize_row_major(z.clone(), n, zero)?;
+        // μ = |det(Y) det(Z)|^(-1/(2n)) balances the two iterates and speeds up the first steps;
+        // it tends to one as Y Z tends to I.
+        let log_modulus: f64 = (y_lup.log_determinant().1 + z_lup.log_determinant().1).into();
+        if !log_modulus.is_finite() {
+            return Err("Matrix square root: matrix is singular!".to_string());
+        }
+        let mu = (-log_modulus / (2 * n) as f64).exp();
+        let mut y_inverse = identity(n);
+        let mut z_inverse = identity(n);
+        y_lup.solve_columns(&mut y_inverse);
+        z_lup.solve_columns(&mut z_inverse);
+
+        let (mu, mu_inverse) = (from_f64::<V>(mu), from_f64::<V>(1.0 / mu));
+        let mut y_next = scale(&y, half * mu);
+        add_scaled(&mut y_next, half * mu_inverse, &z_inverse);
+        let mut z_next = scale(&z, half * mu);
+        add_scaled(&mut z_next, half * mu_inverse, &y_inverse);
+
+        let mut change = y_next.clone();
+        add_scaled(&mut change, V::from(-1f32), &y);
+        let is_converged = norm_1(&change, n) <= tolerance * norm_1(&y_next, n);
+        y = y_next;
+        z = z_next;
+        if is_converged {
+            return Ok(y);
+        }
+    }
+    Err(format!(
+        "Matrix square root: no convergence within {max_iterations} iterations!"
+    ))
+}
+
+pub(crate) fn logm<V>(
+    a: &[V],
+    n: usize,
+    tolerance: V::Real,
+    max_iterations: usize,
+) -> Result<Vec<V>, String>
+where
+    V: FieldTrait,
+    V::Real: FloatTrait<Output = V::Real>,
+{
+    // log A = 2^k log A^(1/2^k); bring A close enough to I for the series to converge quickly.
+    let mut x = a.to_vec();
+    let mut square_roots = 0;
+    while distance_to_identity(&x, n) > V::Real::from(0.25f32) {
+        if square_roots == 64 {
+            return Err("Matrix logarithm: too many square roots taken!".to_string());
+        }
+        x = sqrtm(&x, n, tolerance, max_iterations)?;
+        square_roots += 1;
+    }
+
+    // Z = (X + I)⁻¹ (X - I) has ‖Z‖₁ of roughly 1/8, so every term of
+    // log X = 2 (Z + Z³/3 + Z⁵/5 + ...) is about 64 times smaller than the previous one.
+    let mut z = x.clone();
+    let mut x_plus_identity = x;
+    for i in 0..n {
+        z[i * n + i] -= V::from(1f32);
+        x_plus_identity[i * n + i] += V::from(1f32);
+    }
+    solve_in_place(x_plus_identity, &mut z, n, V::Real::from(0f32))?;
+    let z_2 = multiply(&z, &z, n);
+    let mut power = z.clone();
+    let mut sum = z;
+    let mut is_converged = false;
+    for k in 1..=max_iterations {
+        power = multiply(&power, &z_2, n);
+        let term = scale(&power, V::from(1f32) / V::from((2 * k + 1) as f32));
+        add_scaled(&mut sum, V::from(1f32), &term);
+        if norm_1(&term, n) <= tolerance * norm_1(&sum, n) {
+            is_converged = true;
+            break;
+        }
+    }
+    if !is_converged {
+        return Err(format!(
+            "Matrix logarithm: no convergence within {max_iterations} iterations!"
+        ));
+    }
+    Ok(scale(&sum, from_f64(2f64.powi(square_roots + 1))))
+}
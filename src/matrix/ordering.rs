@@ -0,0 +1,344 @@
+//! Orderings of the sparsity graph of a square matrix.
+//!
+//! The graph has one node per row and an edge `i - j` for every stored off-diagonal entry of
+//! `A + Aᵀ`. All orderings return `order[k] = node numbered k`:
+//!
+//! - reverse Cuthill–McKee: breadth-first numbering from a pseudo-peripheral node (George–Liu),
+//!   neighbours taken by increasing degree, reversed. Keeps entries close to the diagonal, which is
+//!   what the skyline storage and banded solvers pay for.
+//! - approximate minimum degree: eliminates the node of smallest approximate external degree on
+//!   the quotient graph (elements absorb the cliques of eliminated nodes, degrees use the AMD upper
+//!   bound), without supervariable detection. Aims at little fill in a sparse Cholesky or LU.
+//! - nested dissection: splits the graph at the middle level of a breadth-first level structure,
+//!   numbers both halves recursively and the separator last; small parts fall back to minimum
+//!   degree, and the connected components of a part are dissected one after another.
+//!
+//! Bandwidth and profile are measured on the nonzero entries: the bandwidth is the largest
+//! `|i - j|`, the profile the number of off-diagonal positions under the skyline of the symmetrized
+//! pattern (the length of the skyline storage minus the diagonal).
+
+use std::collections::BTreeSet;
+
+use crate::matrix::structs::OrderingReport;
+
+/// Parts of at most this many nodes are not dissected further.
+const DISSECTION_LEAF_SIZE: usize = 16;
+
+/// Sorted adjacency lists of the symmetrized pattern, without the diagonal.
+pub(crate) fn symmetric_adjacency<I>(n: usize, entries: I) -> Vec<Vec<usize>>
+where
+    I: Iterator<Item = (usize, usize)>,
+{
+    let mut adjacency = vec![Vec::new(); n];
+    for (i, j) in entries {
+        if i != j {
+            adjacency[i].push(j);
+            adjacency[j].push(i);
+        }
+    }
+    for neighbours in adjacency.iter_mut() {
+        neighbours.sort_unstable();
+        neighbours.dedup();
+    }
+    adjacency
+}
+
+pub(crate) fn bandwidth<I>(entries: I) -> usize
+where
+    I: Iterator<Item = (usize, usize)>,
+{
+    entries.map(|(i, j)| i.abs_diff(j)).max().unwrap_or(0)
+}
+
+pub(crate) fn profile<I>(n: usize, entries: I) -> usize
+where
+    I: Iterator<Item = (usize, usize)>,
+{
+    let mut first: Vec<usize> = (0..n).collect();
+    for (i, j) in entries {
+        let (low, high) = (i.min(j), i.max(j));
+        first[high] = first[high].min(low);
+    }
+    first.iter().enumerate().map(|(j, &i)| j - i).sum()
+}
+
+/// Bandwidth and profile of the nonzero `positions` before and after renumbering with `inverse`
+/// (`inverse[old] = new`).
+pub(crate) fn ordering_report(
+    n: usize,
+    positions: &[(usize, usize)],
+    inverse: &[usize],
+) -> OrderingReport {
+    let renumbered = || positions.iter().map(|&(i, j)| (inverse[i], inverse[j]));
+    OrderingReport {
+        bandwidth_before: bandwidth(positions.iter().copied()),
+        bandwidth_after: bandwidth(renumbered()),
+        profile_before: profile(n, positions.iter().copied()),
+        profile_after: profile(n, renumbered()),
+    }
+}
+
+/// Graph restricted to the nodes of one region, with scratch space for the searches.
+struct Graph<'a> {
+    adjacency: &'a [Vec<usize>],
+    region: Vec<usize>,
+    next_region: usize,
+    mark: Vec<usize>,
+    stamp: usize,
+    local: Vec<usize>,
+}
+
+impl<'a> Graph<'a> {
+    fn create(adjacency: &'a [Vec<usize>]) -> Self {
+        Graph {
+            adjacency,
+            region: vec![0; adjacency.len()],
+            next_region: 1,
+            mark: vec![0; adjacency.len()],
+            stamp: 0,
+            local: vec![0; adjacency.len()],
+        }
+    }
+
+    fn neighbours(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        let region = self.region[node];
+        self.adjacency[node]
+            .iter()
+            .copied()
+            .filter(move |&j| self.region[j] == region)
+    }
+
+    fn degree(&self, node: usize) -> usize {
+        self.neighbours(node).count()
+    }
+
+    /// Moves `nodes` to a region of their own.
+    fn split_off(&mut self, nodes: &[usize]) {
+        for &node in nodes {
+            self.region[node] = self.next_region;
+        }
+        self.next_region += 1;
+    }
+
+    /// Breadth-first levels from `root`, the neighbours of every node taken by increasing degree.
+    /// The nodes reached keep `mark == stamp` until the next search.
+    fn levels(&mut self, root: usize) -> Vec<Vec<usize>> {
+        self.stamp += 1;
+        let stamp = self.stamp;
+        self.mark[root] = stamp;
+        let mut levels = vec![vec![root]];
+        loop {
+            let mut next_level = Vec::new();
+            for &node in levels.last().expect("Levels are never empty") {
+                let mut neighbours: Vec<usize> = self
+                    .neighbours(node)
+                    .filter(|&j| self.mark[j] != stamp)
+                    .collect();
+                neighbours.sort_by_key(|&j| (self.degree(j), j));
+                for j in neighbours {
+                    self.mark[j] = stamp;
+                    next_level.push(j);
+                }
+            }
+            if next_level.is_empty() {
+                return levels;
+            }
+            levels.push(next_level);
+        }
+    }
+
+    /// Level structure rooted at a pseudo-peripheral node of the component of `start`: restart
+    /// from the lowest-degree node of the last level while that makes the structure deeper.
+    fn pseudo_peripheral_levels(&mut self, start: usize) -> Vec<Vec<usize>> {
+        let mut levels = self.levels(start);
+        loop {
+            let candidate = *levels
+                .last()
+                .expect("Levels are never empty")
+                .iter()
+                .min_by_key(|&&j| (self.degree(j), j))
+                .expect("Levels are never empty");
+            let candidate_levels = self.levels(candidate);
+            if candidate_levels.len() <= levels.len() {
+                // Both searches covered the same component, so the marks still match `levels`.
+                return levels;
+            }
+            levels = candidate_levels;
+        }
+    }
+
+    /// Minimum degree order of the subgraph induced by `nodes`.
+    fn minimum_degree(&mut self, nodes: &[usize]) -> Vec<usize> {
+        for (local, &node) in nodes.iter().enumerate() {
+            self.local[node] = local;
+        }
+        let adjacency: Vec<Vec<usize>> = nodes
+            .iter()
+            .map(|&node| self.neighbours(node).map(|j| self.local[j]).collect())
+            .collect();
+        approximate_minimum_degree(&adjacency)
+            .into_iter()
+            .map(|local| nodes[local])
+            .collect()
+    }
+
+    /// Appends a nested dissection order of the region formed by `nodes` to `order`.
+    fn dissect(&mut self, nodes: Vec<usize>, order: &mut Vec<usize>) {
+        if nodes.len() <= DISSECTION_LEAF_SIZE {
+            order.extend(self.minimum_degree(&nodes));
+            return;
+        }
+        for component in self.components(&nodes) {
+            self.dissect_component(component, order);
+        }
+    }
+
+    /// Moves every connected component of the region formed by `nodes` to a region of its own.
+    /// The components come in the order of their first node.
+    fn components(&mut self, nodes: &[usize]) -> Vec<Vec<usize>> {
+        let region = self.region[nodes[0]];
+        let mut components = Vec::new();
+        for &node in nodes {
+            if self.region[node] == region {
+                let component = self.levels(node).concat();
+                self.split_off(&component);
+                components.push(component);
+            }
+        }
+        components
+    }
+
+    /// Appends a nested dissection order of the connected region formed by `nodes` to `order`.
+    fn dissect_component(&mut self, nodes: Vec<usize>, order: &mut Vec<usize>) {
+        if nodes.len() <= DISSECTION_LEAF_SIZE {
+            order.extend(self.minimum_degree(&nodes));
+            return;
+        }
+        let levels = self.pseudo_peripheral_levels(nodes[0]);
+        if levels.len() < 3 {
+            order.extend(self.minimum_degree(&nodes));
+            return;
+        }
+
+        // The level where half of the nodes have been reached, never the first or the last one.
+        let mut reached = 0;
+        let mut middle = levels.len() / 2;
+        for (l, level) in levels.iter().enumerate() {
+            reached += level.len();
+            if 2 * reached >= nodes.len() {
+                middle = l;
+                break;
+            }
+        }
+        let middle = middle.clamp(1, levels.len() - 2);
+
+        let first = levels[..middle].concat();
+        let second = levels[middle + 1..].concat();
+        let separator = levels[middle].clone();
+        self.split_off(&first);
+        self.split_off(&second);
+        self.split_off(&separator);
+        self.dissect(first, order);
+        self.dissect(second, order);
+        order.extend(separator);
+    }
+}
+
+pub(crate) fn reverse_cuthill_mckee(adjacency: &[Vec<usize>]) -> Vec<usize> {
+    let n = adjacency.len();
+    let mut graph = Graph::create(adjacency);
+    let mut is_numbered = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+
+    // Every component is started from its lowest-degree node.
+    let mut starts: Vec<usize> = (0..n).collect();
+    starts.sort_by_key(|&i| (adjacency[i].len(), i));
+    for start in starts {
+        if is_numbered[start] {
+            continue;
+        }
+        for node in graph.pseudo_peripheral_levels(start).into_iter().flatten() {
+            is_numbered[node] = true;
+            order.push(node);
+        }
+    }
+    order.reverse();
+    order
+}
+
+pub(crate) fn approximate_minimum_degree(adjacency: &[Vec<usize>]) -> Vec<usize> {
+    let n = adjacency.len();
+    // Quotient graph: variables adjacent to every variable, elements adjacent to every variable,
+    // variables of every element (an element is named after the variable whose elimination
+    // created it).
+    let mut variables: Vec<BTreeSet<usize>> = adjacency
+        .iter()
+        .map(|neighbours| neighbours.iter().copied().collect())
+        .collect();
+    let mut elements: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); n];
+    let mut members: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut degree: Vec<usize> = variables.iter().map(|v| v.len()).collect();
+    let mut queue: BTreeSet<(usize, usize)> = (0..n).map(|i| (degree[i], i)).collect();
+    let mut order = Vec::with_capacity(n);
+    // |Le \ Lp| of the elements e adjacent to the current element p, valid where outside_step[e]
+    // is p.
+    let mut outside = vec![0usize; n];
+    let mut outside_step = vec![usize::MAX; n];
+
+    while let Some((_, p)) = queue.pop_first() {
+        order.push(p);
+
+        // The new element p holds every variable reachable from p; the elements it reaches are
+        // absorbed into it.
+        let mut lp = std::mem::take(&mut variables[p]);
+        for e in std::mem::take(&mut elements[p]) {
+            for v in std::mem::take(&mut members[e]) {
+                elements[v].remove(&e);
+                if v != p {
+                    lp.insert(v);
+                }
+            }
+        }
+        for &i in &lp {
+            // Edges inside lp are represented by the element from now on.
+            variables[i].retain(|j| *j != p && !lp.contains(j));
+            elements[i].insert(p);
+        }
+        members[p] = lp.iter().copied().collect();
+
+        // The w(e) pass of AMD: start from |Le| and take one off for every variable of lp in e.
+        for &i in &lp {
+            for &e in &elements[i] {
+                if e != p {
+                    if outside_step[e] != p {
+                        outside_step[e] = p;
+                        outside[e] = members[e].len();
+                    }
+                    outside[e] -= 1;
+                }
+            }
+        }
+
+        let remaining = n - order.len();
+        for &i in &lp {
+            let mut external = variables[i].len() + lp.len() - 1;
+            for &e in &elements[i] {
+                if e != p {
+                    external += outside[e];
+                }
+            }
+            let approximate = external.min(degree[i] + lp.len() - 1).min(remaining - 1);
+            queue.remove(&(degree[i], i));
+            degree[i] = approximate;
+            queue.insert((approximate, i));
+        }
+    }
+    order
+}
+
+pub(crate) fn nested_dissection(adjacency: &[Vec<usize>]) -> Vec<usize> {
+    let mut graph = Graph::create(adjacency);
+    let mut order = Vec::with_capacity(adjacency.len());
+    graph.dissect((0..adjacency.len()).collect(), &mut order);
+    order
+}
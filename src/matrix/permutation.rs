@@ -0,0 +1,151 @@
+//! `Permutation` type + implementations.
+//!
+//! A permutation of `0..n` stored as `indices[new] = old`: position `k` of the permuted object is
+//! taken from position `indices[k]` of the original one. This is the form the orderings produce
+//! (the `k`-th node to eliminate) and the form of a pivot order (the row that became row `k`).
+//!
+//! As a matrix, `P` has a one in `(k, indices[k])`, so `P * x` is `apply(x)`, `P * A` permutes the
+//! rows of `A` and `A * Pᵀ` its columns (`PermuteTrait`, and the inherent methods of `CsrMatrix`
+//! and `SMatrix`).
+
+// external imports
+use std::ops::AddAssign;
+
+use crate::SquareMatrix;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Permutation {
+    indices: Vec<usize>,
+}
+
+impl Permutation {
+    /// Checks that `indices` holds every value of `0..indices.len()` exactly once.
+    pub fn create(indices: Vec<usize>) -> Result<Self, String> {
+        let mut is_used = vec![false; indices.len()];
+        for &index in &indices {
+            if index >= indices.len() || is_used[index] {
+                return Err(format!(
+                    "Permutation: indices are not a bijection of 0..{}!",
+                    indices.len()
+                ));
+            }
+            is_used[index] = true;
+        }
+        Ok(Permutation { indices })
+    }
+
+    pub fn identity(order: usize) -> Self {
+        Permutation {
+            indices: (0..order).collect(),
+        }
+    }
+
+    pub fn get_order(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// `get_indices()[new]` is the original index moved to `new`.
+    pub fn get_indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// `inverse_indices()[old]` is the position `old` is moved to.
+    pub fn inverse_indices(&self) -> Vec<usize> {
+        let mut inverse = vec![0usize; self.indices.len()];
+        for (new, &old) in self.indices.iter().enumerate() {
+            inverse[old] = new;
+        }
+        inverse
+    }
+
+    pub fn inverse(&self) -> Permutation {
+        Permutation {
+            indices: self.inverse_indices(),
+        }
+    }
+
+    /// `self * other`: applies `other` first, then `self`.
+    pub fn compose(&self, other: &Permutation) -> Result<Permutation, String> {
+        other.check_order(self.indices.len())?;
+        Ok(Permutation {
+            indices: self.indices.iter().map(|&k| other.indices[k]).collect(),
+        })
+    }
+
+    /// `true` when the permutation is a product of an even number of transpositions.
+    pub fn is_even(&self) -> bool {
+        let mut is_visited = vec![false; self.indices.len()];
+        let mut transpositions = 0;
+        for start in 0..self.indices.len() {
+            if is_visited[start] {
+                continue;
+            }
+            // A cycle of length k is k - 1 transpositions.
+            let mut i = start;
+            let mut length = 0;
+            while !is_visited[i] {
+                is_visited[i] = true;
+                i = self.indices[i];
+                length += 1;
+            }
+            transpositions += length - 1;
+        }
+        transpositions % 2 == 0
+    }
+
+    /// Determinant of the permutation matrix: `1` for even permutations, `-1` for odd ones.
+    pub fn sign(&self) -> i32 {
+        if self.is_even() { 1 } else { -1 }
+    }
+
+    /// `P * x`: component `k` of the result is component `indices[k]` of `x`.
+    pub fn apply<V>(&self, x: &[V]) -> Result<Vec<V>, String>
+    where
+        V: Copy,
+    {
+        self.check_order(x.len())?;
+        Ok(self.indices.iter().map(|&old| x[old]).collect())
+    }
+
+    /// `Pᵀ * x`, undoing `apply`.
+    pub fn apply_inverse<V>(&self, x: &[V]) -> Result<Vec<V>, String>
+    where
+        V: Copy,
+    {
+        self.check_order(x.len())?;
+        let mut result = x.to_vec();
+        for (&old, value) in self.indices.iter().zip(x) {
+            result[old] = *value;
+        }
+        Ok(result)
+    }
+
+    /// Dense permutation matrix `P`.
+    pub fn to_matrix<V>(&self) -> SquareMatrix<V>
+    where
+        V: Copy + From<f32> + PartialEq + AddAssign,
+    {
+        let n = self.indices.len();
+        let mut values = vec![V::from(0f32); n * n];
+        for (new, &old) in self.indices.iter().enumerate() {
+            values[new * n + old] = V::from(1f32);
+        }
+        SquareMatrix::create(n, &values)
+    }
+
+    pub(crate) fn check_order(&self, order: usize) -> Result<(), String> {
+        if self.indices.len() != order {
+            return Err(format!(
+                "Permutation: order {} does not match matrix order {}",
+                self.indices.len(),
+                order
+            ));
+        }
+        Ok(())
+    }
+
+    /// Wraps indices already known to be a bijection.
+    pub(crate) fn from_indices_unchecked(indices: Vec<usize>) -> Self {
+        Permutation { indices }
+    }
+}
@@ -0,0 +1,83 @@
+//! Power iteration for the largest singular value.
+//!
+//! The operator is only seen through `LinearOperatorTrait`, so dense, sparse and matrix-free
+//! operators share the same loop. The adjoint product is formed from the transpose one as
+//! `Aᴴ y = conj(Aᵀ conj(y))`, which is `Aᵀ y` for real fields.
+
+// external imports
+use extended_matrix_float::MyFloatTrait;
+
+use crate::{FieldTrait, FloatTrait, LinearOperatorTrait};
+
+fn euclidean_norm<V>(x: &[V]) -> V::Real
+where
+    V: FieldTrait,
+    V::Real: FloatTrait<Output = V::Real>,
+{
+    x.iter()
+        .fold(V::Real::from(0f32), |sum, value| {
+            sum + value.modulus() * value.modulus()
+        })
+        .my_sqrt()
+}
+
+/// Estimates `‖A‖₂` by power iteration on `AᴴA`.
+///
+/// The start vector is deterministic and deliberately non-uniform, so it is unlikely to be
+/// orthogonal to the dominant right singular vector. Iteration stops once two successive
+/// estimates agree within `rel_tol`; an error is returned if that does not happen within
+/// `max_iterations` steps. The operator must provide `apply_transpose`.
+pub fn largest_singular_value<V, A>(
+    operator: &A,
+    rel_tol: V::Real,
+    max_iterations: usize,
+) -> Result<V::Real, String>
+where
+    V: FieldTrait,
+    V::Real: FloatTrait<Output = V::Real>,
+    A: LinearOperatorTrait<V> + ?Sized,
+{
+    let zero = V::Real::from(0f32);
+    let columns_number = operator.get_dimensions().1;
+    if columns_number == 0 {
+        return Ok(zero);
+    }
+
+    let mut x = (0..columns_number)
+        .map(|i| V::from(1f32 + i as f32 / columns_number as f32))
+        .collect::<Vec<V>>();
+    let norm = euclidean_norm(&x);
+    x.iter_mut()
+        .for_each(|value| *value = *value / V::from_real(norm));
+
+    let mut sigma = zero;
+    for _ in 0..max_iterations {
+        let y = operator.apply(&x)?;
+        let new_sigma = euclidean_norm(&y);
+        if new_sigma == zero {
+            return Ok(zero);
+        }
+        let conjugated: Vec<V> = y.iter().map(|value| value.conjugate()).collect();
+        let z: Vec<V> = operator
+            .apply_transpose(&conjugated)?
+            .iter()
+            .map(|value| value.conjugate())
+            .collect();
+        let z_norm = euclidean_norm(&z);
+        if z_norm == zero {
+            return Ok(new_sigma);
+        }
+        x = z
+            .into_iter()
+            .map(|value| value / V::from_real(z_norm))
+            .collect();
+        if (new_sigma - sigma).my_abs() <= rel_tol * new_sigma {
+            return Ok(new_sigma);
+        }
+        sigma = new_sigma;
+    }
+
+    Err(format!(
+        "Power iteration: no convergence within {max_iterations} iterations!"
+    ))
+}
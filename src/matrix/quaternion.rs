@@ -0,0 +1,247 @@
+// external imports
+use std::ops::Mul;
+
+use crate::{BasicOperationsTrait, FloatTrait, Matrix, Position, Vector3};
+
+/// Quaternion `w + x i + y j + z k`.
+///
+/// Unit quaternions represent rotations: `q` and `-q` describe the same rotation, and a vector
+/// `v` is rotated as `q v q*`. The product `q_1 * q_2` is the Hamilton product, i.e. the rotation
+/// `q_2` followed by `q_1`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion<V> {
+    pub w: V,
+    pub x: V,
+    pub y: V,
+    pub z: V,
+}
+
+impl<V> Quaternion<V>
+where
+    V: FloatTrait<Output = V>,
+{
+    pub fn create(w: V, x: V, y: V, z: V) -> Self {
+        Quaternion { w, x, y, z }
+    }
+
+    pub fn identity() -> Self {
+        Quaternion::create(V::from(1f32), V::from(0f32), V::from(0f32), V::from(0f32))
+    }
+
+    /// Rotation by `angle` (radians, right-hand rule) about `axis`; `axis` need not be normalized.
+    pub fn from_axis_angle(axis: &Vector3<V>, angle: V) -> Result<Self, String> {
+        let [x, y, z] = axis.get_components();
+        let norm = (x * x + y * y + z * z).my_sqrt();
+        if norm == V::from(0f32) {
+            return Err("Quaternion: rotation axis has zero length!".to_string());
+        }
+        let half_angle = angle / V::from(2f32);
+        let s = half_angle.my_sin() / norm;
+        Ok(Quaternion::create(half_angle.my_cos(), x * s, y * s, z * s))
+    }
+
+    /// Unit quaternion of the rotation matrix `m` (Shepperd's method).
+    ///
+    /// `m` is assumed to be a proper rotation; use `Rotation3::from_rotation_matrix` to have it
+    /// validated first.
+    pub fn from_rotation_matrix<M>(m: &M) -> Result<Self, String>
+    where
+        M: BasicOperationsTrait<Value = V>,
+    {
+        if m.get_shape().0 != 3 || m.get_shape().1 != 3 {
+            return Err("Quaternion: rotation matrix must be 3x3!".to_string());
+        }
+        let r = |i: usize, j: usize| -> V {
+            *m.get_element_value(&Position(i, j))
+                .expect("Element is absent")
+        };
+        let (one, four) = (V::from(1f32), V::from(4f32));
+        let half = V::from(0.5f32);
+        let trace = r(0, 0) + r(1, 1) + r(2, 2);
+        // Divide by the largest of the four candidates to stay away from cancellation.
+        let q = if trace >= r(0, 0) && trace >= r(1, 1) && trace >= r(2, 2) {
+            let w = half * (one + trace).my_sqrt();
+            Quaternion::create(
+                w,
+                (r(2, 1) - r(1, 2)) / (four * w),
+                (r(0, 2) - r(2, 0)) / (four * w),
+                (r(1, 0) - r(0, 1)) / (four * w),
+            )
+        } else if r(0, 0) >= r(1, 1) && r(0, 0) >= r(2, 2) {
+            let x = half * (one + r(0, 0) - r(1, 1) - r(2, 2)).my_sqrt();
+            Quaternion::create(
+                (r(2, 1) - r(1, 2)) / (four * x),
+                x,
+                (r(0, 1) + r(1, 0)) / (four * x),
+                (r(0, 2) + r(2, 0)) / (four * x),
+            )
+        } else if r(1, 1) >= r(2, 2) {
+            let y = half * (one - r(0, 0) + r(1, 1) - r(2, 2)).my_sqrt();
+            Quaternion::create(
+                (r(0, 2) - r(2, 0)) / (four * y),
+                (r(0, 1) + r(1, 0)) / (four * y),
+                y,
+                (r(1, 2) + r(2, 1)) / (four * y),
+            )
+        } else {
+            let z = half * (one - r(0, 0) - r(1, 1) + r(2, 2)).my_sqrt();
+            Quaternion::create(
+                (r(1, 0) - r(0, 1)) / (four * z),
+                (r(0, 2) + r(2, 0)) / (four * z),
+                (r(1, 2) + r(2, 1)) / (four * z),
+                z,
+            )
+        };
+        q.normalize()
+    }
+
+    /// Components in `[w, x, y, z]` order.
+    pub fn get_components(&self) -> [V; 4] {
+        [self.w, self.x, self.y, self.z]
+    }
+
+    pub fn dot(&self, other: &Self) -> V {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn norm(&self) -> V {
+        self.dot(self).my_sqrt()
+    }
+
+    pub fn normalize(&self) -> Result<Self, String> {
+        let norm = self.norm();
+        if norm == V::from(0f32) {
+            return Err("Quaternion: zero quaternion could not be normalized!".to_string());
+        }
+        Ok(self.scale(V::from(1f32) / norm))
+    }
+
+    pub fn conjugate(&self) -> Self {
+        let zero = V::from(0f32);
+        Quaternion::create(self.w, zero - self.x, zero - self.y, zero - self.z)
+    }
+
+    pub fn inverse(&self) -> Result<Self, String> {
+        let norm_sqr = self.dot(self);
+        if norm_sqr == V::from(0f32) {
+            return Err("Quaternion: zero quaternion could not be inverted!".to_string());
+        }
+        Ok(self.conjugate().scale(V::from(1f32) / norm_sqr))
+    }
+
+    fn scale(&self, factor: V) -> Self {
+        Quaternion::create(
+            self.w * factor,
+            self.x * factor,
+            self.y * factor,
+            self.z * factor,
+        )
+    }
+
+    /// Rotation axis (unit length) and angle in `[0, π]` of a unit quaternion. The identity
+    /// rotation reports the x axis and a zero angle.
+    pub fn to_axis_angle(&self) -> (Vector3<V>, V) {
+        let zero = V::from(0f32);
+        // `q` and `-q` are the same rotation; pick the one with `w >= 0` so the angle is <= π.
+        let q = if self.w < zero {
+            self.scale(V::from(-1f32))
+        } else {
+            *self
+        };
+        let vector_norm = (q.x * q.x + q.y * q.y + q.z * q.z).my_sqrt();
+        if vector_norm == zero {
+            return (Vector3::create(&[V::from(1f32), zero, zero]), zero);
+        }
+        let angle = V::from(2f32) * vector_norm.my_atan2(&q.w);
+        let axis = Vector3::create(&[q.x / vector_norm, q.y / vector_norm, q.z / vector_norm]);
+        (axis, angle)
+    }
+
+    /// 3x3 rotation matrix of a unit quaternion.
+    pub fn to_rotation_matrix(&self) -> Matrix<V> {
+        let Quaternion { w, x, y, z } = *self;
+        let (one, two) = (V::from(1f32), V::from(2f32));
+        Matrix::create(
+            3,
+            3,
+            &[
+                one - two * (y * y + z * z),
+                two * (x * y - w * z),
+                two * (x * z + w * y),
+                two * (x * y + w * z),
+                one - two * (x * x + z * z),
+                two * (y * z - w * x),
+                two * (x * z - w * y),
+                two * (y * z + w * x),
+                one - two * (x * x + y * y),
+            ],
+        )
+    }
+
+    /// Rotates `v` by a unit quaternion.
+    pub fn rotate(&self, v: &Vector3<V>) -> Vector3<V> {
+        let [v_x, v_y, v_z] = v.get_components();
+        let two = V::from(2f32);
+        // t = 2 (q_v x v), v' = v + w t + q_v x t
+        let t = [
+            two * (self.y * v_z - self.z * v_y),
+            two * (self.z * v_x - self.x * v_z),
+            two * (self.x * v_y - self.y * v_x),
+        ];
+        Vector3::create(&[
+            v_x + self.w * t[0] + (self.y * t[2] - self.z * t[1]),
+            v_y + self.w * t[1] + (self.z * t[0] - self.x * t[2]),
+            v_z + self.w * t[2] + (self.x * t[1] - self.y * t[0]),
+        ])
+    }
+
+    /// Spherical linear interpolation between unit quaternions along the shorter arc:
+    /// `t = 0` gives `self`, `t = 1` gives `other` (or `-other`).
+    pub fn slerp(&self, other: &Self, t: V) -> Self {
+        let (zero, one) = (V::from(0f32), V::from(1f32));
+        let mut d = self.dot(other);
+        let mut other = *other;
+        if d < zero {
+            other = other.scale(V::from(-1f32));
+            d = zero - d;
+        }
+        // Nearly parallel: the sine below vanishes, fall back to normalized linear interpolation.
+        if d > V::from(0.9995f32) {
+            let q = Quaternion::create(
+                self.w + t * (other.w - self.w),
+                self.x + t * (other.x - self.x),
+                self.y + t * (other.y - self.y),
+                self.z + t * (other.z - self.z),
+            );
+            return q.normalize().expect("Interpolated quaternion is not zero");
+        }
+        let theta = d.my_acos();
+        let sin_theta = theta.my_sin();
+        let (lhs, rhs) = (
+            ((one - t) * theta).my_sin() / sin_theta,
+            (t * theta).my_sin() / sin_theta,
+        );
+        Quaternion::create(
+            lhs * self.w + rhs * other.w,
+            lhs * self.x + rhs * other.x,
+            lhs * self.y + rhs * other.y,
+            lhs * self.z + rhs * other.z,
+        )
+    }
+}
+
+impl<V> Mul for Quaternion<V>
+where
+    V: FloatTrait<Output = V>,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Quaternion::create(
+            self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+            self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+        )
+    }
+}
@@ -0,0 +1,189 @@
+// external imports
+use std::ops::Mul;
+
+use crate::{
+    BasicOperationsTrait, EulerSequence, FloatTrait, Matrix, Position, Quaternion, Vector3,
+};
+
+/// Rotation in three dimensions, stored as a unit quaternion.
+///
+/// Every constructor normalizes or validates its input, so the conversions below (rotation
+/// matrix, quaternion, axis-angle, Euler angles) always describe a proper rotation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rotation3<V> {
+    quaternion: Quaternion<V>,
+}
+
+impl<V> Rotation3<V>
+where
+    V: FloatTrait<Output = V>,
+{
+    pub fn identity() -> Self {
+        Rotation3 {
+            quaternion: Quaternion::identity(),
+        }
+    }
+
+    /// Rotation of `quaternion`, which is normalized first.
+    pub fn from_quaternion(quaternion: &Quaternion<V>) -> Result<Self, String> {
+        Ok(Rotation3 {
+            quaternion: quaternion.normalize()?,
+        })
+    }
+
+    pub fn from_axis_angle(axis: &Vector3<V>, angle: V) -> Result<Self, String> {
+        Ok(Rotation3 {
+            quaternion: Quaternion::from_axis_angle(axis, angle)?,
+        })
+    }
+
+    /// Rotation of the 3x3 matrix `m`. Fails unless `mᵀm = I` holds within `rel_tol`
+    /// (element-wise) and `det(m) > 0`.
+    pub fn from_rotation_matrix<M>(m: &M, rel_tol: V) -> Result<Self, String>
+    where
+        M: BasicOperationsTrait<Value = V>,
+    {
+        if m.get_shape().0 != 3 || m.get_shape().1 != 3 {
+            return Err("Rotation3: rotation matrix must be 3x3!".to_string());
+        }
+        let r = |i: usize, j: usize| -> V {
+            *m.get_element_value(&Position(i, j))
+                .expect("Element is absent")
+        };
+        for i in 0..3 {
+            for j in 0..3 {
+                let mut product = V::from(0f32);
+                for k in 0..3 {
+                    product += r(k, i) * r(k, j);
+                }
+                let expected = if i == j { V::from(1f32) } else { V::from(0f32) };
+                if (product - expected).my_abs() > rel_tol {
+                    return Err("Rotation3: matrix is not orthogonal!".to_string());
+                }
+            }
+        }
+        let determinant = r(0, 0) * (r(1, 1) * r(2, 2) - r(1, 2) * r(2, 1))
+            - r(0, 1) * (r(1, 0) * r(2, 2) - r(1, 2) * r(2, 0))
+            + r(0, 2) * (r(1, 0) * r(2, 1) - r(1, 1) * r(2, 0));
+        if determinant < V::from(0f32) {
+            return Err("Rotation3: matrix is a reflection, not a rotation!".to_string());
+        }
+        Ok(Rotation3 {
+            quaternion: Quaternion::from_rotation_matrix(m)?,
+        })
+    }
+
+    /// Rotation described by the Euler `angles` (radians) of `sequence`.
+    pub fn from_euler_angles(angles: [V; 3], sequence: EulerSequence) -> Self {
+        let zero = V::from(0f32);
+        let mut quaternion = Quaternion::identity();
+        for (axis, angle) in sequence.get_axes().into_iter().zip(angles) {
+            let mut components = [zero; 3];
+            components[axis] = V::from(1f32);
+            quaternion = quaternion
+                * Quaternion::from_axis_angle(&Vector3::create(&components), angle)
+                    .expect("Coordinate axis is not zero");
+        }
+        Rotation3 { quaternion }
+    }
+
+    pub fn to_quaternion(&self) -> Quaternion<V> {
+        self.quaternion
+    }
+
+    /// Unit axis and angle in `[0, π]`; see `Quaternion::to_axis_angle`.
+    pub fn to_axis_angle(&self) -> (Vector3<V>, V) {
+        self.quaternion.to_axis_angle()
+    }
+
+    pub fn to_rotation_matrix(&self) -> Matrix<V> {
+        self.quaternion.to_rotation_matrix()
+    }
+
+    /// Euler angles `[a, b, c]` of `sequence`.
+    ///
+    /// The middle angle lies in `[-π/2, π/2]` for Tait-Bryan and in `[0, π]` for proper Euler
+    /// sequences. When it is within `abs_tol` of gimbal lock (where only `a ± c` is defined),
+    /// `c` is set to zero.
+    pub fn to_euler_angles(&self, sequence: EulerSequence, abs_tol: V) -> [V; 3] {
+        let m = self.to_rotation_matrix();
+        let r = |i: usize, j: usize| -> V {
+            *m.get_element_value(&Position(i, j))
+                .expect("Element is absent")
+        };
+        let (zero, one) = (V::from(0f32), V::from(1f32));
+        let [i, j, k] = sequence.get_axes();
+        let is_proper = i == k;
+        let k = if is_proper { 3 - i - j } else { k };
+        // +1 for cyclic axis orders (x -> y -> z -> x), -1 otherwise.
+        let e = if (j + 3 - i) % 3 == 1 {
+            one
+        } else {
+            zero - one
+        };
+
+        // atan2 keeps the middle angle accurate next to gimbal lock, where acos / asin of an
+        // almost unit argument would lose half of the digits.
+        let (b, is_locked) = if is_proper {
+            let sin_b = (r(i, j) * r(i, j) + r(i, k) * r(i, k)).my_sqrt();
+            (sin_b.my_atan2(&r(i, i)), sin_b < abs_tol)
+        } else {
+            let cos_b = (r(i, i) * r(i, i) + r(i, j) * r(i, j)).my_sqrt();
+            ((e * r(i, k)).my_atan2(&cos_b), cos_b < abs_tol)
+        };
+        if is_locked {
+            // With c = 0 the second column of the sequence is only turned by `a`.
+            return [(e * r(k, j)).my_atan2(&r(j, j)), b, zero];
+        }
+        if is_proper {
+            [
+                r(j, i).my_atan2(&(zero - e * r(k, i))),
+                b,
+                r(i, j).my_atan2(&(e * r(i, k))),
+            ]
+        } else {
+            [
+                (zero - e * r(j, k)).my_atan2(&r(k, k)),
+                b,
+                (zero - e * r(i, j)).my_atan2(&r(i, i)),
+            ]
+        }
+    }
+
+    /// `self` applied after `other`.
+    pub fn compose(&self, other: &Self) -> Self {
+        Rotation3 {
+            quaternion: (self.quaternion * other.quaternion)
+                .normalize()
+                .expect("Product of unit quaternions is not zero"),
+        }
+    }
+
+    pub fn inverse(&self) -> Self {
+        Rotation3 {
+            quaternion: self.quaternion.conjugate(),
+        }
+    }
+
+    /// Constant angular velocity interpolation; `t = 0` gives `self`, `t = 1` gives `other`.
+    pub fn slerp(&self, other: &Self, t: V) -> Self {
+        Rotation3 {
+            quaternion: self.quaternion.slerp(&other.quaternion, t),
+        }
+    }
+
+    pub fn apply(&self, v: &Vector3<V>) -> Vector3<V> {
+        self.quaternion.rotate(v)
+    }
+}
+
+impl<V> Mul for Rotation3<V>
+where
+    V: FloatTrait<Output = V>,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.compose(&other)
+    }
+}
@@ -0,0 +1,365 @@
+//! `SMatrix` / `SVector` types + implementations.
+//!
+//! Fixed-size matrices with the shape in the type (`SMatrix<V, R, C>`) and the elements in a
+//! plain `[[V; C]; R]` array. They are meant for the small element-level blocks (3x3 rotations,
+//! 6x6 or 12x12 element matrices) that are built and thrown away millions of times during
+//! assembly, where the `HashMap` storage of `Matrix` dominates the run time.
+//!
+//! Shapes are checked by the compiler, so the operations that can fail on the dynamic types
+//! (`add`, `multiply`, ...) return their result directly here. Determinant and inverse use the
+//! closed forms up to order four and an inline LUP elimination above that; neither allocates.
+//! `into_matrix` / `into_square_matrix` and `try_from_matrix` convert to and from the dynamic
+//! types.
+
+// external imports
+use extended_matrix_float::MyFloatTrait;
+
+use crate::matrix::closed_form::{self, MAX_CLOSED_FORM_ORDER};
+use crate::matrix::traits::select_pivot;
+use crate::{
+    BasicOperationsTrait, FieldTrait, FloatTrait, Matrix, Permutation, Position, Shape,
+    SquareMatrix,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SMatrix<V, const R: usize, const C: usize> {
+    pub(crate) elements: [[V; C]; R],
+}
+
+/// Column vector of fixed length.
+pub type SVector<V, const N: usize> = SMatrix<V, N, 1>;
+
+/// Packed LUP factors with rows swapped in place: `L` strictly below the diagonal (unit diagonal
+/// implied), `U` on and above it; `o[i]` is the original row of factor row `i`.
+struct LupFactors<V, const N: usize> {
+    lu: [[V; N]; N],
+    o: [usize; N],
+    swaps: usize,
+}
+
+impl<V, const R: usize, const C: usize> SMatrix<V, R, C>
+where
+    V: FieldTrait,
+{
+    /// Row-major `elements_values`; missing values are filled with zeros, extra ones are ignored.
+    pub fn create(elements_values: &[V]) -> Self {
+        let mut m = SMatrix::zeros();
+        for (value, element) in elements_values
+            .iter()
+            .zip(m.elements.as_flattened_mut().iter_mut())
+        {
+            *element = *value;
+        }
+        m
+    }
+
+    pub fn from_rows(rows: [[V; C]; R]) -> Self {
+        SMatrix { elements: rows }
+    }
+
+    pub fn zeros() -> Self {
+        SMatrix {
+            elements: [[V::from(0f32); C]; R],
+        }
+    }
+
+    pub fn get_shape(&self) -> Shape {
+        Shape(R, C)
+    }
+
+    pub fn get_rows(&self) -> &[[V; C]; R] {
+        &self.elements
+    }
+
+    pub fn get_element_value(&self, position: &Position) -> Result<&V, String> {
+        self.elements
+            .get(position.0)
+            .and_then(|row| row.get(position.1))
+            .ok_or("Element is absent".to_string())
+    }
+
+    pub fn get_mut_element_value(&mut self, position: &Position) -> Result<&mut V, String> {
+        self.elements
+            .get_mut(position.0)
+            .and_then(|row| row.get_mut(position.1))
+            .ok_or("Element is absent".to_string())
+    }
+
+    pub fn transpose(&self) -> SMatrix<V, C, R> {
+        let mut result = SMatrix::zeros();
+        for i in 0..R {
+            for j in 0..C {
+                result.elements[j][i] = self.elements[i][j];
+            }
+        }
+        result
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        let mut result = *self;
+        for (value, other_value) in result
+            .elements
+            .as_flattened_mut()
+            .iter_mut()
+            .zip(other.elements.as_flattened())
+        {
+            *value += *other_value;
+        }
+        result
+    }
+
+    pub fn subtract(&self, other: &Self) -> Self {
+        let mut result = *self;
+        for (value, other_value) in result
+            .elements
+            .as_flattened_mut()
+            .iter_mut()
+            .zip(other.elements.as_flattened())
+        {
+            *value -= *other_value;
+        }
+        result
+    }
+
+    pub fn multiply_by_scalar(&self, scalar: V) -> Self {
+        let mut result = *self;
+        for value in result.elements.as_flattened_mut() {
+            *value *= scalar;
+        }
+        result
+    }
+
+    pub fn multiply<const K: usize>(&self, other: &SMatrix<V, C, K>) -> SMatrix<V, R, K> {
+        let mut result = SMatrix::zeros();
+        for i in 0..R {
+            for k in 0..C {
+                let lhs = self.elements[i][k];
+                for j in 0..K {
+                    let product = lhs * other.elements[k][j];
+                    result.elements[i][j] += product;
+                }
+            }
+        }
+        result
+    }
+
+    /// `P * self`: row `i` of the result is row `p[i]` of `self`.
+    pub fn permute_rows(&self, permutation: &Permutation) -> Result<Self, String> {
+        permutation.check_order(R)?;
+        let mut result = *self;
+        for (row, &old) in result.elements.iter_mut().zip(permutation.get_indices()) {
+            *row = self.elements[old];
+        }
+        Ok(result)
+    }
+
+    /// `self * Pᵀ`: column `j` of the result is column `p[j]` of `self`.
+    pub fn permute_columns(&self, permutation: &Permutation) -> Result<Self, String> {
+        permutation.check_order(C)?;
+        let mut result = *self;
+        for (row, old_row) in result.elements.iter_mut().zip(&self.elements) {
+            for (value, &old) in row.iter_mut().zip(permutation.get_indices()) {
+                *value = old_row[old];
+            }
+        }
+        Ok(result)
+    }
+
+    pub fn into_matrix(self) -> Matrix<V> {
+        Matrix::from_row_major(R, C, self.elements.as_flattened().to_vec())
+    }
+
+    /// Copies an `R x C` dynamic matrix; positions it does not store are read as zeros.
+    pub fn try_from_matrix<M>(m: &M) -> Result<Self, String>
+    where
+        M: BasicOperationsTrait<Value = V>,
+    {
+        if *m.get_shape() != Shape(R, C) {
+            return Err("Shapes of matrices do not conform to each other!".to_string());
+        }
+        let mut result = SMatrix::zeros();
+        for (position, value) in m.get_elements() {
+            result.elements[position.0][position.1] = *value;
+        }
+        Ok(result)
+    }
+}
+
+impl<V, const N: usize> SMatrix<V, N, N>
+where
+    V: FieldTrait,
+{
+    pub fn identity() -> Self {
+        let mut m = SMatrix::zeros();
+        for i in 0..N {
+            m.elements[i][i] = V::from(1f32);
+        }
+        m
+    }
+
+    pub fn into_square_matrix(self) -> SquareMatrix<V> {
+        SquareMatrix::create(N, self.elements.as_flattened())
+    }
+
+    fn decompose_lup(&self, rel_tol: V::Real) -> Result<LupFactors<V, N>, String> {
+        let mut lu = self.elements;
+        let mut o = [0usize; N];
+        let mut s = [V::Real::from(0f32); N];
+        for i in 0..N {
+            o[i] = i;
+            for value in &lu[i] {
+                if value.modulus() > s[i] {
+                    s[i] = value.modulus();
+                }
+            }
+        }
+        let mut swaps = 0;
+        for k in 0..N {
+            let p = k + select_pivot(lu.as_flattened(), N, k, &s, k..N, rel_tol)?;
+            if p != k {
+                lu.swap(p, k);
+                o.swap(p, k);
+                s.swap(p, k);
+                swaps += 1;
+            }
+            let pivot_row = lu[k];
+            for row in &mut lu[k + 1..] {
+                let factor = row[k] / pivot_row[k];
+                row[k] = factor;
+                for (value, &pivot) in row[k + 1..].iter_mut().zip(&pivot_row[k + 1..]) {
+                    *value -= factor * pivot;
+                }
+            }
+        }
+        Ok(LupFactors { lu, o, swaps })
+    }
+
+    fn substitute_lup(factors: &LupFactors<V, N>, b: &[V; N]) -> [V; N] {
+        let LupFactors { lu, o, .. } = factors;
+        let mut y = [V::from(0f32); N];
+        for i in 0..N {
+            let mut sum = b[o[i]];
+            for j in 0..i {
+                sum -= lu[i][j] * y[j];
+            }
+            y[i] = sum;
+        }
+        let mut x = [V::from(0f32); N];
+        for i in (0..N).rev() {
+            let mut sum = V::from(0f32);
+            for j in i + 1..N {
+                sum += lu[i][j] * x[j];
+            }
+            x[i] = (y[i] - sum) / lu[i][i];
+        }
+        x
+    }
+
+    /// Determinant; `rel_tol` is only used by the elimination above order four, which reports
+    /// zero when it meets a pivot that fails the check (as `SquareMatrixTrait::determinant`).
+    pub fn determinant(&self, rel_tol: V::Real) -> V {
+        if let Some(det) = closed_form::determinant(self.elements.as_flattened(), N) {
+            return det;
+        }
+        match self.decompose_lup(rel_tol) {
+            Ok(LupFactors { lu, swaps, .. }) => {
+                let mut det = V::from(1f32);
+                for (i, row) in lu.iter().enumerate() {
+                    det *= row[i];
+                }
+                if swaps % 2 == 1 {
+                    det *= V::from(-1f32);
+                }
+                if det.is_nan() {
+                    return V::from(0f32);
+                }
+                det
+            }
+            Err(_) => V::from(0f32),
+        }
+    }
+
+    pub fn inverse(&self, rel_tol: V::Real) -> Result<Self, String> {
+        if N <= MAX_CLOSED_FORM_ORDER {
+            let a = self.elements.as_flattened();
+            let det = closed_form::determinant(a, N).expect("Order has a closed form");
+            closed_form::check_determinant(det, a, N, rel_tol)?;
+            let mut result = SMatrix::zeros();
+            closed_form::adjugate(a, N, result.elements.as_flattened_mut())
+                .expect("Order has a closed form");
+            for value in result.elements.as_flattened_mut() {
+                *value = *value / det;
+            }
+            return Ok(result);
+        }
+        let factors = self.decompose_lup(rel_tol)?;
+        let mut result = SMatrix::zeros();
+        for j in 0..N {
+            let mut e = [V::from(0f32); N];
+            e[j] = V::from(1f32);
+            let x = Self::substitute_lup(&factors, &e);
+            for (row, &value) in result.elements.iter_mut().zip(&x) {
+                row[j] = value;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Solves `self * x = b` by LUP elimination.
+    pub fn solve(&self, b: &SVector<V, N>, rel_tol: V::Real) -> Result<SVector<V, N>, String> {
+        let factors = self.decompose_lup(rel_tol)?;
+        Ok(SVector::from_components(Self::substitute_lup(
+            &factors,
+            &b.get_components(),
+        )))
+    }
+}
+
+impl<V, const N: usize> SMatrix<V, N, 1>
+where
+    V: FieldTrait,
+{
+    pub fn from_components(components: [V; N]) -> Self {
+        SMatrix {
+            elements: components.map(|component| [component]),
+        }
+    }
+
+    pub fn get_components(&self) -> [V; N] {
+        self.elements.map(|[component]| component)
+    }
+
+    pub fn dot_product(&self, other: &Self) -> V {
+        let mut result = V::from(0f32);
+        for i in 0..N {
+            result += self.elements[i][0] * other.elements[i][0];
+        }
+        result
+    }
+
+    pub fn norm(&self) -> V::Real
+    where
+        V::Real: FloatTrait<Output = V::Real>,
+    {
+        let mut sum = V::Real::from(0f32);
+        for [component] in self.elements {
+            sum += component.modulus() * component.modulus();
+        }
+        sum.my_sqrt()
+    }
+}
+
+impl<V> SMatrix<V, 3, 1>
+where
+    V: FieldTrait,
+{
+    pub fn cross_product(&self, other: &Self) -> Self {
+        let [a_x, a_y, a_z] = self.get_components();
+        let [b_x, b_y, b_z] = other.get_components();
+        SVector::from_components([
+            a_y * b_z - a_z * b_y,
+            a_z * b_x - a_x * b_z,
+            a_x * b_y - a_y * b_x,
+        ])
+    }
+}
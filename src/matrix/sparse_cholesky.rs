@@ -0,0 +1,373 @@
+//! Sparse Cholesky factorization `A = L Lᵀ` of a symmetric positive definite `CsrMatrix` or
+//! `SymmetricCsrMatrix`.
+//!
+//! The factorization is split in two phases. `SparseCholeskySymbolic` depends only on the sparsity
+//! pattern: it builds the elimination tree and, from it, the exact pattern of `L`. The numeric
+//! phase (`SparseCholeskySymbolic::factorize`, `SparseCholeskyFactorization::refactorize`) fills
+//! the values in place, so a sequence of matrices with the same pattern (Newton iterations, time
+//! steps, parameter sweeps) pays for the graph work once.
+//!
+//! Only the lower triangle of each row (`j <= i`) of a `CsrMatrix` is read; the upper triangle is
+//! assumed to mirror it. A `SymmetricCsrMatrix` is transposed into the same lower-triangle rows.
+//!
+//! The numeric phase is the up-looking algorithm: row `k` of `L` is a sparse triangular solve whose
+//! pattern is the reach of row `k` of `A` in the elimination tree. `L` is stored by columns, with
+//! the diagonal first in every column.
+
+use crate::{CsrMatrix, FloatTrait, PreconditionerTrait, SymmetricCsrMatrix};
+
+#[derive(Clone, Debug)]
+pub struct SparseCholeskySymbolic {
+    n: usize,
+    parent: Vec<Option<usize>>,
+    // Lower-triangle pattern of the analysed matrix, row by row.
+    a_row_ptr: Vec<usize>,
+    a_col_index: Vec<usize>,
+    l_col_ptr: Vec<usize>,
+    l_row_index: Vec<usize>,
+}
+
+#[derive(Clone, Debug)]
+pub struct SparseCholeskyFactorization<V> {
+    symbolic: SparseCholeskySymbolic,
+    l_values: Vec<V>,
+}
+
+/// Lower triangle (`j <= i`) of a symmetric matrix, row by row.
+struct LowerTriangle<V> {
+    row_ptr: Vec<usize>,
+    col_index: Vec<usize>,
+    values: Vec<V>,
+}
+
+impl<V> LowerTriangle<V>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    fn from_csr(a: &CsrMatrix<V>) -> Result<Self, String> {
+        if a.get_n_rows() != a.get_n_cols() {
+            return Err(format!(
+                "SparseCholesky: matrix is not square: A is {}x{}",
+                a.get_n_rows(),
+                a.get_n_cols()
+            ));
+        }
+        let (row_ptr, col_index, values) = (a.get_row_ptr(), a.get_col_index(), a.get_values());
+        let mut lower = LowerTriangle {
+            row_ptr: Vec::with_capacity(a.get_n_rows() + 1),
+            col_index: Vec::new(),
+            values: Vec::new(),
+        };
+        lower.row_ptr.push(0);
+        for i in 0..a.get_n_rows() {
+            for idx in row_ptr[i]..row_ptr[i + 1] {
+                if col_index[idx] <= i {
+                    lower.col_index.push(col_index[idx]);
+                    lower.values.push(values[idx]);
+                }
+            }
+            lower.row_ptr.push(lower.col_index.len());
+        }
+        Ok(lower)
+    }
+
+    /// The upper triangle by rows is the lower triangle by columns: transposing it gives the rows.
+    fn from_symmetric(a: &SymmetricCsrMatrix<V>) -> Self {
+        let n = a.get_order();
+        let (row_ptr, col_index, values) = (a.get_row_ptr(), a.get_col_index(), a.get_values());
+        let mut lower_row_ptr = vec![0usize; n + 1];
+        for &j in col_index {
+            lower_row_ptr[j + 1] += 1;
+        }
+        for j in 0..n {
+            lower_row_ptr[j + 1] += lower_row_ptr[j];
+        }
+        let mut next = lower_row_ptr[..n].to_vec();
+        let mut lower_col_index = vec![0usize; col_index.len()];
+        let mut lower_values = vec![V::from(0f32); col_index.len()];
+        for i in 0..n {
+            for idx in row_ptr[i]..row_ptr[i + 1] {
+                let j = col_index[idx];
+                lower_col_index[next[j]] = i;
+                lower_values[next[j]] = values[idx];
+                next[j] += 1;
+            }
+        }
+        LowerTriangle {
+            row_ptr: lower_row_ptr,
+            col_index: lower_col_index,
+            values: lower_values,
+        }
+    }
+}
+
+impl SparseCholeskySymbolic {
+    /// Elimination tree and pattern of `L` for the lower triangle of `a`.
+    pub fn create<V>(a: &CsrMatrix<V>) -> Result<Self, String>
+    where
+        V: FloatTrait<Output = V> + Clone,
+    {
+        let lower = LowerTriangle::from_csr(a)?;
+        Ok(Self::from_lower(lower.row_ptr, lower.col_index))
+    }
+
+    /// Elimination tree and pattern of `L` for a matrix stored by its upper triangle.
+    pub fn create_symmetric<V>(a: &SymmetricCsrMatrix<V>) -> Self
+    where
+        V: FloatTrait<Output = V> + Clone,
+    {
+        let lower = LowerTriangle::from_symmetric(a);
+        Self::from_lower(lower.row_ptr, lower.col_index)
+    }
+
+    fn from_lower(a_row_ptr: Vec<usize>, a_col_index: Vec<usize>) -> Self {
+        let n = a_row_ptr.len() - 1;
+
+        // Elimination tree, with path compression through `ancestor`.
+        let mut parent = vec![None; n];
+        let mut ancestor: Vec<Option<usize>> = vec![None; n];
+        for k in 0..n {
+            for &j in &a_col_index[a_row_ptr[k]..a_row_ptr[k + 1]] {
+                let mut i = Some(j);
+                while let Some(node) = i.filter(|&node| node < k) {
+                    i = ancestor[node];
+                    ancestor[node] = Some(k);
+                    if i.is_none() {
+                        parent[node] = Some(k);
+                    }
+                }
+            }
+        }
+
+        let mut symbolic = SparseCholeskySymbolic {
+            n,
+            parent,
+            a_row_ptr,
+            a_col_index,
+            l_col_ptr: Vec::new(),
+            l_row_index: Vec::new(),
+        };
+
+        // Row k of L holds the columns reached from row k of A; count them per column, then fill
+        // the rows in increasing order (the order the numeric phase appends them in).
+        let mut rows = Vec::with_capacity(n);
+        let mut counts = vec![1usize; n];
+        let mut mark = vec![usize::MAX; n];
+        for k in 0..n {
+            let reach = symbolic.reach(k, &mut mark);
+            for &j in &reach {
+                counts[j] += 1;
+            }
+            rows.push(reach);
+        }
+        let mut l_col_ptr = vec![0usize; n + 1];
+        for j in 0..n {
+            l_col_ptr[j + 1] = l_col_ptr[j] + counts[j];
+        }
+        let mut l_row_index = vec![0usize; l_col_ptr[n]];
+        let mut next: Vec<usize> = l_col_ptr[..n].to_vec();
+        for (k, reach) in rows.iter().enumerate() {
+            l_row_index[next[k]] = k;
+            next[k] += 1;
+            for &j in reach {
+                l_row_index[next[j]] = k;
+                next[j] += 1;
+            }
+        }
+        symbolic.l_col_ptr = l_col_ptr;
+        symbolic.l_row_index = l_row_index;
+        symbolic
+    }
+
+    /// Columns `j < k` with `L[k][j] != 0`, in an order where every column comes after the
+    /// columns it depends on. `mark` is scratch space, tagged with `k`.
+    fn reach(&self, k: usize, mark: &mut [usize]) -> Vec<usize> {
+        // Each entry of row k starts a path up the tree that stops at k or at a node already
+        // visited. Ancestors depend on descendants, so a path is taken leaf first and paths found
+        // later (which end inside earlier ones) come before them.
+        let mut paths = Vec::new();
+        mark[k] = k;
+        for &j in &self.a_col_index[self.a_row_ptr[k]..self.a_row_ptr[k + 1]] {
+            let mut path = Vec::new();
+            let mut i = j;
+            while mark[i] != k {
+                path.push(i);
+                mark[i] = k;
+                i = self.parent[i].expect("Columns below k reach k in the elimination tree");
+            }
+            if !path.is_empty() {
+                paths.push(path);
+            }
+        }
+        paths.into_iter().rev().flatten().collect()
+    }
+
+    pub fn get_order(&self) -> usize {
+        self.n
+    }
+
+    /// `get_parent()[j]` is the parent of column `j` in the elimination tree (`None` for roots).
+    pub fn get_parent(&self) -> &[Option<usize>] {
+        &self.parent
+    }
+
+    /// Column pointers of `L`.
+    pub fn get_l_col_ptr(&self) -> &[usize] {
+        &self.l_col_ptr
+    }
+
+    /// Row indices of `L`, column by column, diagonal first.
+    pub fn get_l_row_index(&self) -> &[usize] {
+        &self.l_row_index
+    }
+
+    /// Number of stored entries of `L`, diagonal included.
+    pub fn get_l_nnz(&self) -> usize {
+        self.l_row_index.len()
+    }
+
+    /// Numeric factorization of `a`, which must have the pattern this analysis was built from.
+    pub fn factorize<V>(&self, a: &CsrMatrix<V>) -> Result<SparseCholeskyFactorization<V>, String>
+    where
+        V: FloatTrait<Output = V> + Clone,
+    {
+        let mut factorization = SparseCholeskyFactorization {
+            symbolic: self.clone(),
+            l_values: vec![V::from(0f32); self.l_row_index.len()],
+        };
+        factorization.refactorize(a)?;
+        Ok(factorization)
+    }
+
+    /// Numeric factorization of a matrix stored by its upper triangle, which must have the pattern
+    /// this analysis was built from.
+    pub fn factorize_symmetric<V>(
+        &self,
+        a: &SymmetricCsrMatrix<V>,
+    ) -> Result<SparseCholeskyFactorization<V>, String>
+    where
+        V: FloatTrait<Output = V> + Clone,
+    {
+        let mut factorization = SparseCholeskyFactorization {
+            symbolic: self.clone(),
+            l_values: vec![V::from(0f32); self.l_row_index.len()],
+        };
+        factorization.refactorize_symmetric(a)?;
+        Ok(factorization)
+    }
+}
+
+impl<V> SparseCholeskyFactorization<V>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    pub fn get_symbolic(&self) -> &SparseCholeskySymbolic {
+        &self.symbolic
+    }
+
+    /// Values of `L`, laid out as `SparseCholeskySymbolic::get_l_row_index`.
+    pub fn get_l_values(&self) -> &[V] {
+        &self.l_values
+    }
+
+    /// Recomputes the values of `L` for a matrix with the pattern of the symbolic analysis.
+    pub fn refactorize(&mut self, a: &CsrMatrix<V>) -> Result<(), String> {
+        self.refactorize_lower(&LowerTriangle::from_csr(a)?)
+    }
+
+    /// `refactorize` for a matrix stored by its upper triangle.
+    pub fn refactorize_symmetric(&mut self, a: &SymmetricCsrMatrix<V>) -> Result<(), String> {
+        self.refactorize_lower(&LowerTriangle::from_symmetric(a))
+    }
+
+    fn refactorize_lower(&mut self, a: &LowerTriangle<V>) -> Result<(), String> {
+        let symbolic = &self.symbolic;
+        if a.row_ptr != symbolic.a_row_ptr || a.col_index != symbolic.a_col_index {
+            return Err(
+                "SparseCholesky: sparsity pattern differs from the symbolic analysis!".to_string(),
+            );
+        }
+
+        let n = symbolic.n;
+        let l_col_ptr = &symbolic.l_col_ptr;
+        let l_row_index = &symbolic.l_row_index;
+        let mut x = vec![V::from(0f32); n];
+        let mut mark = vec![usize::MAX; n];
+        // Next free slot of every column; slot 0 is the diagonal.
+        let mut next: Vec<usize> = l_col_ptr[..n].iter().map(|p| p + 1).collect();
+        for k in 0..n {
+            let reach = symbolic.reach(k, &mut mark);
+            for idx in a.row_ptr[k]..a.row_ptr[k + 1] {
+                x[a.col_index[idx]] += a.values[idx];
+            }
+            let mut d = x[k];
+            x[k] = V::from(0f32);
+            for j in reach {
+                let l_kj = x[j] / self.l_values[l_col_ptr[j]];
+                x[j] = V::from(0f32);
+                for p in l_col_ptr[j] + 1..next[j] {
+                    let update = self.l_values[p] * l_kj;
+                    x[l_row_index[p]] -= update;
+                }
+                d -= l_kj * l_kj;
+                self.l_values[next[j]] = l_kj;
+                next[j] += 1;
+            }
+            if d.my_is_nan() || d <= V::from(0f32) {
+                return Err(format!(
+                    "SparseCholesky: matrix is not positive definite (pivot {})",
+                    k
+                ));
+            }
+            self.l_values[l_col_ptr[k]] = d.my_sqrt();
+        }
+        Ok(())
+    }
+
+    /// Solves `A x = b` with one forward (`L`) and one backward (`Lᵀ`) substitution.
+    pub fn solve(&self, b: &[V]) -> Result<Vec<V>, String> {
+        let n = self.symbolic.n;
+        if b.len() != n {
+            return Err(format!(
+                "SparseCholeskyFactorization: dimension mismatch: A is {}x{}, b has len {}",
+                n,
+                n,
+                b.len()
+            ));
+        }
+        let l_col_ptr = &self.symbolic.l_col_ptr;
+        let l_row_index = &self.symbolic.l_row_index;
+
+        let mut x = b.to_vec();
+        for j in 0..n {
+            x[j] = x[j] / self.l_values[l_col_ptr[j]];
+            for p in l_col_ptr[j] + 1..l_col_ptr[j + 1] {
+                let update = self.l_values[p] * x[j];
+                x[l_row_index[p]] -= update;
+            }
+        }
+        for j in (0..n).rev() {
+            for p in l_col_ptr[j] + 1..l_col_ptr[j + 1] {
+                let update = self.l_values[p] * x[l_row_index[p]];
+                x[j] -= update;
+            }
+            x[j] = x[j] / self.l_values[l_col_ptr[j]];
+        }
+        Ok(x)
+    }
+}
+
+/// Exact solve as a preconditioner. Panics if `r` does not match the order of the factorization.
+impl<V> PreconditionerTrait<V> for SparseCholeskyFactorization<V>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    fn apply(&self, r: &[V]) -> Vec<V> {
+        self.solve(r)
+            .expect("Dimensions must match the factorized matrix")
+    }
+
+    fn get_order(&self) -> Option<usize> {
+        Some(self.symbolic.n)
+    }
+}
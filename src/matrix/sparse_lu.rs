@@ -0,0 +1,334 @@
+//! Sparse LU factorization `P A = L U` of a square `CsrMatrix`, with partial pivoting.
+//!
+//! The factorization is left-looking (Gilbert–Peierls): column `k` of `L` and `U` is a sparse
+//! triangular solve with the columns already computed, whose pattern is found by a depth-first
+//! search in the graph of `L` before any arithmetic is done. The pivot is the entry of largest
+//! magnitude among the rows not pivoted yet. The total work is proportional to the flops, not to
+//! `n²`.
+//!
+//! The row order and the patterns of `L` and `U` are kept, so `refactorize` can recompute the values
+//! for a matrix with the same pattern without any graph work or pivot search. The pivots are then
+//! only checked for zero, not re-chosen: when the values change a lot, factorize again.
+//!
+//! Both factors are stored by columns with the rows in pivot order: `L` has its unit diagonal first
+//! in every column, `U` has its rows sorted and the diagonal last.
+
+use crate::{CsrMatrix, FloatTrait, Permutation, PreconditionerTrait};
+
+#[derive(Clone, Debug)]
+pub struct SparseLuFactorization<V> {
+    n: usize,
+    // Column pattern of the factorized matrix.
+    a_col_ptr: Vec<usize>,
+    a_row_index: Vec<usize>,
+    permutation: Permutation,
+    l_col_ptr: Vec<usize>,
+    l_row_index: Vec<usize>,
+    l_values: Vec<V>,
+    u_col_ptr: Vec<usize>,
+    u_row_index: Vec<usize>,
+    u_values: Vec<V>,
+}
+
+/// Compressed columns of a matrix, rows sorted within every column.
+struct Columns<V> {
+    col_ptr: Vec<usize>,
+    row_index: Vec<usize>,
+    values: Vec<V>,
+}
+
+fn to_columns<V>(a: &CsrMatrix<V>) -> Result<Columns<V>, String>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    let n = a.get_n_rows();
+    if a.get_n_cols() != n {
+        return Err(format!(
+            "SparseLu: matrix is not square: A is {}x{}",
+            n,
+            a.get_n_cols()
+        ));
+    }
+    let (row_ptr, col_index, values) = (a.get_row_ptr(), a.get_col_index(), a.get_values());
+    let mut col_ptr = vec![0usize; n + 1];
+    for &j in col_index {
+        col_ptr[j + 1] += 1;
+    }
+    for j in 0..n {
+        col_ptr[j + 1] += col_ptr[j];
+    }
+    let mut next = col_ptr[..n].to_vec();
+    let mut row_index = vec![0usize; col_index.len()];
+    let mut column_values = vec![V::from(0f32); col_index.len()];
+    for i in 0..n {
+        for idx in row_ptr[i]..row_ptr[i + 1] {
+            let j = col_index[idx];
+            row_index[next[j]] = i;
+            column_values[next[j]] = values[idx];
+            next[j] += 1;
+        }
+    }
+    Ok(Columns {
+        col_ptr,
+        row_index,
+        values: column_values,
+    })
+}
+
+impl<V> SparseLuFactorization<V>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    pub fn create(a: &CsrMatrix<V>) -> Result<Self, String> {
+        let Columns {
+            col_ptr: a_col_ptr,
+            row_index: a_row_index,
+            values: a_values,
+        } = to_columns(a)?;
+        let n = a.get_n_rows();
+
+        // pinv[i] is the step at which row i was chosen as pivot (usize::MAX while it is not).
+        let mut pinv = vec![usize::MAX; n];
+        let mut l_col_ptr = vec![0usize];
+        let mut l_row_index = Vec::new();
+        let mut l_values = Vec::new();
+        let mut u_col_ptr = vec![0usize];
+        let mut u_row_index = Vec::new();
+        let mut u_values = Vec::new();
+
+        let mut x = vec![V::from(0f32); n];
+        let mut mark = vec![usize::MAX; n];
+        let mut stack: Vec<(usize, usize)> = Vec::new();
+        for k in 0..n {
+            // Rows reachable from the pattern of column k through the columns of L computed so far
+            // (L keeps the original row numbers until the end), in topological order.
+            let mut reach = Vec::new();
+            for &start in &a_row_index[a_col_ptr[k]..a_col_ptr[k + 1]] {
+                if mark[start] == k {
+                    continue;
+                }
+                mark[start] = k;
+                stack.push((start, 0));
+                while let Some((node, child)) = stack.last_mut() {
+                    let children = match pinv[*node] {
+                        usize::MAX => &l_row_index[0..0],
+                        j => &l_row_index[l_col_ptr[j] + 1..l_col_ptr[j + 1]],
+                    };
+                    if let Some(&next) = children.get(*child) {
+                        *child += 1;
+                        if mark[next] != k {
+                            mark[next] = k;
+                            stack.push((next, 0));
+                        }
+                    } else {
+                        reach.push(*node);
+                        stack.pop();
+                    }
+                }
+            }
+            reach.reverse();
+
+            for idx in a_col_ptr[k]..a_col_ptr[k + 1] {
+                x[a_row_index[idx]] += a_values[idx];
+            }
+            for &i in &reach {
+                let j = pinv[i];
+                if j == usize::MAX {
+                    continue;
+                }
+                for p in l_col_ptr[j] + 1..l_col_ptr[j + 1] {
+                    let update = l_values[p] * x[i];
+                    x[l_row_index[p]] -= update;
+                }
+            }
+
+            let mut pivot_row = None;
+            let mut largest = V::from(0f32);
+            for &i in &reach {
+                if pinv[i] == usize::MAX {
+                    if x[i].my_abs() > largest {
+                        largest = x[i].my_abs();
+                        pivot_row = Some(i);
+                    }
+                } else {
+                    u_row_index.push(pinv[i]);
+                    u_values.push(x[i]);
+                }
+            }
+            let Some(pivot_row) = pivot_row else {
+                return Err(format!("SparseLu: matrix is singular (column {})", k));
+            };
+            let pivot = x[pivot_row];
+            u_row_index.push(k);
+            u_values.push(pivot);
+            u_col_ptr.push(u_row_index.len());
+
+            pinv[pivot_row] = k;
+            l_row_index.push(pivot_row);
+            l_values.push(V::from(1f32));
+            for &i in &reach {
+                if pinv[i] == usize::MAX {
+                    l_row_index.push(i);
+                    l_values.push(x[i] / pivot);
+                }
+                x[i] = V::from(0f32);
+            }
+            l_col_ptr.push(l_row_index.len());
+        }
+
+        for i in l_row_index.iter_mut() {
+            *i = pinv[*i];
+        }
+        // Topological order leaves the rows of U unsorted; sort them so refactorize can sweep
+        // each column in pivot order (the diagonal, row k of column k, ends up last).
+        for k in 0..n {
+            let range = u_col_ptr[k]..u_col_ptr[k + 1];
+            let mut column: Vec<(usize, V)> = u_row_index[range.clone()]
+                .iter()
+                .copied()
+                .zip(u_values[range.clone()].iter().copied())
+                .collect();
+            column.sort_by_key(|&(i, _)| i);
+            for (p, (i, value)) in range.zip(column) {
+                u_row_index[p] = i;
+                u_values[p] = value;
+            }
+        }
+
+        let mut o = vec![0usize; n];
+        for (i, &k) in pinv.iter().enumerate() {
+            o[k] = i;
+        }
+
+        Ok(SparseLuFactorization {
+            n,
+            a_col_ptr,
+            a_row_index,
+            permutation: Permutation::from_indices_unchecked(o),
+            l_col_ptr,
+            l_row_index,
+            l_values,
+            u_col_ptr,
+            u_row_index,
+            u_values,
+        })
+    }
+
+    pub fn get_order(&self) -> usize {
+        self.n
+    }
+
+    /// `get_pivot_order()[k]` is the row of `A` that became row `k` of `L` and `U`.
+    pub fn get_pivot_order(&self) -> &[usize] {
+        self.permutation.get_indices()
+    }
+
+    /// Row permutation `P` with `P * A = L * U`.
+    pub fn get_permutation(&self) -> &Permutation {
+        &self.permutation
+    }
+
+    /// Number of stored entries of `L`, unit diagonal included.
+    pub fn get_l_nnz(&self) -> usize {
+        self.l_row_index.len()
+    }
+
+    /// Number of stored entries of `U`, diagonal included.
+    pub fn get_u_nnz(&self) -> usize {
+        self.u_row_index.len()
+    }
+
+    /// Recomputes `L` and `U` for a matrix with the pattern of the factorized one, keeping its row
+    /// order and the patterns of the factors.
+    pub fn refactorize(&mut self, a: &CsrMatrix<V>) -> Result<(), String> {
+        let Columns {
+            col_ptr: a_col_ptr,
+            row_index: a_row_index,
+            values: a_values,
+        } = to_columns(a)?;
+        if a_col_ptr != self.a_col_ptr || a_row_index != self.a_row_index {
+            return Err(
+                "SparseLu: sparsity pattern differs from the factorized matrix!".to_string(),
+            );
+        }
+        let n = self.n;
+        let pinv = self.permutation.inverse_indices();
+
+        let mut x = vec![V::from(0f32); n];
+        for k in 0..n {
+            for idx in a_col_ptr[k]..a_col_ptr[k + 1] {
+                x[pinv[a_row_index[idx]]] += a_values[idx];
+            }
+            let diagonal = self.u_col_ptr[k + 1] - 1;
+            for p in self.u_col_ptr[k]..diagonal {
+                let j = self.u_row_index[p];
+                let u_jk = x[j];
+                self.u_values[p] = u_jk;
+                x[j] = V::from(0f32);
+                for q in self.l_col_ptr[j] + 1..self.l_col_ptr[j + 1] {
+                    let update = self.l_values[q] * u_jk;
+                    x[self.l_row_index[q]] -= update;
+                }
+            }
+            let pivot = x[k];
+            x[k] = V::from(0f32);
+            if pivot.my_is_nan() || pivot == V::from(0f32) {
+                return Err(format!(
+                    "SparseLu: zero pivot in refactorization (column {}), factorize again",
+                    k
+                ));
+            }
+            self.u_values[diagonal] = pivot;
+            for q in self.l_col_ptr[k] + 1..self.l_col_ptr[k + 1] {
+                let i = self.l_row_index[q];
+                self.l_values[q] = x[i] / pivot;
+                x[i] = V::from(0f32);
+            }
+        }
+        Ok(())
+    }
+
+    /// Solves `A x = b`: `L y = P b` forward, then `U x = y` backward.
+    pub fn solve(&self, b: &[V]) -> Result<Vec<V>, String> {
+        let n = self.n;
+        if b.len() != n {
+            return Err(format!(
+                "SparseLuFactorization: dimension mismatch: A is {}x{}, b has len {}",
+                n,
+                n,
+                b.len()
+            ));
+        }
+        let mut x = self.permutation.apply(b)?;
+        for j in 0..n {
+            for p in self.l_col_ptr[j] + 1..self.l_col_ptr[j + 1] {
+                let update = self.l_values[p] * x[j];
+                x[self.l_row_index[p]] -= update;
+            }
+        }
+        for j in (0..n).rev() {
+            let diagonal = self.u_col_ptr[j + 1] - 1;
+            x[j] = x[j] / self.u_values[diagonal];
+            for p in self.u_col_ptr[j]..diagonal {
+                let update = self.u_values[p] * x[j];
+                x[self.u_row_index[p]] -= update;
+            }
+        }
+        Ok(x)
+    }
+}
+
+/// Exact solve as a preconditioner. Panics if `r` does not match the order of the factorization.
+impl<V> PreconditionerTrait<V> for SparseLuFactorization<V>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    fn apply(&self, r: &[V]) -> Vec<V> {
+        self.solve(r)
+            .expect("Dimensions must match the factorized matrix")
+    }
+
+    fn get_order(&self) -> Option<usize> {
+        Some(self.n)
+    }
+}
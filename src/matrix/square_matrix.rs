@@ -6,12 +6,18 @@
 
 // external imports
 use std::collections::HashMap;
-use std::ops::AddAssign;
+use std::ops::{AddAssign, Mul};
 
+use crate::matrix::boundary_conditions::{Triplet, add_penalty, eliminate, zero_rows_and_columns};
+use crate::matrix::ordering::{bandwidth, ordering_report, profile};
+use crate::matrix::traits::apply_elements;
 use crate::{
-    BasicOperationsTrait, IntoMatrixTrait, SquareMatrixTrait, TryIntoSymmetricCompactedMatrixTrait,
+    BasicOperationsTrait, IntoMatrixTrait, LinearOperatorTrait, MatrixNormTrait, PermuteTrait,
+    SquareMatrixTrait, TryIntoSymmetricCompactedMatrixTrait,
+};
+use crate::{
+    ConstrainedDiagonal, FloatTrait, OrderingReport, Permutation, Position, ReducedSystem, Shape,
 };
-use crate::{Position, Shape};
 
 #[derive(PartialEq, Debug, Clone)]
 pub struct SquareMatrix<V> {
@@ -43,8 +49,30 @@ impl<V> IntoMatrixTrait for SquareMatrix<V> {}
 
 impl<V> SquareMatrixTrait for SquareMatrix<V> {}
 
+impl<V> MatrixNormTrait for SquareMatrix<V> {}
+
+impl<V> PermuteTrait for SquareMatrix<V> {}
+
 impl<V> TryIntoSymmetricCompactedMatrixTrait for SquareMatrix<V> {}
 
+/// Missing entries of a sparse storage count as zeros.
+impl<V> LinearOperatorTrait<V> for SquareMatrix<V>
+where
+    V: Copy + From<f32> + AddAssign + Mul<Output = V>,
+{
+    fn get_dimensions(&self) -> (usize, usize) {
+        (self.shape.0, self.shape.1)
+    }
+
+    fn apply(&self, x: &[V]) -> Result<Vec<V>, String> {
+        apply_elements(&self.elements, self.get_dimensions(), x, false)
+    }
+
+    fn apply_transpose(&self, y: &[V]) -> Result<Vec<V>, String> {
+        apply_elements(&self.elements, self.get_dimensions(), y, true)
+    }
+}
+
 impl<V> SquareMatrix<V>
 where
     V: Copy + From<f32> + PartialEq + AddAssign,
@@ -106,4 +134,121 @@ where
         let dense = self.to_dense_values();
         SquareMatrix::create(n, &dense)
     }
+
+    fn nonzero_positions(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.elements
+            .iter()
+            .filter(|(_, value)| **value != V::from(0f32))
+            .map(|(position, _)| (position.0, position.1))
+    }
+
+    /// `P A Pᵀ`: element `(i, j)` of the result is element `(p[i], p[j])` of `A`.
+    pub fn permute_symmetric(&self, permutation: &Permutation) -> Result<SquareMatrix<V>, String> {
+        self.permute_rows(permutation)?.permute_columns(permutation)
+    }
+
+    /// Largest `|i - j|` over the nonzero elements.
+    pub fn bandwidth(&self) -> usize {
+        bandwidth(self.nonzero_positions())
+    }
+
+    /// Off-diagonal positions under the skyline of the symmetrized nonzero pattern, i.e. the
+    /// length of the `try_into_symmetric_compacted_matrix` storage minus the diagonal.
+    pub fn profile(&self) -> usize {
+        profile(self.shape.0, self.nonzero_positions())
+    }
+
+    /// Bandwidth and profile before and after `permute_symmetric(permutation)`.
+    pub fn ordering_report(&self, permutation: &Permutation) -> Result<OrderingReport, String> {
+        permutation.check_order(self.shape.0)?;
+        let positions: Vec<(usize, usize)> = self.nonzero_positions().collect();
+        Ok(ordering_report(
+            self.shape.0,
+            &positions,
+            &permutation.inverse_indices(),
+        ))
+    }
+}
+
+impl<V> SquareMatrix<V>
+where
+    V: FloatTrait<Output = V>,
+{
+    /// Stored entries sorted by position, so right-hand side updates do not depend on the
+    /// iteration order of the storage.
+    fn sorted_entries(&self) -> Vec<Triplet<V>> {
+        let mut entries: Vec<Triplet<V>> = self
+            .elements
+            .iter()
+            .map(|(Position(i, j), value)| (*i, *j, *value))
+            .collect();
+        entries.sort_by_key(|&(i, j, _)| (i, j));
+        entries
+    }
+
+    fn from_entries(order: usize, entries: Vec<Triplet<V>>) -> SquareMatrix<V> {
+        let mut elements = HashMap::new();
+        for (i, j, value) in entries {
+            elements.insert(Position(i, j), value);
+        }
+        SquareMatrix {
+            shape: Shape(order, order),
+            elements,
+        }
+    }
+
+    /// Removes the DOFs of `prescribed` (`(dof, value)` pairs) from `A u = b`; see
+    /// `DirichletMapping::expand` to recover the full solution.
+    pub fn eliminate_dirichlet(
+        &self,
+        rhs: &[V],
+        prescribed: &[(usize, V)],
+    ) -> Result<ReducedSystem<SquareMatrix<V>, V>, String> {
+        let reduced = eliminate(
+            self.shape.0,
+            self.sorted_entries().into_iter(),
+            rhs,
+            prescribed,
+        )?;
+        Ok(ReducedSystem {
+            matrix: SquareMatrix::from_entries(reduced.mapping.get_reduced_order(), reduced.matrix),
+            rhs: reduced.rhs,
+            mapping: reduced.mapping,
+        })
+    }
+
+    /// Zeroes the rows and columns of the DOFs of `prescribed` and puts `diagonal` on their
+    /// diagonal, keeping the order of the system.
+    pub fn apply_dirichlet(
+        &self,
+        rhs: &[V],
+        prescribed: &[(usize, V)],
+        diagonal: ConstrainedDiagonal<V>,
+    ) -> Result<(SquareMatrix<V>, Vec<V>), String> {
+        let (entries, new_rhs) = zero_rows_and_columns(
+            self.shape.0,
+            self.sorted_entries().into_iter(),
+            rhs,
+            prescribed,
+            diagonal,
+        )?;
+        Ok((SquareMatrix::from_entries(self.shape.0, entries), new_rhs))
+    }
+
+    /// Imposes `prescribed` with the penalty `penalty` on the diagonal.
+    pub fn apply_penalty(
+        &self,
+        rhs: &[V],
+        prescribed: &[(usize, V)],
+        penalty: V,
+    ) -> Result<(SquareMatrix<V>, Vec<V>), String> {
+        let (entries, new_rhs) = add_penalty(
+            self.shape.0,
+            self.sorted_entries().into_iter(),
+            rhs,
+            prescribed,
+            penalty,
+        )?;
+        Ok((SquareMatrix::from_entries(self.shape.0, entries), new_rhs))
+    }
 }
@@ -1,5 +1,9 @@
+mod convergence_info;
+mod ordering_report;
 mod position;
 mod shape;
 
+pub use convergence_info::ConvergenceInfo;
+pub use ordering_report::OrderingReport;
 pub use position::Position;
 pub use shape::Shape;
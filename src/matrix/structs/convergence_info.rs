@@ -0,0 +1,7 @@
+/// How an iterative solver finished: the number of iterations taken and the final residual
+/// `‖b - A x‖₂ / ‖b‖₂`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ConvergenceInfo<V> {
+    pub iterations: usize,
+    pub relative_residual: V,
+}
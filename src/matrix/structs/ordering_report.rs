@@ -0,0 +1,8 @@
+/// Bandwidth and profile of a matrix before and after a symmetric renumbering.
+#[derive(Debug, PartialEq, Clone)]
+pub struct OrderingReport {
+    pub bandwidth_before: usize,
+    pub bandwidth_after: usize,
+    pub profile_before: usize,
+    pub profile_after: usize,
+}
@@ -0,0 +1,234 @@
+//! `SymmetricCsrMatrix` type + implementations.
+//!
+//! A symmetric matrix stored by the upper triangle of its rows (`j >= i`, diagonal included) in
+//! CSR form, about half of the memory of the full `CsrMatrix`. Columns are sorted within every row.
+//!
+//! `spmv` uses every stored off-diagonal entry twice, as `a_ij` in row `i` and as `a_ji` in row
+//! `j`, so it scatters and runs serially. The matrix is a `LinearOperatorTrait` (for the Krylov
+//! solvers) and can be factorized by the sparse Cholesky directly.
+
+// external imports
+use std::collections::BTreeMap;
+
+use crate::matrix::sparse_cholesky::{SparseCholeskyFactorization, SparseCholeskySymbolic};
+use crate::matrix::traits::check_operand_length;
+use crate::{CsrMatrix, FloatTrait, LinearOperatorTrait, SquareMatrix};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SymmetricCsrMatrix<V> {
+    order: usize,
+    values: Vec<V>,
+    col_index: Vec<usize>,
+    row_ptr: Vec<usize>,
+}
+
+impl<V> SymmetricCsrMatrix<V>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    /// Checks that the arrays describe the upper triangle of an `order x order` matrix with sorted
+    /// columns.
+    pub fn create(
+        order: usize,
+        values: Vec<V>,
+        col_index: Vec<usize>,
+        row_ptr: Vec<usize>,
+    ) -> Result<Self, String> {
+        if row_ptr.len() != order + 1
+            || values.len() != col_index.len()
+            || row_ptr.first() != Some(&0)
+            || row_ptr.last() != Some(&values.len())
+            || row_ptr.windows(2).any(|pair| pair[0] > pair[1])
+        {
+            return Err("SymmetricCsrMatrix: inconsistent CSR arrays!".to_string());
+        }
+        for i in 0..order {
+            let row = &col_index[row_ptr[i]..row_ptr[i + 1]];
+            if row.iter().any(|&j| j < i || j >= order)
+                || row.windows(2).any(|pair| pair[0] >= pair[1])
+            {
+                return Err(format!(
+                    "SymmetricCsrMatrix: row {} is not a sorted part of the upper triangle!",
+                    i
+                ));
+            }
+        }
+        Ok(SymmetricCsrMatrix {
+            order,
+            values,
+            col_index,
+            row_ptr,
+        })
+    }
+
+    /// Takes a `CsrMatrix` that stores only its upper triangle, such as the result of an assembly
+    /// with `AssemblyStorage::Upper`.
+    pub fn from_upper_csr(a: &CsrMatrix<V>) -> Result<Self, String> {
+        check_square(a)?;
+        SymmetricCsrMatrix::create(
+            a.get_n_rows(),
+            a.get_values().to_vec(),
+            a.get_col_index().to_vec(),
+            a.get_row_ptr().to_vec(),
+        )
+    }
+
+    /// Keeps the upper triangle of a full `a` after checking that every entry matches its mirror
+    /// within `rel_tol` (relative to the larger of the two; a missing entry counts as zero).
+    pub fn from_csr(a: &CsrMatrix<V>, rel_tol: V) -> Result<Self, String> {
+        check_square(a)?;
+        let n = a.get_n_rows();
+        let (row_ptr, col_index, values) = (a.get_row_ptr(), a.get_col_index(), a.get_values());
+
+        // Every upper position with its value and the value of its mirror.
+        let zero = V::from(0f32);
+        let mut pairs: BTreeMap<(usize, usize), (V, V)> = BTreeMap::new();
+        for i in 0..n {
+            for idx in row_ptr[i]..row_ptr[i + 1] {
+                let j = col_index[idx];
+                let pair = pairs.entry((i.min(j), i.max(j))).or_insert((zero, zero));
+                if j >= i {
+                    pair.0 += values[idx];
+                }
+                if j <= i {
+                    pair.1 += values[idx];
+                }
+            }
+        }
+
+        let mut triplets = Vec::with_capacity(pairs.len());
+        for ((i, j), (a_ij, a_ji)) in pairs {
+            let scale = if a_ij.my_abs() > a_ji.my_abs() {
+                a_ij.my_abs()
+            } else {
+                a_ji.my_abs()
+            };
+            if (a_ij - a_ji).my_abs() > rel_tol * scale {
+                return Err(format!(
+                    "SymmetricCsrMatrix: element [{}, {}] does not match with [{}, {}]!",
+                    j, i, i, j
+                ));
+            }
+            triplets.push((i, j, a_ij));
+        }
+
+        let mut upper_row_ptr = vec![0usize; n + 1];
+        for &(i, _, _) in &triplets {
+            upper_row_ptr[i + 1] += 1;
+        }
+        for i in 0..n {
+            upper_row_ptr[i + 1] += upper_row_ptr[i];
+        }
+        Ok(SymmetricCsrMatrix {
+            order: n,
+            col_index: triplets.iter().map(|&(_, j, _)| j).collect(),
+            values: triplets.into_iter().map(|(_, _, value)| value).collect(),
+            row_ptr: upper_row_ptr,
+        })
+    }
+
+    /// Validates the symmetry of `a` within `rel_tol` like `from_csr`; zero entries are not stored.
+    pub fn from_square_matrix(a: &SquareMatrix<V>, rel_tol: V) -> Result<Self, String> {
+        SymmetricCsrMatrix::from_csr(&CsrMatrix::from_square_matrix(a)?, rel_tol)
+    }
+
+    /// Full matrix with both triangles.
+    pub fn to_csr(&self) -> Result<CsrMatrix<V>, String> {
+        let mut triplets = Vec::with_capacity(2 * self.values.len());
+        for i in 0..self.order {
+            for idx in self.row_ptr[i]..self.row_ptr[i + 1] {
+                let j = self.col_index[idx];
+                triplets.push((i, j, self.values[idx]));
+                if j != i {
+                    triplets.push((j, i, self.values[idx]));
+                }
+            }
+        }
+        CsrMatrix::from_coo(self.order, self.order, &triplets)
+    }
+
+    pub fn get_order(&self) -> usize {
+        self.order
+    }
+
+    /// Number of stored entries (upper triangle only).
+    pub fn get_nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn get_values(&self) -> &[V] {
+        &self.values
+    }
+
+    pub fn get_col_index(&self) -> &[usize] {
+        &self.col_index
+    }
+
+    pub fn get_row_ptr(&self) -> &[usize] {
+        &self.row_ptr
+    }
+
+    /// `y = A * x` from the upper triangle.
+    pub fn spmv(&self, x: &[V]) -> Result<Vec<V>, String> {
+        if x.len() != self.order {
+            return Err(format!(
+                "SymmetricCsrMatrix::spmv: dimension mismatch: A is {}x{}, x has len {}",
+                self.order,
+                self.order,
+                x.len()
+            ));
+        }
+        let mut y = vec![V::from(0f32); self.order];
+        for i in 0..self.order {
+            let mut sum = V::from(0f32);
+            for idx in self.row_ptr[i]..self.row_ptr[i + 1] {
+                let j = self.col_index[idx];
+                sum += self.values[idx] * x[j];
+                if j != i {
+                    y[j] += self.values[idx] * x[i];
+                }
+            }
+            y[i] += sum;
+        }
+        Ok(y)
+    }
+
+    /// Sparse Cholesky factorization of a positive definite matrix. Use
+    /// `SparseCholeskySymbolic::create_symmetric` directly to keep the analysis for later matrices
+    /// of the same pattern.
+    pub fn cholesky(&self) -> Result<SparseCholeskyFactorization<V>, String> {
+        SparseCholeskySymbolic::create_symmetric(self).factorize_symmetric(self)
+    }
+}
+
+fn check_square<V>(a: &CsrMatrix<V>) -> Result<(), String>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    if a.get_n_rows() != a.get_n_cols() {
+        return Err(format!(
+            "SymmetricCsrMatrix: matrix is not square: A is {}x{}",
+            a.get_n_rows(),
+            a.get_n_cols()
+        ));
+    }
+    Ok(())
+}
+
+impl<V> LinearOperatorTrait<V> for SymmetricCsrMatrix<V>
+where
+    V: FloatTrait<Output = V> + Clone,
+{
+    fn get_dimensions(&self) -> (usize, usize) {
+        (self.order, self.order)
+    }
+
+    fn apply(&self, x: &[V]) -> Result<Vec<V>, String> {
+        check_operand_length(self.order, x.len(), self.get_dimensions())?;
+        self.spmv(x)
+    }
+
+    fn apply_transpose(&self, y: &[V]) -> Result<Vec<V>, String> {
+        self.apply(y)
+    }
+}
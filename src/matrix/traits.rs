@@ -1,5 +1,9 @@
 mod basic_operations_trait;
 mod into_matrix_trait;
+mod linear_operator_trait;
+mod matrix_norm_trait;
+mod permute_trait;
+mod preconditioner_trait;
 mod square_matrix_trait;
 mod try_into_square_matrix_trait;
 mod try_into_symmetric_compacted_matrix_trait;
@@ -7,7 +11,13 @@ mod vector_trait;
 
 pub use basic_operations_trait::BasicOperationsTrait;
 pub(crate) use into_matrix_trait::IntoMatrixTrait;
+pub use linear_operator_trait::LinearOperatorTrait;
+pub(crate) use linear_operator_trait::{apply_elements, check_operand_length};
+pub use matrix_norm_trait::MatrixNormTrait;
+pub use permute_trait::PermuteTrait;
+pub use preconditioner_trait::PreconditionerTrait;
 pub use square_matrix_trait::SquareMatrixTrait;
+pub(crate) use square_matrix_trait::{eliminate_gep_row_major, factorize_row_major, select_pivot};
 pub use try_into_square_matrix_trait::TryIntoSquareMatrixTrait;
 pub use try_into_symmetric_compacted_matrix_trait::TryIntoSymmetricCompactedMatrixTrait;
 pub use vector_trait::VectorTrait;
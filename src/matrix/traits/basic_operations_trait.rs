@@ -3,7 +3,10 @@ use std::collections::HashMap;
 use std::fmt::Debug;
 use std::ops::{AddAssign, Mul, MulAssign, SubAssign};
 
-use crate::{IntoMatrixTrait, Matrix, Operation, Position, Shape};
+use crate::matrix::kernels::{
+    for_each_value_mut, gemm_row_major, row_major_values, zip_values_mut,
+};
+use crate::{IntoMatrixTrait, Matrix, MaybeSendSyncTrait, Operation, Position, Shape};
 
 pub trait BasicOperationsTrait {
     type Value;
@@ -50,82 +53,77 @@ pub trait BasicOperationsTrait {
         Ok(())
     }
 
+    #[allow(clippy::needless_borrow)]
     fn add<M>(&self, other: &M) -> Result<Self, String>
     where
         M: BasicOperationsTrait<Value = Self::Value>,
-        Self::Value: Copy + AddAssign,
+        Self::Value: Copy + AddAssign + MaybeSendSyncTrait,
         Self: Clone,
     {
         self.shape_conformity_check::<M>(&other, Operation::Addition)?;
         let mut result = self.clone();
-        for (position, value) in other.get_elements() {
-            *result
-                .get_mut_element_value(position)
-                .expect("Element is absent") += *value;
-        }
+        zip_values_mut(
+            result.get_mut_elements(),
+            other.get_elements(),
+            |value, other_value| *value += other_value,
+        );
         Ok(result)
     }
 
+    #[allow(clippy::needless_borrow)]
     fn subtract<M>(&self, other: &M) -> Result<Self, String>
     where
         M: BasicOperationsTrait<Value = Self::Value>,
-        Self::Value: Copy + SubAssign,
+        Self::Value: Copy + SubAssign + MaybeSendSyncTrait,
         Self: Clone,
     {
         self.shape_conformity_check::<M>(&other, Operation::Subtraction)?;
         let mut result = self.clone();
-        for (position, value) in other.get_elements() {
-            *result
-                .get_mut_element_value(position)
-                .expect("Element is absent") -= *value;
-        }
+        zip_values_mut(
+            result.get_mut_elements(),
+            other.get_elements(),
+            |value, other_value| *value -= other_value,
+        );
         Ok(result)
     }
 
     fn multiply_by_scalar(&self, scalar: Self::Value) -> Self
     where
-        Self::Value: Copy + MulAssign,
+        Self::Value: Copy + MulAssign + MaybeSendSyncTrait,
         Self: Clone,
     {
         let mut result = self.clone();
-        for value in result.get_mut_elements().values_mut() {
-            *value *= scalar;
-        }
+        for_each_value_mut(result.get_mut_elements(), |value| *value *= scalar);
         result
     }
 
+    #[allow(clippy::needless_borrow)]
     fn multiply<M>(&self, other: &M) -> Result<Matrix<Self::Value>, String>
     where
-        M: IntoMatrixTrait<Value = Self::Value> + Clone,
-        Self::Value: Copy + AddAssign + SubAssign + Mul<Output = Self::Value> + From<f32>,
+        M: IntoMatrixTrait<Value = Self::Value>,
+        Self::Value: Copy
+            + AddAssign
+            + SubAssign
+            + Mul<Output = Self::Value>
+            + From<f32>
+            + MaybeSendSyncTrait,
     {
         self.shape_conformity_check::<M>(&other, Operation::Multiplication)?;
-        let mut result = other.clone();
-        let (rows_number, columns_number) = (self.get_shape().0, other.get_shape().1);
-        result.get_mut_shape().update(rows_number, columns_number);
-        result.get_mut_elements().clear();
-
-        for i in 0..(rows_number * columns_number) {
-            let mut result_value = <Self::Value>::from(0f32);
-            for k in 0..self.get_shape().1 {
-                let self_position = Position(i / columns_number, k);
-                let self_value = self
-                    .get_element_value(&self_position)
-                    .expect("Element is absent");
-                let other_position = Position(k, i % columns_number);
-                let other_value = other
-                    .get_element_value(&other_position)
-                    .expect("Element is absent");
-                result_value += (*self_value) * (*other_value);
-            }
-            let result_position = Position(i / columns_number, i % columns_number);
-            result
-                .get_mut_elements()
-                .insert(result_position, result_value);
-        }
-        Ok(result.into_matrix())
+        let (rows_number, inner, columns_number) =
+            (self.get_shape().0, self.get_shape().1, other.get_shape().1);
+        let values = gemm_row_major(
+            &row_major_values(self),
+            false,
+            &row_major_values(other),
+            false,
+            rows_number,
+            inner,
+            columns_number,
+        );
+        Ok(Matrix::from_row_major(rows_number, columns_number, values))
     }
 
+    #[allow(clippy::clone_on_copy)]
     fn transpose(&self) -> Self
     where
         Self::Value: Copy,
@@ -142,6 +140,7 @@ pub trait BasicOperationsTrait {
         result
     }
 
+    #[allow(clippy::clone_on_copy)]
     fn remove_row(&self, row: usize) -> Result<Matrix<Self::Value>, String>
     where
         Self::Value: Copy,
@@ -164,6 +163,7 @@ pub trait BasicOperationsTrait {
         Ok(result)
     }
 
+    #[allow(clippy::clone_on_copy)]
     fn remove_column(&self, column: usize) -> Result<Matrix<Self::Value>, String>
     where
         Self::Value: Copy,
@@ -186,6 +186,7 @@ pub trait BasicOperationsTrait {
         Ok(result)
     }
 
+    #[allow(clippy::useless_format)]
     fn show<F>(&self, f: F)
     where
         F: Fn(&str),
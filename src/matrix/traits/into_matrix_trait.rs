@@ -1,6 +1,7 @@
 use crate::{BasicOperationsTrait, Matrix};
 
 pub trait IntoMatrixTrait: BasicOperationsTrait {
+    #[allow(clippy::wrong_self_convention)]
     fn into_matrix(&self) -> Matrix<<Self as BasicOperationsTrait>::Value>
     where
         <Self as BasicOperationsTrait>::Value: Copy,
@@ -0,0 +1,72 @@
+//! Linear operators for matrix-free solvers.
+//!
+//! A `LinearOperatorTrait` is anything that can compute `A * x` for an `m x n` operator `A`:
+//! `CsrMatrix`, the dense `Matrix` and `SquareMatrix` (missing entries of a sparse storage count as
+//! zeros), or closures wrapped in a `ClosureOperator`, e.g. an element-by-element product that
+//! never assembles `A`. `apply_transpose` (`Aᵀ * y`) is optional and only needed by algorithms
+//! that use it, such as the power iteration for the spectral norm.
+
+// external imports
+use std::collections::HashMap;
+use std::ops::{AddAssign, Mul};
+
+use crate::Position;
+
+pub trait LinearOperatorTrait<V> {
+    /// `(rows, columns)` of the operator.
+    fn get_dimensions(&self) -> (usize, usize);
+
+    fn apply(&self, x: &[V]) -> Result<Vec<V>, String>;
+
+    fn apply_transpose(&self, _y: &[V]) -> Result<Vec<V>, String> {
+        Err("LinearOperator: transpose product is not available!".to_string())
+    }
+}
+
+pub(crate) fn check_operand_length(
+    expected: usize,
+    actual: usize,
+    dimensions: (usize, usize),
+) -> Result<(), String> {
+    if actual != expected {
+        return Err(format!(
+            "LinearOperator: dimension mismatch: operator is {}x{}, operand has len {}",
+            dimensions.0, dimensions.1, actual
+        ));
+    }
+    Ok(())
+}
+
+/// `A * x` (or `Aᵀ * x` with `transpose`) over the stored elements of a dense storage. The
+/// elements are visited in row-major order, so every component is summed in the same order
+/// whatever the iteration order of the map.
+pub(crate) fn apply_elements<V>(
+    elements: &HashMap<Position, V>,
+    dimensions: (usize, usize),
+    x: &[V],
+    transpose: bool,
+) -> Result<Vec<V>, String>
+where
+    V: Copy + From<f32> + AddAssign + Mul<Output = V>,
+{
+    let (rows_number, columns_number) = if transpose {
+        (dimensions.1, dimensions.0)
+    } else {
+        dimensions
+    };
+    check_operand_length(columns_number, x.len(), dimensions)?;
+    let mut y = vec![V::from(0f32); rows_number];
+    for row in 0..dimensions.0 {
+        for column in 0..dimensions.1 {
+            let Some(value) = elements.get(&Position(row, column)) else {
+                continue;
+            };
+            if transpose {
+                y[column] += *value * x[row];
+            } else {
+                y[row] += *value * x[column];
+            }
+        }
+    }
+    Ok(y)
+}
@@ -0,0 +1,97 @@
+//! Matrix norms.
+//!
+//! Norms are computed from the stored elements only, so sparse `SquareMatrix` values (missing
+//! entries) are handled as zeros. All norms are expressed in the `Real` type of the element field,
+//! i.e. complex matrices get real norms.
+//!
+//! - `norm_1`: maximum absolute column sum
+//! - `norm_inf`: maximum absolute row sum
+//! - `norm_frobenius`: square root of the sum of squared moduli
+//! - `norm_max`: largest modulus of a single element
+//! - `norm_2`: spectral norm (largest singular value), estimated by power iteration on `AᴴA`
+
+// external imports
+use extended_matrix_float::MyFloatTrait;
+
+use crate::matrix::traits::apply_elements;
+use crate::{
+    BasicOperationsTrait, ClosureOperator, FieldTrait, FloatTrait, Position, largest_singular_value,
+};
+
+pub trait MatrixNormTrait: BasicOperationsTrait {
+    fn norm_1(&self) -> <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real
+    where
+        <Self as BasicOperationsTrait>::Value: FieldTrait,
+    {
+        let zero = <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real::from(0f32);
+        let mut sums = vec![zero; self.get_shape().1];
+        for (Position(_, column), value) in self.get_elements() {
+            sums[*column] += value.modulus();
+        }
+        sums.into_iter()
+            .fold(zero, |max, sum| if sum > max { sum } else { max })
+    }
+
+    fn norm_inf(&self) -> <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real
+    where
+        <Self as BasicOperationsTrait>::Value: FieldTrait,
+    {
+        let zero = <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real::from(0f32);
+        let mut sums = vec![zero; self.get_shape().0];
+        for (Position(row, _), value) in self.get_elements() {
+            sums[*row] += value.modulus();
+        }
+        sums.into_iter()
+            .fold(zero, |max, sum| if sum > max { sum } else { max })
+    }
+
+    fn norm_frobenius(&self) -> <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real
+    where
+        <Self as BasicOperationsTrait>::Value: FieldTrait,
+        <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real:
+            FloatTrait<Output = <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real>,
+    {
+        let zero = <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real::from(0f32);
+        self.get_elements()
+            .values()
+            .fold(zero, |sum, value| sum + value.modulus() * value.modulus())
+            .my_sqrt()
+    }
+
+    fn norm_max(&self) -> <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real
+    where
+        <Self as BasicOperationsTrait>::Value: FieldTrait,
+    {
+        let zero = <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real::from(0f32);
+        self.get_elements().values().fold(zero, |max, value| {
+            let modulus = value.modulus();
+            if modulus > max { modulus } else { max }
+        })
+    }
+
+    fn norm_2(
+        &self,
+        rel_tol: <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real,
+        max_iterations: usize,
+    ) -> Result<<<Self as BasicOperationsTrait>::Value as FieldTrait>::Real, String>
+    where
+        <Self as BasicOperationsTrait>::Value: FieldTrait,
+        <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real:
+            FloatTrait<Output = <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real>,
+    {
+        let dimensions = (self.get_shape().0, self.get_shape().1);
+        let operator = ClosureOperator::create_with_transpose(
+            dimensions.0,
+            dimensions.1,
+            |x: &[<Self as BasicOperationsTrait>::Value]| {
+                apply_elements(self.get_elements(), dimensions, x, false)
+                    .expect("Dimensions are checked")
+            },
+            |y: &[<Self as BasicOperationsTrait>::Value]| {
+                apply_elements(self.get_elements(), dimensions, y, true)
+                    .expect("Dimensions are checked")
+            },
+        );
+        largest_singular_value(&operator, rel_tol, max_iterations)
+    }
+}
@@ -0,0 +1,39 @@
+//! Row and column permutations of matrices and vectors.
+//!
+//! `permute_rows(p)` is `P * A` (row `i` of the result is row `p[i]` of `A`) and
+//! `permute_columns(p)` is `A * Pᵀ` (column `j` of the result is column `p[j]` of `A`). Only the
+//! stored elements are moved, so sparse matrices stay sparse.
+
+use crate::{BasicOperationsTrait, Permutation, Position};
+
+pub trait PermuteTrait: BasicOperationsTrait {
+    fn permute_rows(&self, permutation: &Permutation) -> Result<Self, String>
+    where
+        Self: Clone,
+    {
+        permutation.check_order(self.get_shape().0)?;
+        let inverse = permutation.inverse_indices();
+        let mut result = self.clone();
+        let elements = std::mem::take(result.get_mut_elements());
+        *result.get_mut_elements() = elements
+            .into_iter()
+            .map(|(Position(i, j), value)| (Position(inverse[i], j), value))
+            .collect();
+        Ok(result)
+    }
+
+    fn permute_columns(&self, permutation: &Permutation) -> Result<Self, String>
+    where
+        Self: Clone,
+    {
+        permutation.check_order(self.get_shape().1)?;
+        let inverse = permutation.inverse_indices();
+        let mut result = self.clone();
+        let elements = std::mem::take(result.get_mut_elements());
+        *result.get_mut_elements() = elements
+            .into_iter()
+            .map(|(Position(i, j), value)| (Position(i, inverse[j]), value))
+            .collect();
+        Ok(result)
+    }
+}
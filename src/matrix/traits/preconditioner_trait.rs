@@ -0,0 +1,26 @@
+//! Preconditioners for the Krylov solvers.
+//!
+//! `apply(r)` returns `M⁻¹ r` for an approximation `M ≈ A`. The incomplete factorizations
+//! (`IncompleteLu`, `IncompleteCholesky`) and the sparse direct factorizations implement it, and so
+//! does any closure `Fn(&[V]) -> Vec<V>`, e.g. `|r: &[f64]| r.to_vec()` for no preconditioning or
+//! a user-written Jacobi or multigrid cycle.
+//!
+//! `get_order` reports the order of `M` when it is known, so the Krylov solvers can reject a
+//! preconditioner built for another system before applying it; closures report `None`.
+
+pub trait PreconditionerTrait<V> {
+    fn apply(&self, r: &[V]) -> Vec<V>;
+
+    fn get_order(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl<V, F> PreconditionerTrait<V> for F
+where
+    F: Fn(&[V]) -> Vec<V>,
+{
+    fn apply(&self, r: &[V]) -> Vec<V> {
+        self(r)
+    }
+}
@@ -3,287 +3,296 @@
 //! This trait groups “classical” routines that are commonly taught in numerical linear algebra:
 //! - Gaussian elimination (`*_gep` variants implement elimination/substitution with pivoting)
 //! - LU / LUP decomposition (including determinant/inverse helpers built on top)
+//! - matrix functions: `powi`, `expm`, `sqrtm`, `logm` (see `matrix::matrix_functions`)
+//! - `*_matrix` variants of the solvers for many right-hand sides (`A * X = B`) at once
+//! - closed-form determinant, inverse, adjugate and cofactor matrix for orders up to four, which
+//!   `determinant` / `inverse` dispatch to automatically
 //!
 //! Notes on naming:
 //! - `*_gep` is used here as a short label for “Gaussian elimination process” style helpers.
 //!   In practice, these methods implement elimination/substitution steps with pivoting.
 //! - `lup_*` refers to an LU factorization with a permutation matrix (row pivoting).
 //!
+//! Element types only need `FieldTrait`. Pivot selection follows `FieldTrait::PIVOTING`:
+//! floating-point types (`f32`, `f64`, `Complex`) use scaled partial pivoting on
+//! `FieldTrait::modulus`, exact types (`Rational`) take the first non-zero entry.
+//!
 //! The goal is clarity over micro-optimizations.
 
 
-// external imports
-use extended_matrix_float::MyFloatTrait;
+use crate::matrix::closed_form::{self, MAX_CLOSED_FORM_ORDER};
+use crate::matrix::kernels::{for_each_chunk_mut, for_each_column_mut, row_major_values};
+use crate::matrix::matrix_functions;
+use crate::{
+    BasicOperationsTrait, FieldTrait, FloatTrait, LupFactorization, Matrix, MatrixNormTrait,
+    Operation, Permutation, Pivoting, Position, Precision, Shape, Vector, VectorTrait,
+};
 
-use crate::{BasicOperationsTrait, FloatTrait, Operation, Position, Vector, VectorTrait};
+/// Rejects an exactly zero pivot as "Singular matrix" and, for `LargestMagnitude`, a pivot that is
+/// too small relative to its row scale as "Ill conditioned system" (`FirstNonZero` arithmetic is
+/// exact, so `rel_tol` is not used there). A NaN ratio (NaN entries) is ill conditioned as well.
+fn check_pivot<V>(pivot: V, scale: V::Real, rel_tol: V::Real) -> Result<(), String>
+where
+    V: FieldTrait,
+{
+    match V::PIVOTING {
+        Pivoting::LargestMagnitude => {
+            if pivot == V::from(0f32) {
+                return Err("Singular matrix".to_string());
+            }
+            let ratio = pivot.modulus() / scale;
+            if scale == V::Real::from(0f32) || ratio.is_nan() || ratio < rel_tol {
+                return Err("Ill conditioned system".to_string());
+            }
+        }
+        Pivoting::FirstNonZero => {
+            if pivot == V::from(0f32) {
+                return Err("Singular matrix".to_string());
+            }
+        }
+    }
+    Ok(())
+}
 
-fn pivot_gep<V, SMT, VT>(
-    a: &mut SMT,
-    b: &mut VT,
-    s: &mut [<SMT as BasicOperationsTrait>::Value],
+/// Pivot of column `k` of the row-major buffer `a` (`n` columns) among the physical `rows`, the row
+/// currently in position `k` first: the largest `modulus / s[row]` (`LargestMagnitude`, ties keep
+/// the earlier row) or the first non-zero entry (`FirstNonZero`). The pivot is then checked with
+/// `check_pivot`. Returns the position of the chosen row within `rows`.
+pub(crate) fn select_pivot<V, I>(
+    a: &[V],
     n: usize,
     k: usize,
-    pn: &mut i32,
-) where
-    V: FloatTrait,
-    SMT: SquareMatrixTrait,
-    VT: VectorTrait,
-    <SMT as BasicOperationsTrait>::Value: FloatTrait<Output = V>,
-    <VT as BasicOperationsTrait>::Value: FloatTrait<Output = V>,
+    s: &[V::Real],
+    rows: I,
+    rel_tol: V::Real,
+) -> Result<usize, String>
+where
+    V: FieldTrait,
+    I: IntoIterator<Item = usize>,
 {
-    let mut p = k;
-    let mut big = (*a
-        .get_element_value(&Position(k, k))
-        .expect("Element is absent")
-        / s[k])
-        .my_abs();
-    for ii in k + 1..n {
-        let dummy = (*a
-            .get_element_value(&Position(ii, k))
-            .expect("Element is absent")
-            / s[ii])
-            .my_abs();
-        if dummy > big {
-            big = dummy;
-            p = ii;
-        }
-    }
-    if p != k {
-        *pn += 1;
-        for jj in k..n {
-            let dummy = *a
-                .get_element_value(&Position(p, jj))
-                .expect("Element is absent");
-            *a.get_mut_element_value(&Position(p, jj))
-                .expect("Element is absent") = *a
-                .get_element_value(&Position(k, jj))
-                .expect("Element is absent");
-            *a.get_mut_element_value(&Position(k, jj))
-                .expect("Element is absent") = dummy;
+    let mut chosen: Option<(usize, usize)> = None;
+    let mut big = V::Real::from(0f32);
+    for (offset, row) in rows.into_iter().enumerate() {
+        let value = a[row * n + k];
+        match V::PIVOTING {
+            Pivoting::LargestMagnitude => {
+                let ratio = value.modulus() / s[row];
+                if chosen.is_none() || ratio > big {
+                    big = ratio;
+                    chosen = Some((offset, row));
+                }
+            }
+            Pivoting::FirstNonZero => {
+                if chosen.is_none() || value != V::from(0f32) {
+                    chosen = Some((offset, row));
+                }
+                if value != V::from(0f32) {
+                    break;
+                }
+            }
         }
-        let dummy = *b
-            .get_element_value(&Position(p, 0))
-            .expect("Element is absent");
-        *b.get_mut_element_value(&Position(p, 0))
-            .expect("Element is absent") = *b
-            .get_element_value(&Position(k, 0))
-            .expect("Element is absent");
-        *b.get_mut_element_value(&Position(k, 0))
-            .expect("Element is absent") = dummy;
-        let dummy = s[p];
-        s[p] = s[k];
-        s[k] = dummy;
     }
+    let (offset, row) = chosen.expect("Pivot candidates are never empty");
+    check_pivot(a[row * n + k], s[row], rel_tol)?;
+    Ok(offset)
 }
 
-fn eliminate_gep<V, SMT, VT>(
-    a: &mut SMT,
-    s: &mut [<SMT as BasicOperationsTrait>::Value],
+/// Gaussian elimination of the row-major `n x n` buffer `a` together with the row-major `n x m`
+/// right-hand sides `b`, with the rows swapped in place; `s` (length `n`) receives the row scales.
+/// Returns the number of row interchanges.
+pub(crate) fn eliminate_gep_row_major<V>(
+    a: &mut [V],
+    b: &mut [V],
     n: usize,
-    b: &mut VT,
-    rel_tol: <SMT as BasicOperationsTrait>::Value,
-) -> Result<i32, String>
+    s: &mut [V::Real],
+    rel_tol: V::Real,
+) -> Result<usize, String>
 where
-    V: FloatTrait,
-    SMT: SquareMatrixTrait + BasicOperationsTrait<Value = V>,
-    VT: VectorTrait + BasicOperationsTrait<Value = V>,
-    <SMT as BasicOperationsTrait>::Value: FloatTrait<Output = V>,
-    <VT as BasicOperationsTrait>::Value: FloatTrait<Output = V>,
+    V: FieldTrait,
 {
-    let mut pn = 0i32;
-    for k in 0..n - 1 {
-        pivot_gep(a, b, s, n, k, &mut pn);
-        if (*a
-            .get_element_value(&Position(k, k))
-            .expect("Element is absent")
-            / s[k])
-            .my_abs()
-            < rel_tol
-        {
-            return Err("Ill conditioned system".to_string());
+    let m = b.len() / n.max(1);
+    for i in 0..n {
+        s[i] = a[i * n].modulus();
+        for j in 1..n {
+            if a[i * n + j].modulus() > s[i] {
+                s[i] = a[i * n + j].modulus();
+            }
+        }
+    }
+    let mut swaps = 0;
+    for k in 0..n {
+        let p = k + select_pivot(a, n, k, s, k..n, rel_tol)?;
+        if p != k {
+            for jj in k..n {
+                a.swap(p * n + jj, k * n + jj);
+            }
+            for jj in 0..m {
+                b.swap(p * m + jj, k * m + jj);
+            }
+            s.swap(p, k);
+            swaps += 1;
         }
         for i in k + 1..n {
-            let factor = *a
-                .get_element_value(&Position(i, k))
-                .expect("Element is absent")
-                / *a.get_element_value(&Position(k, k))
-                    .expect("Element is absent");
+            let factor = a[i * n + k] / a[k * n + k];
             for j in k + 1..n {
-                *a.get_mut_element_value(&Position(i, j))
-                    .expect("Element is absent") = *a
-                    .get_element_value(&Position(i, j))
-                    .expect("Element is absent")
-                    - factor
-                        * *a.get_element_value(&Position(k, j))
-                            .expect("Element is absent");
+                let update = factor * a[k * n + j];
+                a[i * n + j] -= update;
+            }
+            for j in 0..m {
+                let update = factor * b[k * m + j];
+                b[i * m + j] -= update;
             }
-            *b.get_mut_element_value(&Position(i, 0))
-                .expect("Element is absent") = *b
-                .get_element_value(&Position(i, 0))
-                .expect("Element is absent")
-                - factor
-                    * *b.get_element_value(&Position(k, 0))
-                        .expect("Element is absent");
         }
     }
-    if (*a
-        .get_element_value(&Position(n - 1, n - 1))
-        .expect("Element is absent")
-        / s[n - 1])
-        .my_abs()
-        < rel_tol
-    {
-        return Err("Ill conditioned system".to_string());
-    }
-    Ok(pn)
+    Ok(swaps)
 }
 
-fn substitute_gep<V, SMT, VT1, VT2>(a: &SMT, n: usize, b: &VT1, x: &mut VT2)
+/// Back substitution of the eliminated row-major `n x n` buffer `a`, overwriting the right-hand
+/// side `x` with the solution.
+fn substitute_gep_row_major<V>(a: &[V], n: usize, x: &mut [V])
 where
-    V: FloatTrait<Output = V>,
-    SMT: SquareMatrixTrait + BasicOperationsTrait<Value = V>,
-    VT1: VectorTrait + BasicOperationsTrait<Value = V>,
-    VT2: VectorTrait + BasicOperationsTrait<Value = V>,
+    V: FieldTrait,
 {
-    *x.get_mut_element_value(&Position(n - 1, 0))
-        .expect("Element is absent") = *b
-        .get_element_value(&Position(n - 1, 0))
-        .expect("Element is absent")
-        / *a.get_element_value(&Position(n - 1, n - 1))
-            .expect("Element is absent");
+    x[n - 1] = x[n - 1] / a[(n - 1) * n + n - 1];
     for i in (0..n - 1).rev() {
         let mut sum = V::from(0f32);
         for j in i + 1..n {
-            sum += *a
-                .get_element_value(&Position(i, j))
-                .expect("Element is absent")
-                * *x.get_element_value(&Position(j, 0))
-                    .expect("Element is absent");
-        }
-        *x.get_mut_element_value(&Position(i, 0))
-            .expect("Element is absent") = (*b
-            .get_element_value(&Position(i, 0))
-            .expect("Element is absent")
-            - sum)
-            / *a.get_element_value(&Position(i, i))
-                .expect("Element is absent");
-    }
-}
-
-fn pivot_lup<V, SMT, VT>(
-    a: &mut SMT,
-    o: &mut [usize],
-    s: &[<SMT as BasicOperationsTrait>::Value],
-    n: usize,
-    k: usize,
-) where
-    V: FloatTrait,
-    SMT: SquareMatrixTrait,
-    VT: VectorTrait,
-    <SMT as BasicOperationsTrait>::Value: FloatTrait<Output = V>,
-    <VT as BasicOperationsTrait>::Value: FloatTrait<Output = V>,
-{
-    let mut p = k;
-    let mut big = (*a
-        .get_element_value(&Position(o[k], k))
-        .expect("Element is absent")
-        / s[o[k]])
-        .my_abs();
-    for ii in k + 1..n {
-        let dummy = (*a
-            .get_element_value(&Position(o[ii], k))
-            .expect("Element is absent")
-            / s[o[ii]])
-            .my_abs();
-        if dummy > big {
-            big = dummy;
-            p = ii;
+            sum += a[i * n + j] * x[j];
         }
+        x[i] = (x[i] - sum) / a[i * n + i];
     }
-    let dummy = o[p];
-    o[p] = o[k];
-    o[k] = dummy;
 }
 
-fn decompose_lup<V, SMT, VT>(
-    a: &mut SMT,
+/// LUP elimination of the row-major `n x n` buffer `lu` in place.
+fn decompose_lup_row_major<V>(
+    lu: &mut [V],
     n: usize,
-    rel_tol: <SMT as BasicOperationsTrait>::Value,
+    rel_tol: V::Real,
     o: &mut [usize],
-    s: &mut [<SMT as BasicOperationsTrait>::Value],
+    s: &mut [V::Real],
 ) -> Result<(), String>
 where
-    V: FloatTrait,
-    SMT: SquareMatrixTrait + BasicOperationsTrait<Value = V>,
-    VT: VectorTrait + BasicOperationsTrait<Value = V>,
-    <SMT as BasicOperationsTrait>::Value: FloatTrait<Output = V>,
-    <VT as BasicOperationsTrait>::Value: FloatTrait<Output = V>,
+    V: FieldTrait,
 {
+    // Rows stay in their original physical order and `o` records the pivot order; working on a
+    // row-major buffer lets the row updates be handed out to the thread pool.
     for i in 0..n {
         o[i] = i;
-        s[i] = (*a
-            .get_element_value(&Position(i, 0))
-            .expect("Element is absent"))
-        .my_abs();
+        s[i] = lu[i * n].modulus();
         for j in 1..n {
-            if (*a
-                .get_element_value(&Position(i, j))
-                .expect("Element is absent"))
-            .my_abs()
-                > s[i]
-            {
-                s[i] = (*a
-                    .get_element_value(&Position(i, j))
-                    .expect("Element is absent"))
-                .my_abs();
+            if lu[i * n + j].modulus() > s[i] {
+                s[i] = lu[i * n + j].modulus();
             }
         }
     }
+    let mut is_pivot_row = vec![false; n];
     for k in 0..n - 1 {
-        pivot_lup::<V, SMT, VT>(a, o, s, n, k);
-        if (*a
-            .get_element_value(&Position(o[k], k))
-            .expect("Element is absent")
-            / s[o[k]])
-            .my_abs()
-            < rel_tol
-        {
-            return Err("Ill conditioned system".to_string());
-        }
-        for i in k + 1..n {
-            let factor = *a
-                .get_element_value(&Position(o[i], k))
-                .expect("Element is absent")
-                / *a.get_element_value(&Position(o[k], k))
-                    .expect("Element is absent");
-            *a.get_mut_element_value(&Position(o[i], k))
-                .expect("Element is absent") = factor;
+        let p = k + select_pivot(lu, n, k, s, o[k..].iter().copied(), rel_tol)?;
+        o.swap(p, k);
+        is_pivot_row[o[k]] = true;
+        let pivot_row = lu[o[k] * n..(o[k] + 1) * n].to_vec();
+        for_each_chunk_mut(lu, n, |row, values| {
+            if is_pivot_row[row] {
+                return;
+            }
+            let factor = values[k] / pivot_row[k];
+            values[k] = factor;
             for j in k + 1..n {
-                *a.get_mut_element_value(&Position(o[i], j))
-                    .expect("Element is absent") = *a
-                    .get_element_value(&Position(o[i], j))
-                    .expect("Element is absent")
-                    - factor
-                        * *a.get_element_value(&Position(o[k], j))
-                            .expect("Element is absent");
+                values[j] -= factor * pivot_row[j];
             }
+        });
+    }
+    select_pivot(lu, n, n - 1, s, [o[n - 1]], rel_tol)?;
+    Ok(())
+}
+
+pub(crate) fn factorize_row_major<V>(
+    mut lu: Vec<V>,
+    n: usize,
+    rel_tol: V::Real,
+) -> Result<LupFactorization<V>, String>
+where
+    V: FieldTrait,
+{
+    let mut o = vec![0usize; n];
+    let mut s = vec![V::Real::from(0f32); n];
+    if n > 0 {
+        decompose_lup_row_major(&mut lu, n, rel_tol, &mut o, &mut s)?;
+    }
+    Ok(LupFactorization {
+        n,
+        lu,
+        permutation: Permutation::from_indices_unchecked(o),
+    })
+}
+
+/// LUP factors used by the iterative refinement, in the precision they were computed in.
+enum RefinementFactors {
+    Single(LupFactorization<f32>),
+    Double(LupFactorization<f64>),
+}
+
+impl RefinementFactors {
+    fn solve(&self, r: &[f64]) -> Vec<f64> {
+        match self {
+            RefinementFactors::Single(lup) => {
+                let r = r.iter().map(|value| *value as f32).collect::<Vec<f32>>();
+                lup.solve(&r)
+                    .expect("Dimensions are checked")
+                    .into_iter()
+                    .map(f64::from)
+                    .collect()
+            }
+            RefinementFactors::Double(lup) => lup.solve(r).expect("Dimensions are checked"),
         }
     }
-    if (*a
-        .get_element_value(&Position(o[n - 1], n - 1))
-        .expect("Element is absent")
-        / s[o[n - 1]])
-        .my_abs()
-        < rel_tol
-    {
-        return Err("Ill conditioned system".to_string());
+}
+
+/// Normwise backward error `‖b - A x‖∞ / (‖A‖∞ ‖x‖∞ + ‖b‖∞)` together with the residual.
+fn backward_error(a: &[f64], a_norm_inf: f64, b: &[f64], x: &[f64]) -> (f64, Vec<f64>) {
+    let n = b.len();
+    let residual = (0..n)
+        .map(|i| {
+            let mut sum = b[i];
+            for j in 0..n {
+                sum -= a[i * n + j] * x[j];
+            }
+            sum
+        })
+        .collect::<Vec<f64>>();
+    let norm_inf = |v: &[f64]| v.iter().fold(0f64, |max, value| max.max(value.abs()));
+    let denominator = a_norm_inf * norm_inf(x) + norm_inf(b);
+    let error = if denominator == 0f64 {
+        norm_inf(&residual)
+    } else {
+        norm_inf(&residual) / denominator
+    };
+    (error, residual)
+}
+
+fn decompose_lup<V, SMT>(
+    a: &mut SMT,
+    n: usize,
+    rel_tol: V::Real,
+    o: &mut [usize],
+    s: &mut [V::Real],
+) -> Result<(), String>
+where
+    V: FieldTrait,
+    SMT: SquareMatrixTrait + BasicOperationsTrait<Value = V>,
+{
+    let mut lu = row_major_values(a);
+    decompose_lup_row_major(&mut lu, n, rel_tol, o, s)?;
+    for (i, value) in lu.into_iter().enumerate() {
+        *a.get_mut_element_value(&Position(i / n, i % n))
+            .expect("Element is absent") = value;
     }
     Ok(())
 }
 
 fn substitute_lup<V, SMT, VT1, VT2>(a: &SMT, o: &[usize], n: usize, b: &mut VT1, x: &mut VT2)
 where
-    V: FloatTrait<Output = V>,
+    V: FieldTrait,
     SMT: SquareMatrixTrait + BasicOperationsTrait<Value = V>,
     VT1: VectorTrait + BasicOperationsTrait<Value = V>,
     VT2: VectorTrait + BasicOperationsTrait<Value = V>,
@@ -327,50 +336,65 @@ where
     }
 }
 
+/// `m` with its elements replaced by the row-major `values`.
+fn with_row_major_values<M>(m: &M, values: Vec<M::Value>) -> M
+where
+    M: BasicOperationsTrait + Clone,
+{
+    let columns_number = m.get_shape().1;
+    let mut result = m.clone();
+    let elements = result.get_mut_elements();
+    elements.clear();
+    for (i, value) in values.into_iter().enumerate() {
+        elements.insert(Position(i / columns_number, i % columns_number), value);
+    }
+    result
+}
+
+/// Row-major adjugate of `m`, for orders with a closed form.
+fn closed_form_adjugate<M>(m: &M) -> Result<Vec<M::Value>, String>
+where
+    M: BasicOperationsTrait,
+    M::Value: FieldTrait,
+{
+    let n = m.get_shape().0;
+    let mut adjugate = vec![M::Value::from(0f32); n * n];
+    closed_form::adjugate(&row_major_values(m), n, &mut adjugate).ok_or(format!(
+        "Closed-form adjugate is only available up to order {MAX_CLOSED_FORM_ORDER}!"
+    ))?;
+    Ok(adjugate)
+}
+
 pub trait SquareMatrixTrait: BasicOperationsTrait {
+    /// Determinant. Orders up to four use the closed forms (and report an exact zero for a
+    /// singular matrix); larger orders run the elimination, which reports zero when a pivot fails
+    /// the `rel_tol` check.
     fn determinant(
         &self,
-        rel_tol: <Self as BasicOperationsTrait>::Value,
+        rel_tol: <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real,
     ) -> <Self as BasicOperationsTrait>::Value
     where
         Self: Clone,
-        <Self as BasicOperationsTrait>::Value:
-            FloatTrait<Output = <Self as BasicOperationsTrait>::Value>,
+        <Self as BasicOperationsTrait>::Value: FieldTrait,
     {
-        let mut a = self.clone();
-        let n = a.get_shape().0;
-        let mock_b_values = vec![<<Self as BasicOperationsTrait>::Value>::from(0f32); n];
-        let mut mock_b = Vector::create(&mock_b_values);
-        let mut s = vec![<<Self as BasicOperationsTrait>::Value>::from(0f32); n];
-        for i in 0..n {
-            s[i] = (*a
-                .get_element_value(&Position(i, 0))
-                .expect("Element is absent"))
-            .my_abs();
-            for j in 1..n {
-                if (*a
-                    .get_element_value(&Position(i, j))
-                    .expect("Element is absent"))
-                .my_abs()
-                    > s[i]
-                {
-                    s[i] = (*a
-                        .get_element_value(&Position(i, j))
-                        .expect("Element is absent"))
-                    .my_abs();
-                }
-            }
+        if let Some(det) = closed_form::determinant(&row_major_values(self), self.get_shape().0) {
+            return det;
         }
-        match eliminate_gep(&mut a, &mut s, n, &mut mock_b, rel_tol) {
-            Ok(pn) => {
+        let n = self.get_shape().0;
+        let mut a = row_major_values(self);
+        let mut mock_b = vec![<<Self as BasicOperationsTrait>::Value>::from(0f32); n];
+        let mut s =
+            vec![<<Self as BasicOperationsTrait>::Value as FieldTrait>::Real::from(0f32); n];
+        match eliminate_gep_row_major(&mut a, &mut mock_b, n, &mut s, rel_tol) {
+            Ok(swaps) => {
                 let mut det = <<Self as BasicOperationsTrait>::Value>::from(1f32);
-                for i in 0..a.get_shape().0 {
-                    det *= *a
-                        .get_element_value(&Position(i, i))
-                        .expect("Element is absent");
+                for i in 0..n {
+                    det *= a[i * n + i];
+                }
+                if swaps % 2 == 1 {
+                    det *= <<Self as BasicOperationsTrait>::Value>::from(-1f32);
                 }
-                det *= <<Self as BasicOperationsTrait>::Value>::from(-1f32).my_powi(pn);
-                if det.my_is_nan() {
+                if det.is_nan() {
                     return <<Self as BasicOperationsTrait>::Value>::from(0f32);
                 }
                 det
@@ -383,7 +407,7 @@ pub trait SquareMatrixTrait: BasicOperationsTrait {
         &self,
         b: &VT1,
         x: &mut VT2,
-        rel_tol: <Self as BasicOperationsTrait>::Value,
+        rel_tol: <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real,
     ) -> Result<(), String>
     where
         VT1: VectorTrait
@@ -393,10 +417,8 @@ pub trait SquareMatrixTrait: BasicOperationsTrait {
             + BasicOperationsTrait<Value = <Self as BasicOperationsTrait>::Value>
             + Clone,
         Self: Clone,
-        <Self as BasicOperationsTrait>::Value:
-            FloatTrait<Output = <Self as BasicOperationsTrait>::Value>,
+        <Self as BasicOperationsTrait>::Value: FieldTrait,
     {
-        let mut a = self.clone();
         let mut b = b.clone();
         b.vector_shape_conformity_check()?;
         x.vector_shape_conformity_check()?;
@@ -406,48 +428,51 @@ pub trait SquareMatrixTrait: BasicOperationsTrait {
         if x.get_shape().0 == 1 {
             *x = x.transpose();
         }
-        a.shape_conformity_check(&b, Operation::Multiplication)?;
+        self.shape_conformity_check(&b, Operation::Multiplication)?;
         b.shape_conformity_check(x, Operation::Addition)?;
 
-        let n = a.get_shape().0;
-        let mut s = vec![<<Self as BasicOperationsTrait>::Value>::from(0f32); n];
-        for i in 0..n {
-            s[i] = (*a
-                .get_element_value(&Position(i, 0))
-                .expect("Element is absent"))
-            .my_abs();
-            for j in 1..n {
-                if (*a
-                    .get_element_value(&Position(i, j))
-                    .expect("Element is absent"))
-                .my_abs()
-                    > s[i]
-                {
-                    s[i] = (*a
-                        .get_element_value(&Position(i, j))
-                        .expect("Element is absent"))
-                    .my_abs();
-                }
-            }
+        let n = self.get_shape().0;
+        let mut a = row_major_values(self);
+        let mut b = row_major_values(&b);
+        let mut s =
+            vec![<<Self as BasicOperationsTrait>::Value as FieldTrait>::Real::from(0f32); n];
+        eliminate_gep_row_major(&mut a, &mut b, n, &mut s, rel_tol)?;
+        substitute_gep_row_major(&a, n, &mut b);
+        for (i, value) in b.into_iter().enumerate() {
+            *x.get_mut_element_value(&Position(i, 0))
+                .expect("Element is absent") = value;
         }
-        let _ = eliminate_gep(&mut a, &mut s, n, &mut b, rel_tol)?;
-        substitute_gep(&a, n, &b, x);
         Ok(())
     }
 
+    /// Inverse. Orders up to four divide the closed-form adjugate by the determinant and fail with
+    /// "Singular matrix" / "Ill conditioned system" instead of dividing by a vanishing determinant;
+    /// `x` is only used as workspace by the elimination of larger orders.
     fn inverse<VT>(
         &self,
         x: &mut VT,
-        rel_tol: <Self as BasicOperationsTrait>::Value,
+        rel_tol: <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real,
     ) -> Result<Self, String>
     where
         Self: Clone,
-        <Self as BasicOperationsTrait>::Value:
-            FloatTrait<Output = <Self as BasicOperationsTrait>::Value>,
+        <Self as BasicOperationsTrait>::Value: FieldTrait,
         VT: VectorTrait
             + BasicOperationsTrait<Value = <Self as BasicOperationsTrait>::Value>
             + Clone,
     {
+        let n = self.get_shape().0;
+        if n <= MAX_CLOSED_FORM_ORDER {
+            let a = row_major_values(self);
+            let det = closed_form::determinant(&a, n).expect("Order has a closed form");
+            closed_form::check_determinant(det, &a, n, rel_tol)?;
+            let mut inverse = vec![<Self as BasicOperationsTrait>::Value::from(0f32); n * n];
+            closed_form::adjugate(&a, n, &mut inverse).expect("Order has a closed form");
+            for value in inverse.iter_mut() {
+                *value = *value / det;
+            }
+            return Ok(with_row_major_values(self, inverse));
+        }
+
         let mut a_i = self.clone();
         for value in a_i.get_mut_elements().values_mut() {
             *value = <<Self as BasicOperationsTrait>::Value>::from(0f32);
@@ -456,11 +481,10 @@ pub trait SquareMatrixTrait: BasicOperationsTrait {
         let mut a = self.clone();
         let n = a.get_shape().0;
         let mut o = vec![0usize; n];
-        let mut s = vec![<<Self as BasicOperationsTrait>::Value>::from(0f32); n];
+        let mut s =
+            vec![<<Self as BasicOperationsTrait>::Value as FieldTrait>::Real::from(0f32); n];
 
-        decompose_lup::<<Self as BasicOperationsTrait>::Value, Self, VT>(
-            &mut a, n, rel_tol, &mut o, &mut s,
-        )?;
+        decompose_lup(&mut a, n, rel_tol, &mut o, &mut s)?;
 
         let b_values = vec![<<Self as BasicOperationsTrait>::Value>::from(0f32); n];
         let mut b = Vector::create(&b_values);
@@ -491,11 +515,70 @@ pub trait SquareMatrixTrait: BasicOperationsTrait {
         Ok(a_i)
     }
 
+    /// Adjugate (transposed cofactor matrix), `A * adj(A) = det(A) * I`; defined for singular
+    /// matrices as well. Available up to order four.
+    fn adjugate(&self) -> Result<Self, String>
+    where
+        Self: Clone,
+        <Self as BasicOperationsTrait>::Value: FieldTrait,
+    {
+        Ok(with_row_major_values(self, closed_form_adjugate(self)?))
+    }
+
+    /// Matrix of the cofactors `(-1)^(i + j) * M_ij`. Available up to order four.
+    fn cofactor_matrix(&self) -> Result<Self, String>
+    where
+        Self: Clone,
+        <Self as BasicOperationsTrait>::Value: FieldTrait,
+    {
+        Ok(self.adjugate()?.transpose())
+    }
+
+    /// Solves `A * X = B` for all columns of `B` (one load case per column) with a single
+    /// elimination; column `j` of the result is what `gauss_gep` gives for column `j` of `B`. With
+    /// the `rayon` feature the back substitutions run in parallel.
+    fn gauss_gep_matrix<M>(
+        &self,
+        b: &M,
+        rel_tol: <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real,
+    ) -> Result<Matrix<<Self as BasicOperationsTrait>::Value>, String>
+    where
+        M: BasicOperationsTrait<Value = <Self as BasicOperationsTrait>::Value>,
+        <Self as BasicOperationsTrait>::Value: FieldTrait,
+    {
+        self.shape_conformity_check(b, Operation::Multiplication)?;
+        let Shape(n, m) = *b.get_shape();
+        let mut a = row_major_values(self);
+        let mut x = row_major_values(b);
+        if n > 0 {
+            let mut s =
+                vec![<<Self as BasicOperationsTrait>::Value as FieldTrait>::Real::from(0f32); n];
+            eliminate_gep_row_major(&mut a, &mut x, n, &mut s, rel_tol)?;
+            for_each_column_mut(&mut x, n, |column| substitute_gep_row_major(&a, n, column));
+        }
+        Ok(Matrix::from_row_major(n, m, x))
+    }
+
+    /// Solves `A * X = B` for all columns of `B` with a single LUP factorization; see
+    /// `LupFactorization::solve_matrix`.
+    fn lup_decomp_matrix<M>(
+        &self,
+        b: &M,
+        rel_tol: <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real,
+    ) -> Result<Matrix<<Self as BasicOperationsTrait>::Value>, String>
+    where
+        M: BasicOperationsTrait<Value = <Self as BasicOperationsTrait>::Value>,
+        <Self as BasicOperationsTrait>::Value: FieldTrait,
+    {
+        self.shape_conformity_check(b, Operation::Multiplication)?;
+        self.lup_factorization(rel_tol)?.solve_matrix(b)
+    }
+
     fn lup_decomp<VT1, VT2>(
         &self,
         b: &VT1,
         x: &mut VT2,
-        rel_tol: <Self as BasicOperationsTrait>::Value,
+        rel_tol: <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real,
     ) -> Result<(), String>
     where
         VT1: VectorTrait
@@ -505,8 +588,7 @@ pub trait SquareMatrixTrait: BasicOperationsTrait {
             + BasicOperationsTrait<Value = <Self as BasicOperationsTrait>::Value>
             + Clone,
         Self: Clone,
-        <Self as BasicOperationsTrait>::Value:
-            FloatTrait<Output = <Self as BasicOperationsTrait>::Value>,
+        <Self as BasicOperationsTrait>::Value: FieldTrait,
     {
         let mut a = self.clone();
         let mut b = b.clone();
@@ -523,13 +605,299 @@ pub trait SquareMatrixTrait: BasicOperationsTrait {
 
         let n = a.get_shape().0;
         let mut o = vec![0usize; n];
-        let mut s = vec![<<Self as BasicOperationsTrait>::Value>::from(0f32); n];
+        let mut s =
+            vec![<<Self as BasicOperationsTrait>::Value as FieldTrait>::Real::from(0f32); n];
 
-        decompose_lup::<<Self as BasicOperationsTrait>::Value, Self, VT1>(
-            &mut a, n, rel_tol, &mut o, &mut s,
-        )?;
+        decompose_lup(&mut a, n, rel_tol, &mut o, &mut s)?;
         substitute_lup(&a, &o, n, &mut b, x);
 
         Ok(())
     }
+
+    /// Runs the LUP elimination once and keeps the factors for repeated solves.
+    fn lup_factorization(
+        &self,
+        rel_tol: <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real,
+    ) -> Result<LupFactorization<<Self as BasicOperationsTrait>::Value>, String>
+    where
+        <Self as BasicOperationsTrait>::Value: FieldTrait,
+    {
+        let n = self.get_shape().0;
+        if n != self.get_shape().1 {
+            return Err("Matrix is not square!".to_string());
+        }
+        factorize_row_major(row_major_values(self), n, rel_tol)
+    }
+
+    /// Determinant from the LUP factors. Unlike `determinant`, a pivot that fails the `rel_tol`
+    /// check or a NaN result is reported as an error instead of being turned into zero: an exactly
+    /// zero pivot as "Singular matrix", a small one as "Ill conditioned system".
+    fn try_determinant(
+        &self,
+        rel_tol: <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real,
+    ) -> Result<<Self as BasicOperationsTrait>::Value, String>
+    where
+        <Self as BasicOperationsTrait>::Value: FieldTrait,
+    {
+        let det = self.lup_factorization(rel_tol)?.determinant();
+        if det.is_nan() {
+            return Err("Determinant is not a number!".to_string());
+        }
+        Ok(det)
+    }
+
+    /// `(sign, log|det|)` from the LUP factors; see `LupFactorization::log_determinant`. Stays
+    /// finite where the determinant itself would overflow or underflow.
+    fn log_determinant(
+        &self,
+        rel_tol: <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real,
+    ) -> Result<
+        (
+            <Self as BasicOperationsTrait>::Value,
+            <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real,
+        ),
+        String,
+    >
+    where
+        <Self as BasicOperationsTrait>::Value: FieldTrait,
+        <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real:
+            FloatTrait<Output = <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real>,
+    {
+        let (sign, log_modulus) = self.lup_factorization(rel_tol)?.log_determinant();
+        if sign.is_nan() || log_modulus.is_nan() {
+            return Err("Determinant is not a number!".to_string());
+        }
+        Ok((sign, log_modulus))
+    }
+
+    /// `A^exponent` by binary powering; a negative exponent inverts `A` first (with `rel_tol` as
+    /// in `lup_factorization`), `A^0 = I`.
+    fn powi(
+        &self,
+        exponent: i32,
+        rel_tol: <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real,
+    ) -> Result<Self, String>
+    where
+        Self: Clone,
+        <Self as BasicOperationsTrait>::Value: FieldTrait,
+    {
+        let n = self.get_shape().0;
+        let values = matrix_functions::powi(&row_major_values(self), n, exponent, rel_tol)?;
+        Ok(with_row_major_values(self, values))
+    }
+
+    /// Matrix exponential `e^A` (scaling and squaring with Padé approximants). Returns `Err` when
+    /// an entry of the result overflows.
+    fn expm(&self) -> Result<Self, String>
+    where
+        Self: Clone,
+        <Self as BasicOperationsTrait>::Value: FieldTrait,
+        <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real:
+            FloatTrait<Output = <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real>,
+    {
+        let n = self.get_shape().0;
+        let values = matrix_functions::expm(&row_major_values(self), n)?;
+        Ok(with_row_major_values(self, values))
+    }
+
+    /// Principal square root (Denman–Beavers iteration). Stops once an update changes the
+    /// iterate by at most `tolerance` relative to its 1-norm; fails if `A` is singular or the
+    /// iteration does not converge (e.g. for eigenvalues on the negative real axis).
+    fn sqrtm(
+        &self,
+        tolerance: <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real,
+        max_iterations: usize,
+    ) -> Result<Self, String>
+    where
+        Self: Clone,
+        <Self as BasicOperationsTrait>::Value: FieldTrait,
+        <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real:
+            FloatTrait<Output = <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real>,
+    {
+        let n = self.get_shape().0;
+        let values =
+            matrix_functions::sqrtm(&row_major_values(self), n, tolerance, max_iterations)?;
+        Ok(with_row_major_values(self, values))
+    }
+
+    /// Principal logarithm (inverse scaling and squaring). `tolerance` and `max_iterations` apply
+    /// to the square roots and to the final series.
+    fn logm(
+        &self,
+        tolerance: <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real,
+        max_iterations: usize,
+    ) -> Result<Self, String>
+    where
+        Self: Clone,
+        <Self as BasicOperationsTrait>::Value: FieldTrait,
+        <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real:
+            FloatTrait<Output = <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real>,
+    {
+        let n = self.get_shape().0;
+        let values = matrix_functions::logm(&row_major_values(self), n, tolerance, max_iterations)?;
+        Ok(with_row_major_values(self, values))
+    }
+
+    /// Estimate of the 1-norm condition number `‖A‖₁ ‖A⁻¹‖₁` (a lower bound, usually within a
+    /// factor of three of the true value).
+    fn condition_number_estimate(
+        &self,
+        rel_tol: <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real,
+    ) -> Result<<<Self as BasicOperationsTrait>::Value as FieldTrait>::Real, String>
+    where
+        Self: MatrixNormTrait,
+        <Self as BasicOperationsTrait>::Value: FieldTrait,
+    {
+        let factorization = self.lup_factorization(rel_tol)?;
+        Ok(self.norm_1() * factorization.inverse_norm_1_estimate())
+    }
+
+    /// `lup_decomp` that refuses to solve when the estimated 1-norm condition number exceeds
+    /// `max_condition_number`. The estimate is returned on success, so callers can judge how
+    /// many digits of `x` to trust.
+    fn lup_decomp_checked<VT1, VT2>(
+        &self,
+        b: &VT1,
+        x: &mut VT2,
+        rel_tol: <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real,
+        max_condition_number: <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real,
+    ) -> Result<<<Self as BasicOperationsTrait>::Value as FieldTrait>::Real, String>
+    where
+        VT1: VectorTrait
+            + BasicOperationsTrait<Value = <Self as BasicOperationsTrait>::Value>
+            + Clone,
+        VT2: VectorTrait
+            + BasicOperationsTrait<Value = <Self as BasicOperationsTrait>::Value>
+            + Clone,
+        Self: MatrixNormTrait,
+        <Self as BasicOperationsTrait>::Value: FieldTrait,
+    {
+        let mut b = b.clone();
+        b.vector_shape_conformity_check()?;
+        x.vector_shape_conformity_check()?;
+        if b.get_shape().0 == 1 {
+            b = b.transpose();
+        }
+        if x.get_shape().0 == 1 {
+            *x = x.transpose();
+        }
+        self.shape_conformity_check(&b, Operation::Multiplication)?;
+        b.shape_conformity_check(x, Operation::Addition)?;
+
+        let factorization = self.lup_factorization(rel_tol)?;
+        let condition_number = self.norm_1() * factorization.inverse_norm_1_estimate();
+        if condition_number > max_condition_number {
+            return Err(format!(
+                "Ill conditioned system: estimated condition number {condition_number:?} exceeds \
+                {max_condition_number:?}"
+            ));
+        }
+        let solution = factorization.solve(&row_major_values(&b))?;
+        for (i, value) in solution.into_iter().enumerate() {
+            *x.get_mut_element_value(&Position(i, 0))
+                .expect("Element is absent") = value;
+        }
+
+        Ok(condition_number)
+    }
+
+    /// Mixed-precision iterative refinement.
+    ///
+    /// `A` is factored once, in `f32` or `f64` as selected by `precision`. Starting from the plain
+    /// LUP solution, the residual `r = b - A x` is evaluated in `f64` against the original
+    /// matrix and the correction `A d = r` is solved with the stored factors. Refinement stops
+    /// when the normwise backward error `‖r‖∞ / (‖A‖∞ ‖x‖∞ + ‖b‖∞)` drops to `tolerance`, after
+    /// `max_iterations` corrections, or when a correction no longer halves the error (the
+    /// factorization is then too inaccurate for the conditioning of `A`).
+    ///
+    /// The best solution found is written to `x` and its backward error is returned; it may
+    /// exceed `tolerance` when refinement stopped early.
+    fn lup_decomp_refined<VT1, VT2>(
+        &self,
+        b: &VT1,
+        x: &mut VT2,
+        rel_tol: <<Self as BasicOperationsTrait>::Value as FieldTrait>::Real,
+        precision: Precision,
+        tolerance: f64,
+        max_iterations: usize,
+    ) -> Result<f64, String>
+    where
+        VT1: VectorTrait
+            + BasicOperationsTrait<Value = <Self as BasicOperationsTrait>::Value>
+            + Clone,
+        VT2: VectorTrait
+            + BasicOperationsTrait<Value = <Self as BasicOperationsTrait>::Value>
+            + Clone,
+        <Self as BasicOperationsTrait>::Value: FieldTrait<Real = <Self as BasicOperationsTrait>::Value>
+            + FloatTrait<Output = <Self as BasicOperationsTrait>::Value>,
+    {
+        let mut b = b.clone();
+        b.vector_shape_conformity_check()?;
+        x.vector_shape_conformity_check()?;
+        if b.get_shape().0 == 1 {
+            b = b.transpose();
+        }
+        if x.get_shape().0 == 1 {
+            *x = x.transpose();
+        }
+        self.shape_conformity_check(&b, Operation::Multiplication)?;
+        b.shape_conformity_check(x, Operation::Addition)?;
+
+        let n = self.get_shape().0;
+        let a = row_major_values(self)
+            .into_iter()
+            .map(|value| value.into())
+            .collect::<Vec<f64>>();
+        let b = row_major_values(&b)
+            .into_iter()
+            .map(|value| value.into())
+            .collect::<Vec<f64>>();
+        let rel_tol: f64 = rel_tol.into();
+
+        let factors = match precision {
+            Precision::Single => RefinementFactors::Single(factorize_row_major(
+                a.iter().map(|value| *value as f32).collect(),
+                n,
+                rel_tol as f32,
+            )?),
+            Precision::Double => {
+                RefinementFactors::Double(factorize_row_major(a.clone(), n, rel_tol)?)
+            }
+        };
+
+        let a_norm_inf = (0..n)
+            .map(|i| a[i * n..(i + 1) * n].iter().map(|value| value.abs()).sum())
+            .fold(0f64, f64::max);
+
+        let mut solution = factors.solve(&b);
+        let (mut error, mut residual) = backward_error(&a, a_norm_inf, &b, &solution);
+        for _ in 0..max_iterations {
+            if error <= tolerance {
+                break;
+            }
+            let correction = factors.solve(&residual);
+            let candidate = solution
+                .iter()
+                .zip(&correction)
+                .map(|(value, delta)| value + delta)
+                .collect::<Vec<f64>>();
+            let (candidate_error, candidate_residual) =
+                backward_error(&a, a_norm_inf, &b, &candidate);
+            let stagnated = candidate_error.is_nan() || candidate_error >= 0.5 * error;
+            if candidate_error < error {
+                (solution, error, residual) = (candidate, candidate_error, candidate_residual);
+            }
+            if stagnated {
+                break;
+            }
+        }
+
+        for (i, value) in solution.into_iter().enumerate() {
+            *x.get_mut_element_value(&Position(i, 0))
+                .expect("Element is absent") =
+                <<Self as BasicOperationsTrait>::Value as FloatTrait>::from_f64(value);
+        }
+
+        Ok(error)
+    }
 }
@@ -2,8 +2,8 @@
 use extended_matrix_float::MyFloatTrait;
 
 use crate::FloatTrait;
-use crate::Position;
 use crate::{BasicOperationsTrait, IntoMatrixTrait};
+use crate::{Position, Shape};
 
 pub trait VectorTrait: IntoMatrixTrait {
     fn vector_shape_conformity_check(&self) -> Result<(), String> {
@@ -33,18 +33,27 @@ pub trait VectorTrait: IntoMatrixTrait {
     where
         <Self as BasicOperationsTrait>::Value:
             FloatTrait<Output = <Self as BasicOperationsTrait>::Value>,
-        Self: Clone,
     {
         self.vector_shape_conformity_check()?;
         other.vector_shape_conformity_check()?;
-        let mut lhs = self.clone();
-        let mut rhs = other.clone();
-        if lhs.get_shape().1 == 1 {
-            lhs = lhs.transpose();
+        let (lhs_shape, rhs_shape) = (self.get_shape(), other.get_shape());
+        let length = lhs_shape.0 * lhs_shape.1;
+        if length != rhs_shape.0 * rhs_shape.1 {
+            return Err("Shapes of matrices do not conform to each other!".to_string());
         }
-        if rhs.get_shape().0 == 1 {
-            rhs = rhs.transpose();
+        // Rows and columns are read in place instead of transposing one of the operands.
+        let position = |shape: &Shape, i: usize| {
+            if shape.1 == 1 {
+                Position(i, 0)
+            } else {
+                Position(0, i)
+            }
+        };
+        let mut value = <<Self as BasicOperationsTrait>::Value>::from(0f32);
+        for i in 0..length {
+            value += *self.get_element_value(&position(lhs_shape, i))?
+                * *other.get_element_value(&position(rhs_shape, i))?;
         }
-        Ok(*lhs.multiply(&rhs)?.get_element_value(&Position(0, 0))?)
+        Ok(value)
     }
 }
@@ -2,7 +2,7 @@
 use std::collections::HashMap;
 use std::fmt::Debug;
 
-use crate::{BasicOperationsTrait, IntoMatrixTrait, VectorTrait};
+use crate::{BasicOperationsTrait, IntoMatrixTrait, MatrixNormTrait, PermuteTrait, VectorTrait};
 use crate::{Position, Shape};
 
 #[derive(Debug, PartialEq, Clone)]
@@ -35,6 +35,10 @@ impl<V> IntoMatrixTrait for Vector<V> {}
 
 impl<V> VectorTrait for Vector<V> {}
 
+impl<V> MatrixNormTrait for Vector<V> {}
+
+impl<V> PermuteTrait for Vector<V> {}
+
 impl<V> Vector<V>
 where
     V: Debug + Copy + From<f32>,
@@ -4,8 +4,8 @@ use std::fmt::Debug;
 use std::ops::{Mul, Sub};
 
 use crate::FloatTrait;
-use crate::{BasicOperationsTrait, IntoMatrixTrait, VectorTrait};
-use crate::{Matrix, Position, Shape};
+use crate::{BasicOperationsTrait, IntoMatrixTrait, MatrixNormTrait, PermuteTrait, VectorTrait};
+use crate::{Matrix, Position, Quaternion, Shape};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Vector3<V> {
@@ -37,6 +37,10 @@ impl<V> IntoMatrixTrait for Vector3<V> {}
 
 impl<V> VectorTrait for Vector3<V> {}
 
+impl<V> MatrixNormTrait for Vector3<V> {}
+
+impl<V> PermuteTrait for Vector3<V> {}
+
 impl<V> Vector3<V>
 where
     V: Debug + Copy + From<f32>,
@@ -144,6 +148,33 @@ where
             .multiply_by_scalar(V::from(1f32) / other.norm().expect("Norm could not be calculated"))
     }
 
+    /// `cosine_angle_between_vectors` that fails instead of returning NaN for a zero length vector.
+    pub fn try_cosine_angle_between_vectors(&self, other: &Self) -> Result<V, String>
+    where
+        V: FloatTrait<Output = V>,
+    {
+        let norms = self.norm()? * other.norm()?;
+        if norms == V::from(0f32) {
+            return Err("Vector3: angle with a zero length vector is undefined!".to_string());
+        }
+        Ok(self.dot_product(other)? / norms)
+    }
+
+    /// `projection_perpendicular_to_vector` that fails instead of returning NaN when `other` has
+    /// zero length.
+    pub fn try_projection_perpendicular_to_vector(&self, other: &Self) -> Result<Self, String>
+    where
+        V: FloatTrait<Output = V>,
+    {
+        if other.norm()? == V::from(0f32) {
+            return Err(
+                "Vector3: projection perpendicular to a zero length vector is undefined!"
+                    .to_string(),
+            );
+        }
+        Ok(self.projection_perpendicular_to_vector(other))
+    }
+
     fn check_vectors_lenghts_are_the_same(&self, other: &Self, rel_tol: V) -> Result<(), String>
     where
         V: FloatTrait<Output = V>,
@@ -156,12 +187,40 @@ where
                 (rhs_norm, lhs_norm)
             }
         };
+        if max_length == V::from(0f32) {
+            return Err("Vector3: zero length vectors could not be aligned!".to_string());
+        }
         if (max_length - min_length) / min_length > rel_tol {
             return Err("Vectors with different lenghts could not be aligned".to_string());
         }
         Ok(())
     }
 
+    /// Unit vector perpendicular to `self`: `self` crossed with the coordinate axis along which
+    /// `self` has its smallest component (the first one on ties), normalized.
+    fn perpendicular_unit_axis(&self) -> Result<[V; 3], String>
+    where
+        V: FloatTrait<Output = V>,
+    {
+        let components = self.get_components();
+        let mut smallest = 0;
+        for i in 1..3 {
+            if components[i].my_abs() < components[smallest].my_abs() {
+                smallest = i;
+            }
+        }
+        let mut axis_components = [V::from(0f32); 3];
+        axis_components[smallest] = V::from(1f32);
+        let perpendicular = self.cross_product(&Vector3::create(&axis_components));
+        let norm = perpendicular.norm()?;
+        if norm == V::from(0f32) {
+            return Err("Zero length vector has no perpendicular axis".to_string());
+        }
+        Ok(perpendicular
+            .get_components()
+            .map(|component| component / norm))
+    }
+
     pub fn rotation_matrix_to_align_with_vector(
         &self,
         other: &Self,
@@ -172,7 +231,7 @@ where
         V: FloatTrait<Output = V>,
     {
         self.check_vectors_lenghts_are_the_same(other, rel_tol)?;
-        let c = self.cosine_angle_between_vectors(other);
+        let c = self.try_cosine_angle_between_vectors(other)?;
         if V::from(1f32) - c < abs_tol {
             return Ok(Matrix::create(
                 3,
@@ -191,40 +250,16 @@ where
             ));
         }
         if V::from(1f32) + c < abs_tol {
-            if self.get_components()[..2] == [V::from(0f32); 2]
-                && other.get_components()[..2] == [V::from(0f32); 2]
-            {
-                return Ok(Matrix::create(
-                    3,
-                    3,
-                    &[
-                        V::from(-1.0),
-                        V::from(0.0),
-                        V::from(0.0),
-                        V::from(0.0),
-                        V::from(1.0),
-                        V::from(0.0),
-                        V::from(0.0),
-                        V::from(0.0),
-                        V::from(-1.0),
-                    ],
-                ));
+            // Half turn about an axis u perpendicular to `self`: R = 2 u uᵀ - I.
+            let u = self.perpendicular_unit_axis()?;
+            let mut values = [V::from(0f32); 9];
+            for i in 0..3 {
+                for j in 0..3 {
+                    values[i * 3 + j] = V::from(2f32) * u[i] * u[j];
+                }
+                values[i * 3 + i] -= V::from(1f32);
             }
-            return Ok(Matrix::create(
-                3,
-                3,
-                &[
-                    V::from(-1.0),
-                    V::from(0.0),
-                    V::from(0.0),
-                    V::from(0.0),
-                    V::from(-1.0),
-                    V::from(0.0),
-                    V::from(0.0),
-                    V::from(0.0),
-                    V::from(-1.0),
-                ],
-            ));
+            return Ok(Matrix::create(3, 3, &values));
         }
         let axis = self.cross_product(other);
         let axis_norm = axis.norm()?;
@@ -249,4 +284,240 @@ where
         );
         Ok(rotation_matrix)
     }
+
+    /// Minimal rotation taking the direction of `self` to the direction of `other`, as a unit
+    /// quaternion. Tolerances and the antiparallel case (a half turn about an axis perpendicular
+    /// to `self`) follow `rotation_matrix_to_align_with_vector`.
+    pub fn quaternion_to_align_with_vector(
+        &self,
+        other: &Self,
+        rel_tol: V,
+        abs_tol: V,
+    ) -> Result<Quaternion<V>, String>
+    where
+        V: FloatTrait<Output = V>,
+    {
+        self.check_vectors_lenghts_are_the_same(other, rel_tol)?;
+        let c = self.try_cosine_angle_between_vectors(other)?;
+        let (zero, one, half) = (V::from(0f32), V::from(1f32), V::from(0.5f32));
+        if one - c < abs_tol {
+            return Ok(Quaternion::identity());
+        }
+        if one + c < abs_tol {
+            let [x, y, z] = self.perpendicular_unit_axis()?;
+            return Ok(Quaternion::create(zero, x, y, z));
+        }
+        let axis = self.cross_product(other);
+        let axis_norm = axis.norm()?;
+        let [x, y, z] = axis.get_components();
+        // cos(θ/2) and sin(θ/2) from cos θ.
+        let (w, s) = ((half * (one + c)).my_sqrt(), (half * (one - c)).my_sqrt());
+        Ok(Quaternion::create(
+            w,
+            x / axis_norm * s,
+            y / axis_norm * s,
+            z / axis_norm * s,
+        ))
+    }
+
+    /// Direction cosine matrix of a local frame whose x axis points along `self`.
+    ///
+    /// The rows of the result are the local x, y and z unit vectors in global coordinates, so it
+    /// maps global components to local ones. `reference` fixes the local x-y plane: local
+    /// z = x × reference and local y = z × x. Without a reference, or when the reference is
+    /// (anti)parallel to `self`, the global Z axis is used, and the global Y axis for members
+    /// along Z. Vectors are treated as parallel when the sine of the angle between them is
+    /// below 1e-6.
+    pub fn orthonormal_frame(&self, reference: Option<&Self>) -> Result<Matrix<V>, String>
+    where
+        V: FloatTrait<Output = V>,
+    {
+        let (zero, one) = (V::from(0f32), V::from(1f32));
+        let parallel_tol = V::from(1e-6f32);
+        let cross = |a: [V; 3], b: [V; 3]| {
+            [
+                a[1] * b[2] - a[2] * b[1],
+                a[2] * b[0] - a[0] * b[2],
+                a[0] * b[1] - a[1] * b[0],
+            ]
+        };
+        let length = |a: [V; 3]| (a[0] * a[0] + a[1] * a[1] + a[2] * a[2]).my_sqrt();
+
+        let axis = self.get_components();
+        let axis_length = length(axis);
+        if axis_length == zero {
+            return Err("Vector3: frame axis has zero length!".to_string());
+        }
+        let x = axis.map(|component| component / axis_length);
+
+        // |x × r| / |r| is the sine of the angle between the axis and the reference.
+        let is_usable = |r: [V; 3]| {
+            let r_length = length(r);
+            r_length != zero && length(cross(x, r)) / r_length >= parallel_tol
+        };
+        let global_z = [zero, zero, one];
+        let reference = match reference.map(|r| r.get_components()) {
+            Some(r) if is_usable(r) => r,
+            _ if is_usable(global_z) => global_z,
+            _ => [zero, one, zero],
+        };
+
+        let z = cross(x, reference);
+        let z_length = length(z);
+        let z = z.map(|component| component / z_length);
+        let y = cross(z, x);
+
+        Ok(Matrix::create(
+            3,
+            3,
+            &[x[0], x[1], x[2], y[0], y[1], y[2], z[0], z[1], z[2]],
+        ))
+    }
+
+    /// Copy of `self` in column orientation, so results do not depend on how inputs are shaped.
+    fn as_column(&self) -> Self {
+        Vector3::create(&self.get_components())
+    }
+
+    /// Unit vector along `self`.
+    pub fn normalize(&self) -> Result<Self, String>
+    where
+        V: FloatTrait<Output = V>,
+    {
+        let norm = self.norm()?;
+        if norm == V::from(0f32) {
+            return Err("Vector3: zero length vector could not be normalized!".to_string());
+        }
+        Ok(Vector3::create(
+            &self.get_components().map(|component| component / norm),
+        ))
+    }
+
+    /// Angle in `(-π, π]` that turns `self` towards `other` about `axis` (right-hand rule).
+    /// Only the components perpendicular to `axis` matter.
+    pub fn signed_angle_about_axis(&self, other: &Self, axis: &Self) -> Result<V, String>
+    where
+        V: FloatTrait<Output = V>,
+    {
+        let n = axis.normalize()?;
+        let lhs = self
+            .as_column()
+            .subtract(&self.projection_onto_vector(&n)?)?;
+        let rhs = other
+            .as_column()
+            .subtract(&other.projection_onto_vector(&n)?)?;
+        if lhs.norm()? == V::from(0f32) || rhs.norm()? == V::from(0f32) {
+            return Err(
+                "Vector3: angle about an axis is undefined for vectors along it!".to_string(),
+            );
+        }
+        let sine = lhs.cross_product(&rhs).dot_product(&n)?;
+        let cosine = lhs.dot_product(&rhs)?;
+        Ok(sine.my_atan2(&cosine))
+    }
+
+    /// Component of `self` along `other`: `(self · other / other · other) other`.
+    pub fn projection_onto_vector(&self, other: &Self) -> Result<Self, String>
+    where
+        V: FloatTrait<Output = V>,
+    {
+        let other_norm_sqr = other.dot_product(other)?;
+        if other_norm_sqr == V::from(0f32) {
+            return Err("Vector3: projection onto a zero length vector is undefined!".to_string());
+        }
+        let factor = self.dot_product(other)? / other_norm_sqr;
+        Ok(Vector3::create(
+            &other.get_components().map(|component| component * factor),
+        ))
+    }
+
+    /// Mirror image of `self` in the plane through the origin with the given `normal`.
+    pub fn reflection(&self, normal: &Self) -> Result<Self, String>
+    where
+        V: FloatTrait<Output = V>,
+    {
+        let projection = self.projection_onto_vector(normal)?;
+        self.as_column()
+            .subtract(&projection.multiply_by_scalar(V::from(2f32)))
+    }
+
+    /// `self · (b × c)`, the signed volume of the parallelepiped spanned by the three vectors.
+    pub fn scalar_triple_product(&self, b: &Self, c: &Self) -> V
+    where
+        V: FloatTrait<Output = V>,
+    {
+        let [a_x, a_y, a_z] = self.get_components();
+        let [x, y, z] = b.cross_product(c).get_components();
+        a_x * x + a_y * y + a_z * z
+    }
+
+    /// `self × (b × c)`.
+    pub fn vector_triple_product(&self, b: &Self, c: &Self) -> Self
+    where
+        V: FloatTrait<Output = V>,
+    {
+        self.as_column().cross_product(&b.cross_product(c))
+    }
+
+    /// Euclidean distance between the points `self` and `other`.
+    pub fn distance(&self, other: &Self) -> V
+    where
+        V: FloatTrait<Output = V>,
+    {
+        let [x_1, y_1, z_1] = self.get_components();
+        let [x_2, y_2, z_2] = other.get_components();
+        let (d_x, d_y, d_z) = (x_2 - x_1, y_2 - y_1, z_2 - z_1);
+        (d_x * d_x + d_y * d_y + d_z * d_z).my_sqrt()
+    }
+
+    /// Distance from the point `self` to the line through `point` along `direction`.
+    pub fn distance_to_line(&self, point: &Self, direction: &Self) -> Result<V, String>
+    where
+        V: FloatTrait<Output = V>,
+    {
+        let offset = self.as_column().subtract(&point.as_column())?;
+        let perpendicular = offset.subtract(&offset.projection_onto_vector(direction)?)?;
+        perpendicular.norm()
+    }
+
+    /// Signed distance from the point `self` to the plane through `point` with the given
+    /// `normal`; positive on the side `normal` points to.
+    pub fn distance_to_plane(&self, point: &Self, normal: &Self) -> Result<V, String>
+    where
+        V: FloatTrait<Output = V>,
+    {
+        let n = normal.normalize()?;
+        let offset = self.as_column().subtract(&point.as_column())?;
+        offset.dot_product(&n)
+    }
+
+    /// Area of the triangle with vertices `p_1`, `p_2`, `p_3`.
+    pub fn triangle_area(p_1: &Self, p_2: &Self, p_3: &Self) -> Result<V, String>
+    where
+        V: FloatTrait<Output = V>,
+    {
+        let doubled = Vector3::triangle_doubled_area_vector(p_1, p_2, p_3)?;
+        Ok(doubled.norm()? / V::from(2f32))
+    }
+
+    /// Unit normal of the triangle `p_1`, `p_2`, `p_3`, oriented by the right-hand rule (counter
+    /// clockwise vertices seen from the tip of the normal).
+    pub fn triangle_normal(p_1: &Self, p_2: &Self, p_3: &Self) -> Result<Self, String>
+    where
+        V: FloatTrait<Output = V>,
+    {
+        Vector3::triangle_doubled_area_vector(p_1, p_2, p_3)?
+            .normalize()
+            .map_err(|_| "Vector3: degenerate triangle has no normal!".to_string())
+    }
+
+    fn triangle_doubled_area_vector(p_1: &Self, p_2: &Self, p_3: &Self) -> Result<Self, String>
+    where
+        V: FloatTrait<Output = V>,
+    {
+        let p_1 = p_1.as_column();
+        let edge_1 = p_2.as_column().subtract(&p_1)?;
+        let edge_2 = p_3.as_column().subtract(&p_1)?;
+        Ok(edge_1.cross_product(&edge_2))
+    }
 }
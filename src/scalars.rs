@@ -0,0 +1,10 @@
+//! Scalar types beyond the built-in floats.
+//!
+//! They implement `FieldTrait`, so the square-matrix algorithms (elimination, LUP, determinant,
+//! inverse) run on them unchanged.
+
+mod complex;
+mod rational;
+
+pub use complex::Complex;
+pub use rational::Rational;
@@ -0,0 +1,194 @@
+// external imports
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{FieldTrait, FloatTrait};
+
+/// Complex number `re + i * im` over a real float type.
+///
+/// Pivoting treats complex values by magnitude: `FieldTrait::modulus` returns `abs()`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Complex<V> {
+    pub re: V,
+    pub im: V,
+}
+
+impl<V> Complex<V>
+where
+    V: FloatTrait<Output = V>,
+{
+    pub fn create(re: V, im: V) -> Self {
+        Complex { re, im }
+    }
+
+    pub fn i() -> Self {
+        Complex::create(V::from(0f32), V::from(1f32))
+    }
+
+    pub fn from_polar(r: V, theta: V) -> Self {
+        Complex::create(r * theta.my_cos(), r * theta.my_sin())
+    }
+
+    pub fn conj(&self) -> Self {
+        Complex::create(self.re, V::from(0f32) - self.im)
+    }
+
+    pub fn norm_sqr(&self) -> V {
+        self.re * self.re + self.im * self.im
+    }
+
+    pub fn abs(&self) -> V {
+        // Scale by the larger component so the squares neither overflow nor underflow.
+        let (re_abs, im_abs) = (self.re.my_abs(), self.im.my_abs());
+        let scale = if re_abs > im_abs { re_abs } else { im_abs };
+        if scale == V::from(0f32) {
+            return V::from(0f32);
+        }
+        let (re, im) = (self.re / scale, self.im / scale);
+        scale * (re * re + im * im).my_sqrt()
+    }
+
+    pub fn arg(&self) -> V {
+        self.im.my_atan2(&self.re)
+    }
+}
+
+impl<V> From<f32> for Complex<V>
+where
+    V: FloatTrait<Output = V>,
+{
+    fn from(value: f32) -> Self {
+        Complex::create(V::from(value), V::from(0f32))
+    }
+}
+
+impl<V> Add for Complex<V>
+where
+    V: FloatTrait<Output = V>,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Complex::create(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl<V> Sub for Complex<V>
+where
+    V: FloatTrait<Output = V>,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Complex::create(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl<V> Mul for Complex<V>
+where
+    V: FloatTrait<Output = V>,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Complex::create(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+impl<V> Div for Complex<V>
+where
+    V: FloatTrait<Output = V>,
+{
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        // Smith's algorithm: avoids forming |other|^2, which may overflow.
+        if other.re.my_abs() >= other.im.my_abs() {
+            let ratio = other.im / other.re;
+            let denominator = other.re + other.im * ratio;
+            Complex::create(
+                (self.re + self.im * ratio) / denominator,
+                (self.im - self.re * ratio) / denominator,
+            )
+        } else {
+            let ratio = other.re / other.im;
+            let denominator = other.im + other.re * ratio;
+            Complex::create(
+                (self.re * ratio + self.im) / denominator,
+                (self.im * ratio - self.re) / denominator,
+            )
+        }
+    }
+}
+
+impl<V> Neg for Complex<V>
+where
+    V: FloatTrait<Output = V>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Complex::create(V::from(0f32) - self.re, V::from(0f32) - self.im)
+    }
+}
+
+impl<V> AddAssign for Complex<V>
+where
+    V: FloatTrait<Output = V>,
+{
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<V> SubAssign for Complex<V>
+where
+    V: FloatTrait<Output = V>,
+{
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<V> MulAssign for Complex<V>
+where
+    V: FloatTrait<Output = V>,
+{
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<V> DivAssign for Complex<V>
+where
+    V: FloatTrait<Output = V>,
+{
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl<V> FieldTrait for Complex<V>
+where
+    V: FloatTrait<Output = V> + FieldTrait<Real = V>,
+{
+    type Real = V;
+
+    fn modulus(&self) -> V {
+        self.abs()
+    }
+
+    fn from_real(value: V) -> Self {
+        Complex::create(value, V::from(0f32))
+    }
+
+    fn conjugate(&self) -> Self {
+        self.conj()
+    }
+
+    fn is_nan(&self) -> bool {
+        self.re.my_is_nan() || self.im.my_is_nan()
+    }
+}
@@ -0,0 +1,273 @@
+// external imports
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{FieldTrait, Pivoting};
+
+fn gcd(a: i128, b: i128) -> i128 {
+    // Unsigned, so that `i128::MIN` neither overflows `abs` nor `% -1`.
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    Rational::checked(i128::try_from(a).ok())
+}
+
+/// Exact fraction `numerator / denominator` backed by `i128`.
+///
+/// Values are always kept reduced with a positive denominator, so the derived equality is exact
+/// equality of fractions. Arithmetic, `abs` and negation panic on `i128` overflow (including
+/// `-i128::MIN`) instead of silently wrapping.
+///
+/// `From<f32>` is exact, and panics when the fraction does not fit: for `|value| >= 2^127` and for
+/// values whose denominator exceeds `2^126`, i.e. most values at or below `f32::MIN_POSITIVE`.
+/// `Rational::from_f32` is the checked conversion. Elimination on `Rational` matrices uses first-non-zero pivoting and yields exact
+/// determinants, inverses and solutions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    pub fn create(numerator: i128, denominator: i128) -> Result<Self, String> {
+        if denominator == 0 {
+            return Err("Rational: denominator equals to zero!".to_string());
+        }
+        Ok(Rational::reduced(numerator, denominator))
+    }
+
+    pub fn from_integer(value: i128) -> Self {
+        Rational {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+
+    pub fn get_numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn get_denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    pub fn abs(&self) -> Self {
+        Rational {
+            numerator: Rational::checked(self.numerator.checked_abs()),
+            denominator: self.denominator,
+        }
+    }
+
+    pub fn recip(&self) -> Self {
+        if self.numerator == 0 {
+            panic!("Rational: division by zero!");
+        }
+        Rational::reduced(self.denominator, self.numerator)
+    }
+
+    /// Exact conversion of a finite `f32`; fails when the fraction does not fit into `i128`.
+    pub fn from_f32(value: f32) -> Result<Self, String> {
+        let error = || format!("Rational: {value} could not be converted!");
+        if !value.is_finite() {
+            return Err(error());
+        }
+        if value == 0f32 {
+            return Ok(Rational::from_integer(0));
+        }
+        let bits = value.to_bits();
+        let sign = if bits >> 31 == 0 { 1i128 } else { -1i128 };
+        let biased_exponent = ((bits >> 23) & 0xff) as i32;
+        let fraction = (bits & 0x7f_ffff) as i128;
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            (fraction, -149)
+        } else {
+            (fraction | 0x80_0000, biased_exponent - 150)
+        };
+        if exponent >= 0 {
+            let numerator = mantissa.checked_mul(1i128 << exponent).ok_or_else(error)?;
+            return Ok(Rational::from_integer(sign * numerator));
+        }
+        // Drop the trailing zero bits of the mantissa so that the denominator is as small as it
+        // gets; 2^127 and above do not fit into i128.
+        let shift = (mantissa.trailing_zeros() as i32).min(-exponent);
+        let (mantissa, exponent) = (mantissa >> shift, exponent + shift);
+        if exponent <= -127 {
+            return Err(error());
+        }
+        Ok(Rational::reduced(
+            sign * mantissa,
+            1i128 << (-exponent) as u32,
+        ))
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    fn reduced(numerator: i128, denominator: i128) -> Self {
+        let divisor = gcd(numerator, denominator);
+        let (numerator, denominator) = (numerator / divisor, denominator / divisor);
+        if denominator < 0 {
+            Rational {
+                numerator: Rational::checked(numerator.checked_neg()),
+                denominator: Rational::checked(denominator.checked_neg()),
+            }
+        } else {
+            Rational {
+                numerator,
+                denominator,
+            }
+        }
+    }
+
+    fn checked(value: Option<i128>) -> i128 {
+        value.expect("Rational: arithmetic overflow!")
+    }
+}
+
+impl From<f32> for Rational {
+    /// Exact conversion: every finite `f32` is a dyadic fraction. Panics where
+    /// `Rational::from_f32` returns an error.
+    fn from(value: f32) -> Self {
+        Rational::from_f32(value).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Self {
+        Rational::from_integer(value)
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Denominators are positive, so cross-multiplication keeps the ordering.
+        let lhs = Rational::checked(self.numerator.checked_mul(other.denominator));
+        let rhs = Rational::checked(other.numerator.checked_mul(self.denominator));
+        lhs.cmp(&rhs)
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let divisor = gcd(self.denominator, other.denominator);
+        let (lhs_factor, rhs_factor) = (other.denominator / divisor, self.denominator / divisor);
+        let numerator = Rational::checked(
+            Rational::checked(self.numerator.checked_mul(lhs_factor))
+                .checked_add(Rational::checked(other.numerator.checked_mul(rhs_factor))),
+        );
+        let denominator = Rational::checked(self.denominator.checked_mul(lhs_factor));
+        Rational::reduced(numerator, denominator)
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + (-other)
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        // Cross-reduce first to keep the intermediate products small.
+        let lhs_divisor = gcd(self.numerator, other.denominator);
+        let rhs_divisor = gcd(other.numerator, self.denominator);
+        let numerator = Rational::checked(
+            (self.numerator / lhs_divisor).checked_mul(other.numerator / rhs_divisor),
+        );
+        let denominator = Rational::checked(
+            (self.denominator / rhs_divisor).checked_mul(other.denominator / lhs_divisor),
+        );
+        Rational::reduced(numerator, denominator)
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Self) -> Self {
+        self * other.recip()
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Rational {
+            numerator: Rational::checked(self.numerator.checked_neg()),
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl AddAssign for Rational {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Rational {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for Rational {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign for Rational {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl FieldTrait for Rational {
+    type Real = Rational;
+
+    const PIVOTING: Pivoting = Pivoting::FirstNonZero;
+
+    fn modulus(&self) -> Rational {
+        self.abs()
+    }
+
+    fn from_real(value: Rational) -> Self {
+        value
+    }
+
+    fn conjugate(&self) -> Self {
+        *self
+    }
+
+    fn is_nan(&self) -> bool {
+        false
+    }
+}
@@ -1 +1,3 @@
+mod helpers;
 mod matrix;
+mod scalars;
@@ -0,0 +1,62 @@
+//! Fixtures and comparisons shared by the matrix tests.
+
+use crate::CsrMatrix;
+
+/// Largest componentwise difference.
+pub(crate) fn max_error(actual: &[f64], expected: &[f64]) -> f64 {
+    actual
+        .iter()
+        .zip(expected)
+        .map(|(a, e)| (a - e).abs())
+        .fold(0.0, f64::max)
+}
+
+/// No preconditioning.
+pub(crate) fn identity(r: &[f64]) -> Vec<f64> {
+    r.to_vec()
+}
+
+/// Five-point Laplacian on an `m x m` grid with `shift` added to the diagonal.
+pub(crate) fn laplacian_2d(m: usize, shift: f64) -> CsrMatrix<f64> {
+    numbered_laplacian_2d(m, shift, |k| k)
+}
+
+/// [`laplacian_2d`] with grid node `k` numbered `label(k)`.
+pub(crate) fn numbered_laplacian_2d(
+    m: usize,
+    shift: f64,
+    label: impl Fn(usize) -> usize,
+) -> CsrMatrix<f64> {
+    let mut triplets = Vec::new();
+    for i in 0..m {
+        for j in 0..m {
+            let k = i * m + j;
+            triplets.push((label(k), label(k), 4.0 + shift));
+            if i > 0 {
+                triplets.push((label(k), label(k - m), -1.0));
+            }
+            if i + 1 < m {
+                triplets.push((label(k), label(k + m), -1.0));
+            }
+            if j > 0 {
+                triplets.push((label(k), label(k - 1), -1.0));
+            }
+            if j + 1 < m {
+                triplets.push((label(k), label(k + 1), -1.0));
+            }
+        }
+    }
+    CsrMatrix::from_coo(m * m, m * m, &triplets).unwrap()
+}
+
+/// Row-major values of `a`, duplicates summed.
+pub(crate) fn dense_values(a: &CsrMatrix<f64>) -> Vec<f64> {
+    let n = a.get_n_cols();
+    let mut values = vec![0.0; a.get_n_rows() * n];
+    for i in 0..a.get_n_rows() {
+        for idx in a.get_row_ptr()[i]..a.get_row_ptr()[i + 1] {
+            values[i * n + a.get_col_index()[idx]] += a.get_values()[idx];
+        }
+    }
+    values
+}
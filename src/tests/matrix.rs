@@ -1,7 +1,24 @@
 mod structs;
+mod test_assembler;
+mod test_banded_matrix;
+mod test_block_matrix;
+mod test_boundary_conditions;
 mod test_csr_matrix;
+mod test_gemm;
+mod test_incomplete_factorizations;
+mod test_krylov;
+mod test_lup_factorization;
 mod test_matrix;
+mod test_matrix_functions;
+mod test_ordering;
+mod test_permutation;
+mod test_quaternion;
+mod test_rotation_3;
+mod test_s_matrix;
+mod test_sparse_cholesky;
+mod test_sparse_lu;
 mod test_square_matrix;
+mod test_symmetric_csr_matrix;
 mod test_vector;
 mod test_vector_3;
 mod traits;
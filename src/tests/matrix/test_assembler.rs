@@ -0,0 +1,137 @@
+#![allow(unused_imports)]
+
+use crate::tests::helpers::dense_values;
+use crate::{AssemblyStorage, CooAssembler, CsrAssembler, CsrMatrix, CsrPattern};
+
+/// Two-node bar elements on a chain of 5 nodes, numbered against the node order so that elements
+/// touch DOFs in descending order as well.
+const ELEMENTS: [[usize; 2]; 4] = [[0, 1], [2, 1], [2, 3], [4, 3]];
+
+fn bar_element(stiffness: f64) -> [f64; 4] {
+    [stiffness, -stiffness, -stiffness, stiffness]
+}
+
+#[rustfmt::skip]
+const EXPECTED: [f64; 25] = [
+     1.0, -1.0,  0.0,  0.0,  0.0,
+    -1.0,  2.0, -1.0,  0.0,  0.0,
+     0.0, -1.0,  2.0, -1.0,  0.0,
+     0.0,  0.0, -1.0,  2.0, -1.0,
+     0.0,  0.0,  0.0, -1.0,  1.0,
+];
+
+#[test]
+fn test_coo_assembler() -> Result<(), String> {
+    let mut assembler = CooAssembler::create(5, AssemblyStorage::Full);
+    for dofs in &ELEMENTS {
+        assembler.add_element_matrix(dofs, &bar_element(1.0))?;
+        assembler.add_element_vector(dofs, &[0.5, 0.5])?;
+    }
+    assert_eq!(assembler.get_order(), 5);
+    assert_eq!(assembler.get_triplets_number(), 16);
+    assert_eq!(assembler.get_rhs(), &[0.5, 1.0, 1.0, 1.0, 0.5]);
+    let a = assembler.to_csr()?;
+    assert_eq!(a.get_values().len(), 13);
+    assert_eq!(dense_values(&a), EXPECTED.to_vec());
+
+    let mut upper = CooAssembler::create(5, AssemblyStorage::Upper);
+    for dofs in &ELEMENTS {
+        upper.add_element_matrix(dofs, &bar_element(1.0))?;
+    }
+    let a = upper.to_symmetric_csr()?;
+    assert_eq!(a.get_nnz(), 9);
+    assert!((0..5).all(|i| a.get_col_index()[a.get_row_ptr()[i]] == i));
+    assert_eq!(dense_values(&a.to_csr()?), EXPECTED.to_vec());
+
+    // The upper triangle alone is not the global matrix.
+    assert_eq!(
+        upper.to_csr().unwrap_err(),
+        "Assembler: upper-triangle storage, use to_symmetric_csr for the full matrix"
+    );
+    assert_eq!(
+        assembler.to_symmetric_csr().unwrap_err(),
+        "Assembler: full storage, use to_csr"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_csr_assembler_reuses_pattern() -> Result<(), String> {
+    let pattern = CsrPattern::create(5, AssemblyStorage::Full, ELEMENTS)?;
+    assert_eq!(pattern.get_nnz(), 13);
+    assert_eq!(pattern.get_row_ptr(), &[0, 2, 5, 8, 11, 13]);
+    assert_eq!(&pattern.get_col_index()[2..5], &[0, 1, 2]);
+
+    let mut assembler = CsrAssembler::create(&pattern);
+    for dofs in &ELEMENTS {
+        assembler.add_element_matrix(dofs, &bar_element(1.0))?;
+        assembler.add_element_vector(dofs, &[1.0, 0.0])?;
+    }
+    assert_eq!(dense_values(&assembler.to_csr()?), EXPECTED.to_vec());
+    assert_eq!(assembler.get_rhs(), &[1.0, 0.0, 2.0, 0.0, 1.0]);
+
+    // Second numeric pass with other values on the same pattern.
+    assembler.reset();
+    for dofs in &ELEMENTS {
+        assembler.add_element_matrix(dofs, &bar_element(3.0))?;
+    }
+    let a = assembler.to_csr()?;
+    assert_eq!(a.get_col_index(), pattern.get_col_index());
+    assert_eq!(
+        dense_values(&a),
+        EXPECTED
+            .iter()
+            .map(|value| 3.0 * value)
+            .collect::<Vec<f64>>()
+    );
+    assert_eq!(assembler.get_rhs(), &[0.0; 5]);
+
+    let upper = CsrPattern::create(5, AssemblyStorage::Upper, ELEMENTS.iter())?;
+    assert_eq!(upper.get_nnz(), 9);
+    let mut assembler = CsrAssembler::create(&upper);
+    for dofs in &ELEMENTS {
+        assembler.add_element_matrix(dofs, &bar_element(1.0))?;
+    }
+    let coo_upper = {
+        let mut coo = CooAssembler::create(5, AssemblyStorage::Upper);
+        for dofs in &ELEMENTS {
+            coo.add_element_matrix(dofs, &bar_element(1.0))?;
+        }
+        coo.to_symmetric_csr()?
+    };
+    assert_eq!(
+        assembler.to_symmetric_csr()?.get_values(),
+        coo_upper.get_values()
+    );
+    assert!(assembler.to_csr().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_assembler_errors() -> Result<(), String> {
+    let mut coo = CooAssembler::create(3, AssemblyStorage::Full);
+    assert_eq!(
+        coo.add_element_matrix(&[0, 1], &[1.0; 3]).unwrap_err(),
+        "Assembler: element matrix has 3 values, expected 4 for 2 DOFs"
+    );
+    assert_eq!(
+        coo.add_element_vector(&[0, 3], &[1.0; 2]).unwrap_err(),
+        "Assembler: DOF 3 is out of range for order 3"
+    );
+    assert_eq!(
+        CsrPattern::create(3, AssemblyStorage::Full, [[0, 5]]).unwrap_err(),
+        "Assembler: DOF 5 is out of range for order 3"
+    );
+
+    let pattern = CsrPattern::create(3, AssemblyStorage::Full, [[0, 1], [1, 2]])?;
+    let mut assembler = CsrAssembler::create(&pattern);
+    assert_eq!(
+        assembler
+            .add_element_matrix(&[0, 2], &bar_element(1.0))
+            .unwrap_err(),
+        "Assembler: entry (0, 2) is not in the pattern"
+    );
+    // The positions in the pattern were not added either.
+    assert_eq!(assembler.to_csr()?.get_values(), &[0.0; 7]);
+    Ok(())
+}
@@ -0,0 +1,110 @@
+#![allow(unused_imports)]
+
+use crate::tests::helpers::max_error;
+use crate::{
+    BandStorage, BandedMatrix, CsrMatrix, LinearOperatorTrait, Position, SquareMatrix,
+    conjugate_gradient,
+};
+
+/// Nonsymmetric, one subdiagonal and two superdiagonals, small diagonal so that LU has to pivot.
+fn nonsymmetric(n: usize) -> CsrMatrix<f64> {
+    let mut triplets = Vec::new();
+    for i in 0..n {
+        triplets.push((i, i, 0.01 * (i as f64 + 1.0)));
+        if i > 0 {
+            triplets.push((i, i - 1, 2.0 + i as f64));
+        }
+        if i + 1 < n {
+            triplets.push((i, i + 1, -1.0));
+        }
+        if i + 2 < n {
+            triplets.push((i, i + 2, 0.5));
+        }
+    }
+    CsrMatrix::from_coo(n, n, &triplets).unwrap()
+}
+
+fn laplacian_1d(n: usize) -> SquareMatrix<f64> {
+    let mut values = vec![0.0; n * n];
+    for i in 0..n {
+        values[i * n + i] = 2.0;
+        if i + 1 < n {
+            values[i * n + i + 1] = -1.0;
+            values[(i + 1) * n + i] = -1.0;
+        }
+    }
+    SquareMatrix::create(n, &values)
+}
+
+#[test]
+fn test_general_storage_and_spmv() -> Result<(), String> {
+    let csr = nonsymmetric(6);
+    let banded = BandedMatrix::from_csr(&csr)?;
+    assert_eq!(banded.get_storage(), BandStorage::General);
+    assert_eq!(
+        (banded.get_lower_bandwidth(), banded.get_upper_bandwidth()),
+        (1, 2)
+    );
+    assert_eq!(banded.get_leading_dimension(), 4);
+    // Column 0: two unused superdiagonal positions, then a_00 and a_10.
+    assert_eq!(&banded.get_values()[..4], &[0.0, 0.0, 0.01, 3.0]);
+    assert_eq!(banded.get_element_value(&Position(3, 5))?, 0.5);
+    assert_eq!(banded.get_element_value(&Position(5, 0))?, 0.0);
+    assert!(banded.get_element_value(&Position(6, 0)).is_err());
+
+    let x: Vec<f64> = (0..6).map(|i| (i as f64 * 0.9).sin()).collect();
+    assert!(max_error(&banded.spmv(&x)?, &csr.spmv(&x)?) < 1e-14);
+    assert!(max_error(&banded.apply_transpose(&x)?, &csr.spmv_transpose(&x)?) < 1e-14);
+    assert_eq!(banded.to_csr()?.get_values(), csr.get_values());
+    assert!(banded.spmv(&x[..5]).is_err());
+    assert!(BandedMatrix::create(3, 1, 1, vec![0.0; 8]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_lu_with_partial_pivoting() -> Result<(), String> {
+    let csr = nonsymmetric(9);
+    let lu = BandedMatrix::from_csr(&csr)?.lu()?;
+    assert!(lu.get_pivots().iter().enumerate().any(|(j, &p)| p != j));
+
+    let b: Vec<f64> = (0..9).map(|i| 1.0 + i as f64).collect();
+    let x = lu.solve(&b)?;
+    assert!(max_error(&csr.spmv(&x)?, &b) < 1e-10);
+    assert!(max_error(&x, &csr.lu()?.solve(&b)?) < 1e-10);
+
+    let singular =
+        CsrMatrix::from_coo(2, 2, &[(0, 0, 1.0), (0, 1, 2.0), (1, 0, 2.0), (1, 1, 4.0)])?;
+    assert!(BandedMatrix::from_csr(&singular)?.lu().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_symmetric_storage_and_cholesky() -> Result<(), String> {
+    let n = 8;
+    let dense = laplacian_1d(n);
+    let banded = BandedMatrix::symmetric_from_square_matrix(&dense, 1e-12)?;
+    assert_eq!(banded.get_storage(), BandStorage::Symmetric);
+    assert_eq!(banded.get_upper_bandwidth(), 1);
+    assert_eq!(banded.get_values().len(), 2 * n);
+    assert_eq!(banded.get_element_value(&Position(4, 3))?, -1.0);
+
+    let b: Vec<f64> = (0..n).map(|i| (i as f64).cos()).collect();
+    let csr = CsrMatrix::from_square_matrix(&dense)?;
+    assert!(max_error(&banded.spmv(&b)?, &csr.spmv(&b)?) < 1e-14);
+
+    let x = banded.cholesky()?.solve(&b)?;
+    assert!(max_error(&csr.spmv(&x)?, &b) < 1e-12);
+    assert!(max_error(&banded.lu()?.solve(&b)?, &x) < 1e-12);
+
+    let (x_cg, _) = conjugate_gradient(&banded, &b, &banded.cholesky()?, 1e-12, 5)?;
+    assert!(max_error(&x_cg, &x) < 1e-12);
+
+    assert!(
+        BandedMatrix::from_square_matrix(&dense)?
+            .cholesky()
+            .is_err()
+    );
+    let indefinite = BandedMatrix::create_symmetric(2, 1, vec![0.0, 1.0, 2.0, 1.0])?;
+    assert!(indefinite.cholesky().is_err());
+    Ok(())
+}
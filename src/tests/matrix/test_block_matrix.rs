@@ -0,0 +1,89 @@
+#![allow(unused_imports)]
+
+use crate::tests::helpers::max_error;
+use crate::{
+    BasicOperationsTrait, BlockMatrix, CsrMatrix, LinearOperatorTrait, Matrix, MatrixBlock,
+    Position,
+};
+
+/// `[[K, Bᵀ], [B, 0]]` with `K = [[4, 1], [1, 3]]` and `B = [1, 2]`.
+fn saddle_point() -> BlockMatrix<f64> {
+    let k =
+        CsrMatrix::from_coo(2, 2, &[(0, 0, 4.0), (0, 1, 1.0), (1, 0, 1.0), (1, 1, 3.0)]).unwrap();
+    let mut block_matrix = BlockMatrix::create(&[2, 1], &[2, 1]);
+    block_matrix
+        .set_block(0, 0, MatrixBlock::Sparse(k))
+        .unwrap();
+    block_matrix
+        .set_block(0, 1, MatrixBlock::Dense(Matrix::create(2, 1, &[1.0, 2.0])))
+        .unwrap();
+    block_matrix
+        .set_block(1, 0, MatrixBlock::Dense(Matrix::create(1, 2, &[1.0, 2.0])))
+        .unwrap();
+    block_matrix
+}
+
+#[test]
+fn test_spmv_and_flattening() -> Result<(), String> {
+    let block_matrix = saddle_point();
+    assert_eq!(block_matrix.get_shape(), (3, 3));
+    assert!(block_matrix.get_block(1, 1).is_none());
+
+    let x = [1.0, -2.0, 0.5];
+    let expected = [4.0 - 2.0 + 0.5, 1.0 - 6.0 + 1.0, 1.0 - 4.0];
+    assert!(max_error(&block_matrix.spmv(&x)?, &expected) < 1e-14);
+
+    let csr = block_matrix.to_csr()?;
+    assert_eq!(csr.get_row_ptr(), &[0, 3, 6, 8]);
+    assert_eq!(csr.get_col_index(), &[0, 1, 2, 0, 1, 2, 0, 1]);
+    assert!(max_error(&csr.spmv(&x)?, &expected) < 1e-14);
+
+    let dense = block_matrix.to_matrix();
+    assert_eq!(dense.get_shape().0, 3);
+    assert_eq!(dense.get_elements().get(&Position(2, 1)), Some(&2.0));
+    assert_eq!(dense.get_elements().get(&Position(2, 2)), Some(&0.0));
+
+    let y = [0.3, 1.0, -1.0];
+    assert!(max_error(&block_matrix.apply_transpose(&y)?, &csr.spmv_transpose(&y)?) < 1e-14);
+    assert!(block_matrix.spmv(&[1.0, 2.0]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_block_diagonal_and_set_block_checks() -> Result<(), String> {
+    let block_matrix = BlockMatrix::block_diagonal(vec![
+        MatrixBlock::Dense(Matrix::create(1, 1, &[2.0])),
+        MatrixBlock::Sparse(CsrMatrix::from_coo(2, 2, &[(0, 1, 3.0), (1, 0, 5.0)])?),
+    ])?;
+    assert_eq!(block_matrix.get_row_sizes(), &[1, 2]);
+    assert!(block_matrix.get_block(0, 1).is_none());
+    assert_eq!(block_matrix.spmv(&[1.0, 1.0, 2.0])?, vec![2.0, 6.0, 5.0]);
+
+    let mut block_matrix = BlockMatrix::<f64>::create(&[2, 1], &[2, 1]);
+    assert!(
+        block_matrix
+            .set_block(0, 1, MatrixBlock::Dense(Matrix::create(1, 2, &[1.0, 2.0])))
+            .is_err()
+    );
+    assert!(
+        block_matrix
+            .set_block(2, 0, MatrixBlock::Dense(Matrix::create(1, 2, &[1.0, 2.0])))
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_schur_complement() -> Result<(), String> {
+    // S = 0 - B K⁻¹ Bᵀ = -15 / 11.
+    let s = saddle_point().schur_complement()?;
+    let value = *s.get_elements().get(&Position(0, 0)).unwrap();
+    assert!((value + 15.0 / 11.0).abs() < 1e-14);
+
+    assert!(
+        BlockMatrix::<f64>::create(&[1], &[1])
+            .schur_complement()
+            .is_err()
+    );
+    Ok(())
+}
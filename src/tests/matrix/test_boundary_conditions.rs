@@ -0,0 +1,163 @@
+#![allow(unused_imports)]
+
+use crate::tests::helpers::{dense_values, max_error};
+use crate::{
+    BasicOperationsTrait, ConstrainedDiagonal, CsrMatrix, Position, ReducedSystem, SquareMatrix,
+};
+
+/// Stiffness of a bar of 4 unit elements with unit stiffness: nodes 0..5.
+fn bar_triplets() -> Vec<(usize, usize, f64)> {
+    let mut triplets = Vec::new();
+    for e in 0..4 {
+        triplets.push((e, e, 1.0));
+        triplets.push((e, e + 1, -1.0));
+        triplets.push((e + 1, e, -1.0));
+        triplets.push((e + 1, e + 1, 1.0));
+    }
+    triplets
+}
+
+fn bar_dense() -> SquareMatrix<f64> {
+    let mut a = SquareMatrix::create(5, &[0.0; 25]);
+    for (i, j, value) in bar_triplets() {
+        a.add_value(Position(i, j), value);
+    }
+    a
+}
+
+fn bar_sparse() -> CsrMatrix<f64> {
+    CsrMatrix::from_coo(5, 5, &bar_triplets()).unwrap()
+}
+
+fn solve(a: &CsrMatrix<f64>, b: &[f64]) -> Result<Vec<f64>, String> {
+    a.lu()?.solve(b)
+}
+
+// u(0) = 0, u(4) = 2 and a unit load at node 2.
+const PRESCRIBED: [(usize, f64); 2] = [(4, 2.0), (0, 0.0)];
+const LOAD: [f64; 5] = [0.0, 0.0, 1.0, 0.0, 0.0];
+const EXPECTED: [f64; 5] = [0.0, 1.0, 2.0, 2.0, 2.0];
+
+#[test]
+fn test_eliminate_dirichlet() -> Result<(), String> {
+    let ReducedSystem {
+        matrix: reduced,
+        rhs,
+        mapping,
+    } = bar_sparse().eliminate_dirichlet(&LOAD, &PRESCRIBED)?;
+    assert_eq!(mapping.get_order(), 5);
+    assert_eq!(mapping.get_reduced_order(), 3);
+    assert_eq!(mapping.get_free_dofs(), &[1, 2, 3]);
+    assert_eq!(mapping.get_prescribed(), &[(0, 0.0), (4, 2.0)]);
+    assert_eq!(rhs, vec![0.0, 1.0, 2.0]);
+    let u = mapping.expand(&solve(&reduced, &rhs)?)?;
+    assert!(max_error(&u, &EXPECTED) < 1e-12);
+    assert_eq!(mapping.restrict(&u)?.len(), 3);
+
+    let dense = bar_dense().eliminate_dirichlet(&LOAD, &PRESCRIBED)?;
+    assert_eq!(dense.mapping, mapping);
+    assert_eq!(dense.rhs, rhs);
+    assert_eq!(dense.matrix.get_shape().0, 3);
+    assert_eq!(
+        CsrMatrix::from_square_matrix(&dense.matrix)?.get_values(),
+        reduced.get_values()
+    );
+
+    assert_eq!(
+        mapping.expand(&[1.0]).unwrap_err(),
+        "DirichletMapping: reduced vector has len 1, expected 3"
+    );
+
+    // Every DOF prescribed: nothing is left to solve for.
+    let all: Vec<(usize, f64)> = (0..5).map(|dof| (dof, dof as f64)).collect();
+    let empty = bar_sparse().eliminate_dirichlet(&LOAD, &all)?;
+    assert_eq!(
+        (empty.matrix.get_n_rows(), empty.matrix.get_n_cols()),
+        (0, 0)
+    );
+    assert_eq!(empty.matrix.get_row_ptr(), &[0]);
+    assert!(empty.rhs.is_empty());
+    assert_eq!(empty.mapping.expand(&[])?, vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(
+        bar_dense().eliminate_dirichlet(&LOAD, &all)?.mapping,
+        empty.mapping
+    );
+    Ok(())
+}
+
+#[test]
+fn test_apply_dirichlet() -> Result<(), String> {
+    let a = bar_sparse();
+    let (constrained, rhs) = a.apply_dirichlet(&LOAD, &PRESCRIBED, ConstrainedDiagonal::Unit)?;
+    // The pattern is kept and the matrix stays symmetric.
+    assert_eq!(constrained.get_col_index(), a.get_col_index());
+    let dense = SquareMatrix::create(5, &dense_values(&constrained));
+    assert_eq!(dense.to_dense_values(), dense.transpose().to_dense_values());
+    assert_eq!(rhs, vec![0.0, 0.0, 1.0, 2.0, 2.0]);
+    assert!(max_error(&solve(&constrained, &rhs)?, &EXPECTED) < 1e-12);
+
+    let (scaled, scaled_rhs) =
+        a.apply_dirichlet(&LOAD, &PRESCRIBED, ConstrainedDiagonal::Scaled(3.0))?;
+    assert_eq!(scaled_rhs[4], 6.0);
+    assert!(max_error(&solve(&scaled, &scaled_rhs)?, &EXPECTED) < 1e-12);
+
+    let (dense, dense_rhs) =
+        bar_dense().apply_dirichlet(&LOAD, &PRESCRIBED, ConstrainedDiagonal::Scaled(3.0))?;
+    assert_eq!(dense_rhs, scaled_rhs);
+    assert_eq!(dense.to_dense_values(), dense_values(&scaled));
+    Ok(())
+}
+
+#[test]
+fn test_apply_penalty() -> Result<(), String> {
+    let (penalized, rhs) = bar_sparse().apply_penalty(&LOAD, &PRESCRIBED, 1e10)?;
+    assert_eq!(rhs[4], 2e10);
+    assert!(max_error(&solve(&penalized, &rhs)?, &EXPECTED) < 1e-8);
+
+    let (dense, dense_rhs) = bar_dense().apply_penalty(&LOAD, &PRESCRIBED, 1e10)?;
+    assert_eq!(dense_rhs, rhs);
+    assert_eq!(dense.to_dense_values(), dense_values(&penalized));
+
+    // A constrained row without a stored diagonal gets one.
+    let a = CsrMatrix::from_coo(2, 2, &[(0, 1, 1.0), (1, 0, 1.0), (1, 1, 1.0)])?;
+    let (penalized, _) = a.apply_penalty(&[0.0, 0.0], &[(0, 1.0)], 1e8)?;
+    assert_eq!(penalized.get_values().len(), 4);
+    Ok(())
+}
+
+#[test]
+fn test_boundary_condition_errors() -> Result<(), String> {
+    let a = bar_sparse();
+    assert_eq!(
+        a.eliminate_dirichlet(&LOAD, &[(5, 0.0)]).unwrap_err(),
+        "Dirichlet: DOF 5 is out of range for order 5"
+    );
+    assert_eq!(
+        a.apply_penalty(&LOAD, &[(1, 0.0), (1, 1.0)], 1e8)
+            .unwrap_err(),
+        "Dirichlet: DOF 1 is constrained twice"
+    );
+    assert_eq!(
+        bar_dense()
+            .apply_dirichlet(&LOAD[..4], &PRESCRIBED, ConstrainedDiagonal::Unit)
+            .unwrap_err(),
+        "Dirichlet: dimension mismatch: A is 5x5, b has len 4"
+    );
+
+    let no_diagonal = CsrMatrix::from_coo(2, 2, &[(0, 1, 1.0), (1, 0, 1.0)])?;
+    assert_eq!(
+        no_diagonal
+            .apply_dirichlet(&[0.0, 0.0], &[(0, 1.0)], ConstrainedDiagonal::Scaled(1.0))
+            .unwrap_err(),
+        "Dirichlet: zero diagonal entry in row 0"
+    );
+
+    let rectangular = CsrMatrix::from_coo(2, 3, &[(0, 0, 1.0)])?;
+    assert_eq!(
+        rectangular
+            .eliminate_dirichlet(&[0.0, 0.0], &[(0, 1.0)])
+            .unwrap_err(),
+        "Dirichlet: matrix is not square: A is 2x3"
+    );
+    Ok(())
+}
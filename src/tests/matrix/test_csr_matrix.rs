@@ -1,6 +1,6 @@
 #![allow(unused_imports)]
 
-use crate::{BasicOperationsTrait, CsrMatrix, Position, SquareMatrix};
+use crate::{BasicOperationsTrait, CsrMatrix, MatrixNormTrait, Position, SquareMatrix};
 
 const ABS_TOL: f64 = 1e-12;
 
@@ -55,3 +55,54 @@ fn test_from_coo_sums_duplicates() {
     assert!((y[0] - 3.0).abs() < ABS_TOL);
     assert!((y[1] - 4.0).abs() < ABS_TOL);
 }
+
+#[test]
+fn test_spmv_matches_row_by_row_sum() {
+    let n = 50usize;
+    let mut trip = Vec::new();
+    for i in 0..n {
+        for j in [i.saturating_sub(3), i, (i + 5) % n] {
+            trip.push((i, j, ((i * 31 + j * 17) % 23) as f64 / 7.0 - 1.5));
+        }
+    }
+    let x = (0..n).map(|i| (i as f64).sin()).collect::<Vec<f64>>();
+
+    let csr = CsrMatrix::from_coo(n, n, &trip).unwrap();
+    let y = csr.spmv(&x).unwrap();
+
+    for (i, &y_i) in y.iter().enumerate() {
+        let mut expected = 0.0;
+        for idx in csr.get_row_ptr()[i]..csr.get_row_ptr()[i + 1] {
+            expected += csr.get_values()[idx] * x[csr.get_col_index()[idx]];
+        }
+        assert_eq!(y_i, expected);
+    }
+}
+
+#[test]
+fn test_spmv_transpose_and_norms() {
+    // A = [[1, -2, 0],
+    //      [0,  3, 4]]
+    let trip = vec![
+        (0usize, 0usize, 1.0f64),
+        (0, 1, -2.0),
+        (1, 1, 3.0),
+        (1, 2, 4.0),
+    ];
+    let csr = CsrMatrix::from_coo(2, 3, &trip).unwrap();
+
+    assert_eq!(
+        csr.spmv_transpose(&[1.0, 2.0]).unwrap(),
+        vec![1.0, 4.0, 8.0]
+    );
+    assert!(csr.spmv_transpose(&[1.0, 2.0, 3.0]).is_err());
+
+    assert_eq!(csr.norm_1(), 5.0);
+    assert_eq!(csr.norm_inf(), 7.0);
+    assert_eq!(csr.norm_frobenius(), 30f64.sqrt());
+    assert_eq!(csr.norm_max(), 4.0);
+
+    let dense = SquareMatrix::create(3, &[1.0f64, -2.0, 0.0, 0.0, 3.0, 4.0, 0.0, 0.0, 0.0]);
+    let expected = dense.norm_2(1e-14, 1000).unwrap();
+    assert!((csr.norm_2(1e-14, 1000).unwrap() - expected).abs() < 1e-10);
+}
@@ -0,0 +1,64 @@
+#![allow(unused_imports)]
+
+use crate::{BasicOperationsTrait, Matrix, SquareMatrix, gemm, gemm_nt, gemm_tn};
+
+fn values(count: usize, seed: usize) -> Vec<f64> {
+    (0..count)
+        .map(|i| ((i * 7919 + seed) % 101) as f64 / 13.0 - 3.0)
+        .collect()
+}
+
+#[test]
+fn test_gemm() {
+    let a = Matrix::create(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let b = Matrix::create(3, 2, &[7.0, 8.0, 9.0, 10.0, 11.0, 12.0]);
+    let mut c = Matrix::create(2, 2, &[1.0, 1.0, 1.0, 1.0]);
+
+    gemm(2.0, &a, &b, 3.0, &mut c).unwrap();
+
+    let expected = Matrix::create(2, 2, &[119.0, 131.0, 281.0, 311.0]);
+    assert_eq!(c, expected);
+}
+
+#[test]
+fn test_gemm_ignores_c_when_beta_is_zero() {
+    let a = SquareMatrix::create(2, &[1.0, 2.0, 3.0, 4.0]);
+    let mut c = SquareMatrix::create(2, &[]);
+
+    gemm(1.0, &a, &a, 0.0, &mut c).unwrap();
+
+    assert_eq!(
+        c.to_dense(),
+        SquareMatrix::create(2, &[7.0, 10.0, 15.0, 22.0])
+    );
+}
+
+#[test]
+fn test_gemm_tn_and_gemm_nt_match_explicit_transpose() {
+    // Shapes chosen to cross the row, column and inner tile boundaries of the kernel.
+    let (m, k, n) = (41, 150, 270);
+    let a = Matrix::create(m, k, &values(m * k, 3));
+    let a_t = Matrix::create(k, m, &values(m * k, 5));
+    let b = Matrix::create(k, n, &values(k * n, 7));
+    let b_t = Matrix::create(n, k, &values(k * n, 11));
+
+    let mut c_tn = Matrix::create(m, n, &[]);
+    gemm_tn(1.0, &a_t, &b, 0.0, &mut c_tn).unwrap();
+    assert_eq!(Ok(c_tn), a_t.transpose().multiply(&b));
+
+    let mut c_nt = Matrix::create(m, n, &[]);
+    gemm_nt(1.0, &a, &b_t, 0.0, &mut c_nt).unwrap();
+    assert_eq!(Ok(c_nt), a.multiply(&b_t.transpose()));
+}
+
+#[test]
+fn test_gemm_shape_mismatch() {
+    let a = Matrix::create(2, 3, &[0.0; 6]);
+    let mut c = Matrix::create(2, 2, &[0.0; 4]);
+
+    assert_eq!(
+        gemm(1.0, &a, &a, 0.0, &mut c),
+        Err("Shapes of matrices do not conform to each other!".to_string())
+    );
+    assert_eq!(gemm_nt(1.0, &a, &a, 0.0, &mut c), Ok(()));
+}
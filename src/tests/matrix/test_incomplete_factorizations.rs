@@ -0,0 +1,135 @@
+#![allow(unused_imports)]
+
+use crate::tests::helpers::{laplacian_2d, max_error};
+use crate::{CsrMatrix, PreconditionerTrait};
+
+/// Nonsymmetric tridiagonal matrix: its LU factors have no fill, so ILU(0) is exact.
+fn tridiagonal(n: usize) -> CsrMatrix<f64> {
+    let mut triplets = Vec::new();
+    for i in 0..n {
+        triplets.push((i, i, 4.0 + i as f64));
+        if i > 0 {
+            triplets.push((i, i - 1, -1.0));
+        }
+        if i + 1 < n {
+            triplets.push((i, i + 1, -2.0));
+        }
+    }
+    CsrMatrix::from_coo(n, n, &triplets).unwrap()
+}
+
+#[test]
+fn test_ilu0_is_exact_without_fill() -> Result<(), String> {
+    let a = tridiagonal(8);
+    let expected: Vec<f64> = (0..8).map(|i| 1.0 - 0.25 * i as f64).collect();
+    let b = a.spmv(&expected)?;
+
+    let ilu = a.ilu0()?;
+    assert_eq!(ilu.get_order(), 8);
+    assert_eq!(ilu.get_nnz(), a.get_values().len());
+    assert!(max_error(&ilu.solve(&b)?, &expected) < 1e-14);
+    assert!(max_error(&ilu.apply(&b), &expected) < 1e-14);
+    assert_eq!(
+        ilu.solve(&b[..7]).unwrap_err(),
+        "IncompleteLu: dimension mismatch: M is 8x8, r has len 7"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_iluk_fill_grows_with_level() -> Result<(), String> {
+    let a = laplacian_2d(6, 0.0);
+    let expected: Vec<f64> = (0..36).map(|i| (i % 5) as f64 - 2.0).collect();
+    let b = a.spmv(&expected)?;
+
+    let nnz: Vec<usize> = (0..4)
+        .map(|level| a.iluk(level).map(|ilu| ilu.get_nnz()))
+        .collect::<Result<_, _>>()?;
+    assert_eq!(nnz[0], a.get_values().len());
+    assert!(nnz.windows(2).all(|pair| pair[0] < pair[1]));
+
+    // Level n admits all the fill of the complete factorization.
+    let complete = a.iluk(36)?;
+    assert!(max_error(&complete.solve(&b)?, &expected) < 1e-12);
+    assert!(max_error(&a.ilu0()?.solve(&b)?, &expected) > 1e-3);
+    Ok(())
+}
+
+#[test]
+fn test_ilut_drops_small_entries() -> Result<(), String> {
+    let a = laplacian_2d(6, 0.0);
+    let expected: Vec<f64> = (0..36).map(|i| (i % 3) as f64).collect();
+    let b = a.spmv(&expected)?;
+
+    let complete = a.ilut(0.0, 36)?;
+    assert_eq!(complete.get_nnz(), a.iluk(36)?.get_nnz());
+    assert!(max_error(&complete.solve(&b)?, &expected) < 1e-12);
+
+    let dropped = a.ilut(1e-2, 36)?;
+    let limited = a.ilut(0.0, 2)?;
+    assert!(dropped.get_nnz() < complete.get_nnz());
+    assert!(limited.get_nnz() <= 36 * 5);
+    assert!(
+        max_error(&dropped.solve(&b)?, &expected) < max_error(&a.ilu0()?.solve(&b)?, &expected)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_ic0() -> Result<(), String> {
+    // Without fill IC(0) is the exact Cholesky factorization.
+    let mut triplets = Vec::new();
+    for i in 0..6 {
+        triplets.push((i, i, 2.0));
+        if i > 0 {
+            triplets.push((i, i - 1, -1.0));
+            triplets.push((i - 1, i, -1.0));
+        }
+    }
+    let a = CsrMatrix::from_coo(6, 6, &triplets)?;
+    let expected = vec![1.0, 2.0, 3.0, -1.0, 0.5, 0.0];
+    let ic = a.ic0()?;
+    assert_eq!(ic.get_nnz(), 11);
+    assert!(max_error(&ic.solve(&a.spmv(&expected)?)?, &expected) < 1e-12);
+    assert_eq!(
+        ic.solve(&expected[..5]).unwrap_err(),
+        "IncompleteCholesky: dimension mismatch: M is 6x6, r has len 5"
+    );
+
+    let laplacian = laplacian_2d(5, 0.0);
+    let ic = laplacian.ic0()?;
+    assert_eq!(ic.get_order(), 25);
+    assert_eq!(ic.get_nnz(), (laplacian.get_values().len() + 25) / 2);
+    Ok(())
+}
+
+#[test]
+fn test_incomplete_factorization_errors() -> Result<(), String> {
+    let rectangular = CsrMatrix::from_coo(2, 3, &[(0, 0, 1.0), (1, 1, 1.0)])?;
+    assert_eq!(
+        rectangular.ilu0().unwrap_err(),
+        "IncompleteLu: matrix is not square: A is 2x3"
+    );
+    assert_eq!(
+        rectangular.ic0().unwrap_err(),
+        "IncompleteCholesky: matrix is not square: A is 2x3"
+    );
+
+    let zero_diagonal = CsrMatrix::from_coo(2, 2, &[(0, 1, 1.0), (1, 0, 1.0)])?;
+    assert_eq!(
+        zero_diagonal.ilu0().unwrap_err(),
+        "IncompleteLu: zero pivot in row 0"
+    );
+    assert_eq!(
+        zero_diagonal.ic0().unwrap_err(),
+        "IncompleteCholesky: missing diagonal in row 0"
+    );
+
+    let indefinite =
+        CsrMatrix::from_coo(2, 2, &[(0, 0, 1.0), (0, 1, 2.0), (1, 0, 2.0), (1, 1, 1.0)])?;
+    assert_eq!(
+        indefinite.ic0().unwrap_err(),
+        "IncompleteCholesky: non-positive pivot in row 1"
+    );
+    Ok(())
+}
@@ -0,0 +1,118 @@
+#![allow(unused_imports)]
+
+use crate::tests::helpers::{identity, laplacian_2d, max_error};
+use crate::{CsrMatrix, PreconditionerTrait, bicgstab, conjugate_gradient};
+
+/// Upwinded convection-diffusion on an `m x m` grid: nonsymmetric, diagonally dominant.
+fn convection_diffusion(m: usize) -> CsrMatrix<f64> {
+    let mut triplets = Vec::new();
+    for i in 0..m {
+        for j in 0..m {
+            let k = i * m + j;
+            triplets.push((k, k, 5.0));
+            if i > 0 {
+                triplets.push((k, k - m, -1.5));
+            }
+            if i + 1 < m {
+                triplets.push((k, k + m, -0.5));
+            }
+            if j > 0 {
+                triplets.push((k, k - 1, -2.0));
+            }
+            if j + 1 < m {
+                triplets.push((k, k + 1, -0.5));
+            }
+        }
+    }
+    CsrMatrix::from_coo(m * m, m * m, &triplets).unwrap()
+}
+
+#[test]
+fn test_conjugate_gradient() -> Result<(), String> {
+    let a = laplacian_2d(12, 0.0);
+    let expected: Vec<f64> = (0..144).map(|i| ((i * 7) % 11) as f64 - 5.0).collect();
+    let b = a.spmv(&expected)?;
+
+    let (x, plain) = conjugate_gradient(&a, &b, &identity, 1e-10, 500)?;
+    assert!(plain.relative_residual <= 1e-10);
+    assert!(max_error(&x, &expected) < 1e-7);
+
+    let (x, preconditioned) = conjugate_gradient(&a, &b, &a.ic0()?, 1e-10, 500)?;
+    assert!(max_error(&x, &expected) < 1e-7);
+    assert!(preconditioned.iterations < plain.iterations);
+
+    // An exact factorization converges in one step.
+    let (x, exact) = conjugate_gradient(&a, &b, &a.cholesky()?, 1e-10, 500)?;
+    assert_eq!(exact.iterations, 1);
+    assert!(max_error(&x, &expected) < 1e-10);
+    Ok(())
+}
+
+#[test]
+fn test_bicgstab() -> Result<(), String> {
+    let a = convection_diffusion(10);
+    let expected: Vec<f64> = (0..100).map(|i| ((i * 3) % 7) as f64 * 0.5).collect();
+    let b = a.spmv(&expected)?;
+
+    let (x, plain) = bicgstab(&a, &b, &identity, 1e-10, 500)?;
+    assert!(plain.relative_residual <= 1e-10);
+    assert!(max_error(&x, &expected) < 1e-8);
+
+    let ilu: &dyn PreconditionerTrait<f64> = &a.ilu0()?;
+    let (x, preconditioned) = bicgstab(&a, &b, ilu, 1e-10, 500)?;
+    assert!(max_error(&x, &expected) < 1e-8);
+    assert!(preconditioned.iterations < plain.iterations);
+
+    let (x, exact) = bicgstab(&a, &b, &a.lu()?, 1e-10, 500)?;
+    assert_eq!(exact.iterations, 1);
+    assert!(max_error(&x, &expected) < 1e-10);
+    Ok(())
+}
+
+#[test]
+fn test_krylov_errors() -> Result<(), String> {
+    let a = laplacian_2d(6, 0.0);
+    let b = vec![1.0; 36];
+    assert_eq!(
+        conjugate_gradient(&a, &b[..35], &identity, 1e-10, 100).unwrap_err(),
+        "Conjugate gradient: dimension mismatch: A is 36x36, b has len 35"
+    );
+    assert_eq!(
+        conjugate_gradient(&a, &b, &identity, 1e-10, 2).unwrap_err(),
+        "Conjugate gradient: no convergence within 2 iterations!"
+    );
+    assert_eq!(
+        bicgstab(&a, &b, &identity, 1e-10, 1).unwrap_err(),
+        "BiCGSTAB: no convergence within 1 iterations!"
+    );
+
+    // Preconditioners built for another system are rejected instead of applied.
+    let other = laplacian_2d(5, 0.0).ic0()?;
+    assert_eq!(
+        conjugate_gradient(&a, &b, &other, 1e-10, 100).unwrap_err(),
+        "Conjugate gradient: dimension mismatch: preconditioner is of order 25, b has len 36"
+    );
+    let truncating = |r: &[f64]| r[1..].to_vec();
+    assert_eq!(
+        bicgstab(&a, &b, &truncating, 1e-10, 100).unwrap_err(),
+        "BiCGSTAB: dimension mismatch: preconditioner returned 35 components, b has len 36"
+    );
+
+    let indefinite = CsrMatrix::from_coo(2, 2, &[(0, 0, 1.0), (1, 1, -1.0)])?;
+    assert_eq!(
+        conjugate_gradient(&indefinite, &[0.0, 1.0], &identity, 1e-10, 10).unwrap_err(),
+        "Conjugate gradient: matrix is not positive definite!"
+    );
+
+    // A rotation maps b to a vector orthogonal to the shadow residual b.
+    let rotation = CsrMatrix::from_coo(2, 2, &[(0, 1, 1.0), (1, 0, -1.0)])?;
+    assert_eq!(
+        bicgstab(&rotation, &[1.0, 0.0], &identity, 1e-10, 10).unwrap_err(),
+        "BiCGSTAB: breakdown (r_hat · v = 0)!"
+    );
+
+    let (x, info) = bicgstab(&a, &[0.0; 36], &identity, 1e-10, 10)?;
+    assert_eq!(x, vec![0.0; 36]);
+    assert_eq!(info.iterations, 0);
+    Ok(())
+}
@@ -0,0 +1,112 @@
+#![allow(unused_imports)]
+
+use crate::{
+    BasicOperationsTrait, Complex, Matrix, Position, Rational, SquareMatrix, SquareMatrixTrait,
+};
+
+fn hilbert(n: usize) -> SquareMatrix<f64> {
+    let values = (0..n * n)
+        .map(|k| 1.0 / ((k / n + k % n + 1) as f64))
+        .collect::<Vec<f64>>();
+    SquareMatrix::create(n, &values)
+}
+
+#[test]
+fn test_solve_and_solve_adjoint() -> Result<(), String> {
+    // A = [[0, 2, 1],
+    //      [1, 1, 0],
+    //      [3, 0, 1]], zero leading entry forces a row swap.
+    let a = SquareMatrix::create(3, &[0.0, 2.0, 1.0, 1.0, 1.0, 0.0, 3.0, 0.0, 1.0]);
+    let lup = a.lup_factorization(1e-12)?;
+    assert_eq!(lup.get_order(), 3);
+
+    // A * [1, 2, 3] = [7, 3, 6], Aᵀ * [1, 2, 3] = [11, 4, 4].
+    let x = lup.solve(&[7.0, 3.0, 6.0])?;
+    let x_t = lup.solve_adjoint(&[11.0, 4.0, 4.0])?;
+    for (value, expected) in x.iter().zip([1.0f64, 2.0, 3.0]) {
+        assert!((value - expected).abs() < 1e-12);
+    }
+    for (value, expected) in x_t.iter().zip([1.0f64, 2.0, 3.0]) {
+        assert!((value - expected).abs() < 1e-12);
+    }
+
+    assert!(lup.solve(&[1.0, 2.0]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_solve_adjoint_complex() -> Result<(), String> {
+    let c = |re: f64, im: f64| Complex::create(re, im);
+    // A = [[1 + i, 2], [0, 3 - i]], Aᴴ = [[1 - i, 0], [2, 3 + i]].
+    let a = SquareMatrix::create(2, &[c(1.0, 1.0), c(2.0, 0.0), c(0.0, 0.0), c(3.0, -1.0)]);
+    let lup = a.lup_factorization(1e-12)?;
+    // Aᴴ * [1, i] = [1 - i, 2 + (3 + i) i] = [1 - i, 1 + 3i].
+    let x = lup.solve_adjoint(&[c(1.0, -1.0), c(1.0, 3.0)])?;
+    assert!((x[0] - c(1.0, 0.0)).abs() < 1e-12);
+    assert!((x[1] - c(0.0, 1.0)).abs() < 1e-12);
+    Ok(())
+}
+
+#[test]
+fn test_inverse_norm_1_estimate() -> Result<(), String> {
+    // A⁻¹ = [[-2, 1], [1.5, -0.5]], ‖A⁻¹‖₁ = 3.5.
+    let a = SquareMatrix::create(2, &[1.0f64, 2.0, 3.0, 4.0]);
+    let estimate = a.lup_factorization(1e-12)?.inverse_norm_1_estimate();
+    assert!((estimate - 3.5).abs() < 1e-12);
+
+    let r = |n: i128| Rational::from_integer(n);
+    let exact = SquareMatrix::create(2, &[r(1), r(2), r(3), r(4)]);
+    let estimate = exact
+        .lup_factorization(Rational::from_integer(0))?
+        .inverse_norm_1_estimate();
+    assert_eq!(estimate, Rational::create(7, 2)?);
+
+    // The estimate is a lower bound and within a small factor of the truth.
+    let h = hilbert(5);
+    let exact_norm = 413280.0;
+    let estimate = h.lup_factorization(1e-15)?.inverse_norm_1_estimate();
+    assert!(estimate <= exact_norm * (1.0 + 1e-6));
+    assert!(estimate >= exact_norm / 3.0);
+    Ok(())
+}
+
+#[test]
+fn test_determinant_and_log_determinant() -> Result<(), String> {
+    let a = SquareMatrix::create(3, &[0.0, 2.0, 1.0, 1.0, 1.0, 0.0, 3.0, 0.0, 1.0]);
+    let lup = a.lup_factorization(1e-12)?;
+    assert!((lup.determinant() - -5.0f64).abs() < 1e-12);
+    let (sign, log_modulus) = lup.log_determinant();
+    assert_eq!(sign, -1.0);
+    assert!((log_modulus - 5.0f64.ln()).abs() < 1e-12);
+
+    // Cyclic permutation: two transpositions, det = 1.
+    let r = |n: i128| Rational::from_integer(n);
+    let p = SquareMatrix::create(3, &[0, 1, 0, 0, 0, 1, 1, 0, 0].map(r));
+    assert_eq!(p.lup_factorization(r(0))?.determinant(), r(1));
+
+    let c = |re: f64, im: f64| Complex::create(re, im);
+    let i = SquareMatrix::create(2, &[c(0.0, 1.0), c(0.0, 0.0), c(0.0, 0.0), c(0.0, 1.0)]);
+    let (sign, log_modulus) = i.lup_factorization(1e-12)?.log_determinant();
+    assert!((sign - c(-1.0, 0.0)).abs() < 1e-12);
+    assert!(log_modulus.abs() < 1e-12);
+    Ok(())
+}
+
+#[test]
+fn test_solve_matrix() -> Result<(), String> {
+    // X = [[1, -1], [2, 0], [3, 1]], A X = [[7, 1], [3, -1], [6, -2]].
+    let a = SquareMatrix::create(3, &[0.0, 2.0, 1.0, 1.0, 1.0, 0.0, 3.0, 0.0, 1.0]);
+    let lup = a.lup_factorization(1e-12)?;
+    let x = lup.solve_matrix(&Matrix::create(3, 2, &[7.0, 1.0, 3.0, -1.0, 6.0, -2.0]))?;
+    let expected = [1.0f64, -1.0, 2.0, 0.0, 3.0, 1.0];
+    for (k, expected) in expected.iter().enumerate() {
+        let value = x.get_element_value(&Position(k / 2, k % 2))?;
+        assert!((value - expected).abs() < 1e-12);
+    }
+
+    assert_eq!(
+        lup.solve_matrix(&Matrix::create(2, 2, &[0.0; 4])),
+        Err("LupFactorization: dimension mismatch: A is 3x3, B is 2x2".to_string())
+    );
+    Ok(())
+}
@@ -83,3 +83,19 @@ fn test_into_matrix() {
 
     assert_eq!(m.into_matrix(), expected);
 }
+
+#[test]
+fn test_expand_block_diagonal() {
+    let m = Matrix::create(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+
+    let expected = Matrix::create(
+        4,
+        4,
+        &[
+            1.0, 2.0, 0.0, 0.0, 3.0, 4.0, 0.0, 0.0, 0.0, 0.0, 1.0, 2.0, 0.0, 0.0, 3.0, 4.0,
+        ],
+    );
+
+    assert_eq!(m.expand_block_diagonal(2), expected);
+    assert_eq!(m.expand_block_diagonal(4).get_shape(), &Shape(8, 8));
+}
@@ -0,0 +1,138 @@
+#![allow(unused_imports)]
+
+use crate::{BasicOperationsTrait, Position, Rational, SquareMatrix, SquareMatrixTrait};
+
+/// Largest element-wise difference relative to the largest modulus of `expected`.
+fn relative_error<M>(actual: &M, expected: &SquareMatrix<f64>) -> f64
+where
+    M: BasicOperationsTrait<Value = f64>,
+{
+    let scale = expected
+        .get_elements()
+        .values()
+        .fold(0.0f64, |max, value| max.max(value.abs()));
+    expected
+        .get_elements()
+        .iter()
+        .map(|(position, value)| (actual.get_element_value(position).unwrap() - value).abs())
+        .fold(0.0, f64::max)
+        / scale
+}
+
+#[test]
+fn test_powi() -> Result<(), String> {
+    // Fibonacci matrix: A^n = [[F(n + 1), F(n)], [F(n), F(n - 1)]].
+    let a = SquareMatrix::create(2, &[1.0, 1.0, 1.0, 0.0]);
+
+    assert_eq!(
+        a.powi(10, 1e-12)?,
+        SquareMatrix::create(2, &[89.0, 55.0, 55.0, 34.0])
+    );
+    assert_eq!(a.powi(1, 1e-12)?, a);
+    assert_eq!(
+        a.powi(0, 1e-12)?,
+        SquareMatrix::create(2, &[1.0, 0.0, 0.0, 1.0])
+    );
+
+    // A⁻¹ = [[0, 1], [1, -1]] is exact in rational arithmetic.
+    let r = |n: i128| Rational::from_integer(n);
+    let exact = SquareMatrix::create(2, &[1, 1, 1, 0].map(r));
+    assert_eq!(
+        exact.powi(-3, r(0))?,
+        SquareMatrix::create(2, &[-1, 2, 2, -3].map(r))
+    );
+
+    let singular = SquareMatrix::create(2, &[1, 2, 2, 4].map(r));
+    assert_eq!(singular.powi(-1, r(0)), Err("Singular matrix".to_string()));
+    Ok(())
+}
+
+#[test]
+fn test_expm() -> Result<(), String> {
+    let e = 1.0f64.exp();
+
+    // Nilpotent: the series stops after the linear term.
+    let nilpotent = SquareMatrix::create(2, &[0.0, 1.0, 0.0, 0.0]);
+    let expected = SquareMatrix::create(2, &[1.0, 1.0, 0.0, 1.0]);
+    assert!(relative_error(&nilpotent.expm()?, &expected) < 1e-15);
+
+    // Rotation generator.
+    let theta = 1.2f64;
+    let generator = SquareMatrix::create(2, &[0.0, -theta, theta, 0.0]);
+    let rotation = SquareMatrix::create(2, &[theta.cos(), -theta.sin(), theta.sin(), theta.cos()]);
+    assert!(relative_error(&generator.expm()?, &rotation) < 1e-14);
+
+    // Non-normal with eigenvalues 1 and 2: e^A = [[e, e² - e], [0, e²]].
+    let a = SquareMatrix::create(2, &[1.0, 1.0, 0.0, 2.0]);
+    let expected = SquareMatrix::create(2, &[e, e * e - e, 0.0, e * e]);
+    assert!(relative_error(&a.expm()?, &expected) < 1e-14);
+
+    // ‖A‖₁ = 20 needs scaling and squaring; both entries keep their relative accuracy.
+    let stiff = SquareMatrix::create(2, &[10.0, 0.0, 0.0, -10.0]);
+    let exponential = stiff.expm()?;
+    let (large, small) = (
+        *exponential.get_element_value(&Position(0, 0))?,
+        *exponential.get_element_value(&Position(1, 1))?,
+    );
+    assert!((large - 10.0f64.exp()).abs() / 10.0f64.exp() < 1e-13);
+    assert!((small - (-10.0f64).exp()).abs() / (-10.0f64).exp() < 1e-13);
+
+    let partly_nan = SquareMatrix::create(2, &[f64::NAN, 0.0, 0.0, 1.0]);
+    assert_eq!(
+        partly_nan.expm(),
+        Err("Matrix exponential: matrix has NaN entries!".to_string())
+    );
+
+    // e^1e300 is not representable.
+    let huge = SquareMatrix::create(2, &[1e300, 0.0, 0.0, 1.0]);
+    assert_eq!(
+        huge.expm(),
+        Err("Matrix exponential: result overflows!".to_string())
+    );
+    Ok(())
+}
+
+#[test]
+fn test_sqrtm() -> Result<(), String> {
+    let diagonal = SquareMatrix::create(3, &[4.0, 0.0, 0.0, 0.0, 9.0, 0.0, 0.0, 0.0, 16.0]);
+    let expected = SquareMatrix::create(3, &[2.0, 0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0, 4.0]);
+    assert!(relative_error(&diagonal.sqrtm(1e-14, 50)?, &expected) < 1e-14);
+
+    // SPD: the principal root is SPD and squares back to A.
+    let a = SquareMatrix::create(3, &[4.0, 1.0, 0.5, 1.0, 3.0, 0.0, 0.5, 0.0, 2.0]);
+    let root = a.sqrtm(1e-14, 50)?;
+    assert!(relative_error(&root, &root.transpose()) < 1e-14);
+    assert!(relative_error(&root.multiply(&root)?, &a) < 1e-13);
+
+    // Eigenvalue -1: no real principal square root.
+    let negative = SquareMatrix::create(2, &[-1.0, 0.0, 0.0, 1.0]);
+    assert!(negative.sqrtm(1e-14, 50).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_logm() -> Result<(), String> {
+    let e = 1.0f64.exp();
+
+    let diagonal = SquareMatrix::create(2, &[e, 0.0, 0.0, e * e]);
+    let expected = SquareMatrix::create(2, &[1.0, 0.0, 0.0, 2.0]);
+    assert!(relative_error(&diagonal.logm(1e-15, 50)?, &expected) < 1e-13);
+
+    // Round trips through the exponential.
+    let b = SquareMatrix::create(3, &[0.1, 0.5, 0.0, -0.3, 0.2, 0.4, 0.0, -0.1, -0.2]);
+    assert!(relative_error(&b.expm()?.logm(1e-15, 50)?, &b) < 1e-12);
+
+    let a = SquareMatrix::create(3, &[4.0, 1.0, 0.5, 1.0, 3.0, 0.0, 0.5, 0.0, 2.0]);
+    assert!(relative_error(&a.logm(1e-15, 50)?.expm()?, &a) < 1e-12);
+
+    assert_eq!(
+        SquareMatrix::create(2, &[1.0, 0.0, 0.0, 1.0]).logm(1e-15, 50)?,
+        SquareMatrix::create(2, &[0.0, 0.0, 0.0, 0.0])
+    );
+    assert!(
+        SquareMatrix::create(2, &[0.0, 0.0, 0.0, 1.0])
+            .logm(1e-15, 50)
+            .is_err()
+    );
+    Ok(())
+}
@@ -0,0 +1,174 @@
+#![allow(unused_imports)]
+
+use crate::tests::helpers::{laplacian_2d, numbered_laplacian_2d};
+use crate::{
+    BasicOperationsTrait, CsrMatrix, OrderingReport, Permutation, Position, SparseCholeskySymbolic,
+    SquareMatrix, TryIntoSymmetricCompactedMatrixTrait,
+};
+
+/// Scatters the nodes of an `n`-node graph (`n` coprime with 7).
+fn scattered(n: usize) -> impl Fn(usize) -> usize {
+    move |k| (7 * k) % n
+}
+
+fn cholesky_fill(a: &CsrMatrix<f64>) -> usize {
+    SparseCholeskySymbolic::create(a).unwrap().get_l_nnz()
+}
+
+#[test]
+fn test_reverse_cuthill_mckee() -> Result<(), String> {
+    // A path numbered out of order gets bandwidth 1 back.
+    let n = 30;
+    let label = scattered(n);
+    let mut triplets = Vec::new();
+    for k in 0..n {
+        triplets.push((label(k), label(k), 2.0));
+        if k + 1 < n {
+            triplets.push((label(k), label(k + 1), -1.0));
+            triplets.push((label(k + 1), label(k), -1.0));
+        }
+    }
+    let path = CsrMatrix::from_coo(n, n, &triplets)?;
+    let p = path.reverse_cuthill_mckee()?;
+    assert!(path.bandwidth() > 1);
+    assert_eq!(path.permute_symmetric(&p)?.bandwidth(), 1);
+
+    // On a scattered grid the bandwidth comes back to the grid width.
+    let grid = numbered_laplacian_2d(8, 0.0, scattered(64));
+    let report = grid.ordering_report(&grid.reverse_cuthill_mckee()?)?;
+    assert!(report.bandwidth_after <= 8);
+    assert!(report.bandwidth_after < report.bandwidth_before);
+    assert!(report.profile_after < report.profile_before);
+    Ok(())
+}
+
+#[test]
+fn test_orderings_reduce_fill() -> Result<(), String> {
+    let a = laplacian_2d(12, 0.0);
+    let natural = cholesky_fill(&a);
+
+    for p in [a.approximate_minimum_degree()?, a.nested_dissection()?] {
+        assert_eq!(Permutation::create(p.get_indices().to_vec()), Ok(p.clone()));
+        assert!(cholesky_fill(&a.permute_symmetric(&p)?) < natural);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_approximate_minimum_degree_eliminates_hub_late() -> Result<(), String> {
+    // Arrow: node 0 is coupled to every other node, which fills L completely if it goes first.
+    let n = 10;
+    let mut triplets = vec![(0, 0, 10.0)];
+    for i in 1..n {
+        triplets.extend([(0, i, 1.0), (i, 0, 1.0), (i, i, 10.0)]);
+    }
+    let arrow = CsrMatrix::from_coo(n, n, &triplets)?;
+    let p = arrow.approximate_minimum_degree()?;
+
+    // Once one leaf is left, hub and leaf are interchangeable.
+    assert!(p.get_indices()[n - 2..].contains(&0));
+    assert_eq!(cholesky_fill(&arrow), n * (n + 1) / 2);
+    assert_eq!(cholesky_fill(&arrow.permute_symmetric(&p)?), 2 * n - 1);
+    Ok(())
+}
+
+#[test]
+fn test_disconnected_graph() -> Result<(), String> {
+    // Two grids side by side plus an isolated node.
+    let mut triplets = Vec::new();
+    for (offset, m) in [(0, 5), (25, 5)] {
+        let grid = laplacian_2d(m, 0.0);
+        for i in 0..m * m {
+            for idx in grid.get_row_ptr()[i]..grid.get_row_ptr()[i + 1] {
+                let j = grid.get_col_index()[idx];
+                triplets.push((offset + i, offset + j, grid.get_values()[idx]));
+            }
+        }
+    }
+    triplets.push((50, 50, 1.0));
+    let a = CsrMatrix::from_coo(51, 51, &triplets)?;
+
+    for p in [
+        a.reverse_cuthill_mckee()?,
+        a.approximate_minimum_degree()?,
+        a.nested_dissection()?,
+    ] {
+        assert!(Permutation::create(p.get_indices().to_vec()).is_ok());
+    }
+
+    // Every node of a diagonal matrix is a component of its own.
+    let n = 100_000;
+    let diagonal = CsrMatrix::create(n, n, vec![1.0; n], (0..n).collect(), (0..=n).collect())?;
+    let expected: Vec<usize> = (0..n).collect();
+    assert_eq!(diagonal.nested_dissection()?.get_indices(), &expected[..]);
+    Ok(())
+}
+
+#[test]
+fn test_permute_symmetric_and_profile() -> Result<(), String> {
+    let a = SquareMatrix::create(
+        4,
+        &[
+            4.0, 0.0, 0.0, 1.0, //
+            0.0, 5.0, 2.0, 0.0, //
+            0.0, 2.0, 6.0, 0.0, //
+            1.0, 0.0, 0.0, 7.0,
+        ],
+    );
+    let p = Permutation::create(vec![0, 3, 1, 2])?;
+    let b = a.permute_symmetric(&p)?;
+
+    assert_eq!(
+        b.to_dense(),
+        SquareMatrix::create(
+            4,
+            &[
+                4.0, 1.0, 0.0, 0.0, //
+                1.0, 7.0, 0.0, 0.0, //
+                0.0, 0.0, 5.0, 2.0, //
+                0.0, 0.0, 2.0, 6.0,
+            ],
+        )
+    );
+    assert_eq!(
+        CsrMatrix::from_square_matrix(&a)?
+            .permute_symmetric(&p)?
+            .get_values(),
+        &[4.0, 1.0, 1.0, 7.0, 5.0, 2.0, 2.0, 6.0]
+    );
+    assert_eq!(
+        a.ordering_report(&p)?,
+        OrderingReport {
+            bandwidth_before: 3,
+            bandwidth_after: 1,
+            profile_before: 4,
+            profile_after: 2,
+        }
+    );
+
+    // The profile is the off-diagonal part of the skyline storage.
+    let (values, _) = a.try_into_symmetric_compacted_matrix(1e-12)?;
+    assert_eq!(values.len() - 4, a.profile());
+    let (values, _) = b.try_into_symmetric_compacted_matrix(1e-12)?;
+    assert_eq!(values.len() - 4, b.profile());
+    Ok(())
+}
+
+#[test]
+fn test_ordering_errors() -> Result<(), String> {
+    let a = laplacian_2d(3, 0.0);
+    assert_eq!(
+        a.permute_symmetric(&Permutation::identity(4)).unwrap_err(),
+        "Permutation: order 4 does not match matrix order 9"
+    );
+    assert_eq!(
+        Permutation::create(vec![0, 2, 2]).unwrap_err(),
+        "Permutation: indices are not a bijection of 0..3!"
+    );
+    let rectangular = CsrMatrix::from_coo(2, 3, &[(0, 2, 1.0)])?;
+    assert_eq!(
+        rectangular.reverse_cuthill_mckee().unwrap_err(),
+        "CsrMatrix::ordering: matrix is not square: A is 2x3"
+    );
+    Ok(())
+}
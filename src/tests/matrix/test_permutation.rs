@@ -0,0 +1,159 @@
+#![allow(unused_imports)]
+
+use crate::{
+    BasicOperationsTrait, CsrMatrix, Matrix, Permutation, PermuteTrait, Position, SMatrix,
+    SquareMatrix, SquareMatrixTrait, TryIntoSquareMatrixTrait, Vector,
+};
+
+#[test]
+fn test_create_and_inverse() -> Result<(), String> {
+    let p = Permutation::create(vec![2, 0, 3, 1])?;
+
+    assert_eq!(p.get_order(), 4);
+    assert_eq!(p.inverse().get_indices(), &[1, 3, 0, 2]);
+    assert_eq!(p.compose(&p.inverse())?, Permutation::identity(4));
+    assert_eq!(p.inverse().compose(&p)?, Permutation::identity(4));
+    assert_eq!(
+        Permutation::create(vec![0, 4, 1]),
+        Err("Permutation: indices are not a bijection of 0..3!".to_string())
+    );
+    assert_eq!(
+        p.compose(&Permutation::identity(3)),
+        Err("Permutation: order 3 does not match matrix order 4".to_string())
+    );
+    Ok(())
+}
+
+#[test]
+fn test_compose_matches_matrix_product() -> Result<(), String> {
+    let p = Permutation::create(vec![1, 2, 0, 3])?;
+    let q = Permutation::create(vec![3, 1, 0, 2])?;
+    let product = p.to_matrix::<f64>().multiply(&q.to_matrix::<f64>())?;
+
+    assert_eq!(
+        p.compose(&q)?.to_matrix::<f64>().to_dense_values(),
+        product.try_into_square_matrix()?.to_dense_values()
+    );
+
+    let x = [10.0, 20.0, 30.0, 40.0];
+    assert_eq!(p.compose(&q)?.apply(&x)?, p.apply(&q.apply(&x)?)?);
+    Ok(())
+}
+
+#[test]
+fn test_sign() -> Result<(), String> {
+    for indices in [
+        vec![0, 1, 2, 3],
+        vec![1, 0, 2, 3],
+        vec![1, 2, 0, 3],
+        vec![3, 2, 1, 0],
+        vec![1, 2, 3, 0],
+    ] {
+        let p = Permutation::create(indices)?;
+        assert_eq!(p.sign() as f64, p.to_matrix::<f64>().determinant(1e-12));
+        assert_eq!(p.is_even(), p.sign() == 1);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_apply_to_vectors() -> Result<(), String> {
+    let p = Permutation::create(vec![2, 0, 1])?;
+    let x = [1.0, 2.0, 3.0];
+
+    assert_eq!(p.apply(&x)?, vec![3.0, 1.0, 2.0]);
+    assert_eq!(p.apply_inverse(&p.apply(&x)?)?, x.to_vec());
+    assert_eq!(
+        Vector::create(&x).permute_rows(&p)?,
+        Vector::create(&[3.0, 1.0, 2.0])
+    );
+    assert_eq!(
+        p.apply(&[1.0]),
+        Err("Permutation: order 3 does not match matrix order 1".to_string())
+    );
+    Ok(())
+}
+
+#[test]
+fn test_permute_rows_and_columns() -> Result<(), String> {
+    let values = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let rows = Permutation::create(vec![1, 0])?;
+    let columns = Permutation::create(vec![2, 0, 1])?;
+    let expected_rows = [4.0, 5.0, 6.0, 1.0, 2.0, 3.0];
+    let expected_columns = [3.0, 1.0, 2.0, 6.0, 4.0, 5.0];
+
+    let m = Matrix::create(2, 3, &values);
+    assert_eq!(m.permute_rows(&rows)?, Matrix::create(2, 3, &expected_rows));
+    assert_eq!(
+        m.permute_columns(&columns)?,
+        Matrix::create(2, 3, &expected_columns)
+    );
+    assert!(m.permute_rows(&columns).is_err());
+
+    let s = SMatrix::<f64, 2, 3>::create(&values);
+    assert_eq!(s.permute_rows(&rows)?, SMatrix::create(&expected_rows));
+    assert_eq!(
+        s.permute_columns(&columns)?,
+        SMatrix::create(&expected_columns)
+    );
+
+    let triplets: Vec<(usize, usize, f64)> = values
+        .iter()
+        .enumerate()
+        .map(|(k, &v)| (k / 3, k % 3, v))
+        .collect();
+    let csr = CsrMatrix::from_coo(2, 3, &triplets)?;
+    assert_eq!(csr.permute_rows(&rows)?.get_values(), &expected_rows);
+    assert_eq!(
+        csr.permute_columns(&columns)?.get_values(),
+        &expected_columns
+    );
+    assert_eq!(
+        csr.permute_columns(&columns)?.get_col_index(),
+        &[0, 1, 2, 0, 1, 2]
+    );
+
+    // P * A is the product with the permutation matrix.
+    let a = SquareMatrix::create(3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    assert_eq!(
+        a.permute_rows(&columns)?.to_dense_values(),
+        columns
+            .to_matrix()
+            .multiply(&a)?
+            .try_into_square_matrix()?
+            .to_dense_values()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_lup_permutation() -> Result<(), String> {
+    let a = SquareMatrix::create(3, &[0.0, 2.0, 1.0, 1.0, 1.0, 0.0, 3.0, 0.0, 1.0]);
+    let factorization = a.lup_factorization(1e-12)?;
+    let p = factorization.get_permutation();
+    assert_eq!(p.get_indices(), factorization.get_pivot_order());
+
+    // P * A = L * U.
+    let n = 3;
+    let lu = factorization.get_lu();
+    let pa = a.permute_rows(p)?;
+    for i in 0..n {
+        for j in 0..n {
+            let mut sum = 0.0f64;
+            for k in 0..=i.min(j) {
+                let l = if k == i {
+                    1.0
+                } else {
+                    lu[p.get_indices()[i] * n + k]
+                };
+                sum += l * lu[p.get_indices()[k] * n + j];
+            }
+            assert!((sum - pa.get_element_value(&Position(i, j))?).abs() < 1e-14);
+        }
+    }
+
+    let sparse = CsrMatrix::from_square_matrix(&a)?.lu()?;
+    assert_eq!(sparse.get_permutation().get_order(), 3);
+    assert!((factorization.determinant() - (-5.0f64)).abs() < 1e-14);
+    Ok(())
+}
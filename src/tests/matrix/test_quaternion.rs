@@ -0,0 +1,91 @@
+#![allow(unused_imports)]
+
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use crate::{BasicOperationsTrait, Position, Quaternion, Vector3};
+
+const ABS_TOL: f64 = 1e-12;
+
+fn assert_vector_close(actual: &Vector3<f64>, expected: [f64; 3]) {
+    for (a, e) in actual.get_components().iter().zip(expected) {
+        assert!((a - e).abs() < ABS_TOL, "{actual:?} != {expected:?}");
+    }
+}
+
+#[test]
+fn test_axis_angle() -> Result<(), String> {
+    let q = Quaternion::from_axis_angle(&Vector3::create(&[0.0, 0.0, 2.0]), FRAC_PI_2)?;
+    assert!((q.norm() - 1.0).abs() < ABS_TOL);
+    assert_vector_close(
+        &q.rotate(&Vector3::create(&[1.0, 0.0, 0.0])),
+        [0.0, 1.0, 0.0],
+    );
+
+    let (axis, angle) = q.to_axis_angle();
+    assert_vector_close(&axis, [0.0, 0.0, 1.0]);
+    assert!((angle - FRAC_PI_2).abs() < ABS_TOL);
+
+    // -q is the same rotation and reports the same axis and angle.
+    let (axis, angle) = Quaternion::create(-q.w, -q.x, -q.y, -q.z).to_axis_angle();
+    assert_vector_close(&axis, [0.0, 0.0, 1.0]);
+    assert!((angle - FRAC_PI_2).abs() < ABS_TOL);
+
+    assert_eq!(Quaternion::<f64>::identity().to_axis_angle().1, 0.0);
+    assert!(Quaternion::from_axis_angle(&Vector3::create(&[0.0; 3]), 1.0).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_product_inverse_and_rotation_matrix() -> Result<(), String> {
+    let q_1 = Quaternion::from_axis_angle(&Vector3::create(&[1.0, 2.0, -1.0]), 0.7)?;
+    let q_2 = Quaternion::from_axis_angle(&Vector3::create(&[-3.0, 0.5, 1.0]), -1.9)?;
+    let v = Vector3::create(&[0.3, -1.2, 2.5]);
+
+    // q_1 * q_2 rotates by q_2 first.
+    let expected = q_1.rotate(&q_2.rotate(&v)).get_components();
+    assert_vector_close(&(q_1 * q_2).rotate(&v), expected);
+
+    let identity = q_1 * q_1.inverse()?;
+    for (a, e) in identity.get_components().iter().zip([1.0, 0.0, 0.0, 0.0]) {
+        assert!((a - e).abs() < ABS_TOL);
+    }
+    assert!(Quaternion::create(0.0, 0.0, 0.0, 0.0).inverse().is_err());
+
+    let m = q_1.to_rotation_matrix();
+    let [x, y, z] = v.get_components();
+    let rotated = (0..3)
+        .map(|i| {
+            *m.get_element_value(&Position(i, 0)).unwrap() * x
+                + *m.get_element_value(&Position(i, 1)).unwrap() * y
+                + *m.get_element_value(&Position(i, 2)).unwrap() * z
+        })
+        .collect::<Vec<f64>>();
+    assert_vector_close(&q_1.rotate(&v), [rotated[0], rotated[1], rotated[2]]);
+
+    let q = Quaternion::from_rotation_matrix(&m)?;
+    assert!((q.dot(&q_1).abs() - 1.0).abs() < ABS_TOL);
+    Ok(())
+}
+
+#[test]
+fn test_slerp() -> Result<(), String> {
+    let axis = Vector3::create(&[1.0f64, 1.0, 0.0]);
+    let q_0 = Quaternion::identity();
+    let q_1 = Quaternion::from_axis_angle(&axis, 2.0)?;
+    let (_, angle) = q_0.slerp(&q_1, 0.25).to_axis_angle();
+    assert!((angle - 0.5).abs() < ABS_TOL);
+
+    // The shorter arc is taken even when the target has the opposite sign.
+    let q_2 = Quaternion::from_axis_angle(&axis, 1.0)?;
+    let q_2_negated = Quaternion::create(-q_2.w, -q_2.x, -q_2.y, -q_2.z);
+    let (_, angle) = q_0.slerp(&q_2_negated, 0.5).to_axis_angle();
+    assert!((angle - 0.5).abs() < ABS_TOL);
+
+    // Nearly identical endpoints fall back to normalized linear interpolation.
+    let q_3 = Quaternion::from_axis_angle(&axis, 1e-9)?;
+    assert!((q_0.slerp(&q_3, 0.5).norm() - 1.0).abs() < ABS_TOL);
+
+    let q_4 = Quaternion::from_axis_angle(&axis, PI)?;
+    assert!((q_0.slerp(&q_4, 1.0).dot(&q_4) - 1.0).abs() < ABS_TOL);
+    Ok(())
+}
@@ -0,0 +1,155 @@
+#![allow(unused_imports)]
+
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use crate::{
+    BasicOperationsTrait, EulerSequence, Matrix, Position, Quaternion, Rotation3, Vector3,
+};
+
+const ABS_TOL: f64 = 1e-10;
+
+const SEQUENCES: [EulerSequence; 12] = [
+    EulerSequence::Xyz,
+    EulerSequence::Xzy,
+    EulerSequence::Yxz,
+    EulerSequence::Yzx,
+    EulerSequence::Zxy,
+    EulerSequence::Zyx,
+    EulerSequence::Xyx,
+    EulerSequence::Xzx,
+    EulerSequence::Yxy,
+    EulerSequence::Yzy,
+    EulerSequence::Zxz,
+    EulerSequence::Zyz,
+];
+
+fn assert_matrix_close(actual: &Matrix<f64>, expected: &Matrix<f64>) {
+    for i in 0..3 {
+        for j in 0..3 {
+            let a = *actual.get_element_value(&Position(i, j)).unwrap();
+            let e = *expected.get_element_value(&Position(i, j)).unwrap();
+            assert!((a - e).abs() < ABS_TOL, "{actual:?} != {expected:?}");
+        }
+    }
+}
+
+fn elementary(axis: usize, angle: f64) -> Matrix<f64> {
+    let (c, s) = (angle.cos(), angle.sin());
+    match axis {
+        0 => Matrix::create(3, 3, &[1.0, 0.0, 0.0, 0.0, c, -s, 0.0, s, c]),
+        1 => Matrix::create(3, 3, &[c, 0.0, s, 0.0, 1.0, 0.0, -s, 0.0, c]),
+        _ => Matrix::create(3, 3, &[c, -s, 0.0, s, c, 0.0, 0.0, 0.0, 1.0]),
+    }
+}
+
+#[test]
+fn test_euler_angles_match_elementary_rotations() {
+    let angles = [0.3, -1.1, 2.4];
+    for sequence in SEQUENCES {
+        let [a, b, c] = sequence.get_axes();
+        let expected = elementary(a, angles[0])
+            .multiply(&elementary(b, angles[1]))
+            .unwrap()
+            .multiply(&elementary(c, angles[2]))
+            .unwrap();
+        let rotation = Rotation3::from_euler_angles(angles, sequence);
+        assert_matrix_close(&rotation.to_rotation_matrix(), &expected);
+    }
+}
+
+#[test]
+fn test_euler_angles_round_trip() {
+    let samples = [
+        [0.3, 0.4, -2.0],
+        [-2.9, 1.2, 0.7],
+        [1.0, -0.2, 3.0],
+        // gimbal lock for Tait-Bryan (b = ±π/2) and proper Euler (b = 0, π) sequences
+        [0.5, FRAC_PI_2, 0.4],
+        [0.5, -FRAC_PI_2, 0.4],
+        [0.5, 0.0, 0.4],
+        [0.5, PI, 0.4],
+    ];
+    for sequence in SEQUENCES {
+        for angles in samples {
+            let rotation = Rotation3::from_euler_angles(angles, sequence);
+            let recovered = rotation.to_euler_angles(sequence, 1e-7);
+            let round_trip = Rotation3::from_euler_angles(recovered, sequence);
+            assert_matrix_close(
+                &round_trip.to_rotation_matrix(),
+                &rotation.to_rotation_matrix(),
+            );
+        }
+    }
+
+    let recovered = Rotation3::from_euler_angles([0.3f64, 0.4, -2.0], EulerSequence::Zyx)
+        .to_euler_angles(EulerSequence::Zyx, 1e-7);
+    for (r, e) in recovered.iter().zip([0.3, 0.4, -2.0]) {
+        assert!((r - e).abs() < ABS_TOL);
+    }
+}
+
+#[test]
+fn test_rotation_matrix_conversions() -> Result<(), String> {
+    for angles in [
+        [0.1, 0.2, 0.3],
+        [3.0, -0.5, 1.0],
+        [0.0, PI, 0.0],
+        [PI, 0.0, 0.0],
+    ] {
+        let rotation = Rotation3::from_euler_angles(angles, EulerSequence::Xyz);
+        let m = rotation.to_rotation_matrix();
+        let from_matrix = Rotation3::from_rotation_matrix(&m, 1e-12)?;
+        assert_matrix_close(&from_matrix.to_rotation_matrix(), &m);
+
+        let (axis, angle) = rotation.to_axis_angle();
+        let from_axis_angle = Rotation3::from_axis_angle(&axis, angle)?;
+        assert_matrix_close(&from_axis_angle.to_rotation_matrix(), &m);
+    }
+
+    let reflection = Matrix::create(3, 3, &[-1.0, 0.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, -1.0]);
+    assert_eq!(
+        Rotation3::from_rotation_matrix(&reflection, 1e-12),
+        Err("Rotation3: matrix is a reflection, not a rotation!".to_string())
+    );
+    let sheared = Matrix::create(3, 3, &[1.0, 0.1, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
+    assert!(Rotation3::from_rotation_matrix(&sheared, 1e-12).is_err());
+    assert!(Rotation3::from_rotation_matrix(&Matrix::create(2, 2, &[1.0; 4]), 1e-12).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_compose_inverse_slerp_apply() -> Result<(), String> {
+    let r_1 = Rotation3::from_axis_angle(&Vector3::create(&[0.0, 0.0, 1.0]), FRAC_PI_2)?;
+    let r_2 = Rotation3::from_axis_angle(&Vector3::create(&[1.0, 0.0, 0.0]), FRAC_PI_2)?;
+    let v = Vector3::create(&[0.0, 1.0, 0.0]);
+
+    // r_2 turns y into z, r_1 leaves z alone.
+    let composed = r_1.compose(&r_2);
+    for (a, e) in composed
+        .apply(&v)
+        .get_components()
+        .iter()
+        .zip([0.0, 0.0, 1.0])
+    {
+        assert!((a - e).abs() < ABS_TOL);
+    }
+    assert_matrix_close(
+        &(r_1 * r_2).to_rotation_matrix(),
+        &r_1.to_rotation_matrix()
+            .multiply(&r_2.to_rotation_matrix())?,
+    );
+
+    let identity = r_1.compose(&r_1.inverse());
+    assert_matrix_close(
+        &identity.to_rotation_matrix(),
+        &Rotation3::identity().to_rotation_matrix(),
+    );
+
+    let halfway = Rotation3::identity().slerp(&r_1, 0.5);
+    let (_, angle) = halfway.to_axis_angle();
+    assert!((angle - FRAC_PI_2 / 2.0).abs() < ABS_TOL);
+
+    let q = Quaternion::create(2.0, 0.0, 0.0, 0.0);
+    assert_eq!(Rotation3::from_quaternion(&q)?, Rotation3::identity());
+    Ok(())
+}
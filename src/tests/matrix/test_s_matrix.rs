@@ -0,0 +1,207 @@
+#![allow(unused_imports)]
+
+use crate::{
+    BasicOperationsTrait, Matrix, Position, SMatrix, SVector, Shape, SquareMatrix,
+    SquareMatrixTrait, Vector,
+};
+
+fn tridiagonal_4() -> SMatrix<f64, 4, 4> {
+    SMatrix::from_rows([
+        [4.0, 1.0, 0.0, 0.0],
+        [1.0, 4.0, 1.0, 0.0],
+        [0.0, 1.0, 4.0, 1.0],
+        [0.0, 0.0, 1.0, 4.0],
+    ])
+}
+
+#[test]
+fn test_create() {
+    let m = SMatrix::<f64, 2, 3>::create(&[1.0, 2.0, 3.0, 4.0]);
+
+    assert_eq!(m.get_rows(), &[[1.0, 2.0, 3.0], [4.0, 0.0, 0.0]]);
+    assert_eq!(m.get_shape(), Shape(2, 3));
+    assert_eq!(m, SMatrix::from_rows([[1.0, 2.0, 3.0], [4.0, 0.0, 0.0]]));
+}
+
+#[test]
+fn test_get_element_value() {
+    let mut m = SMatrix::<f64, 2, 2>::identity();
+
+    *m.get_mut_element_value(&Position(0, 1)).unwrap() = 5.0;
+
+    assert_eq!(m.get_element_value(&Position(0, 1)), Ok(&5.0));
+    assert_eq!(
+        m.get_element_value(&Position(2, 0)),
+        Err("Element is absent".to_string())
+    );
+}
+
+#[test]
+fn test_basic_operations() {
+    let a = SMatrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    let b = SMatrix::from_rows([[7.0, 8.0], [9.0, 10.0], [11.0, 12.0]]);
+
+    assert_eq!(
+        a.multiply(&b),
+        SMatrix::from_rows([[58.0, 64.0], [139.0, 154.0]])
+    );
+    assert_eq!(
+        a.transpose(),
+        SMatrix::from_rows([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]])
+    );
+    assert_eq!(
+        a.add(&a),
+        SMatrix::from_rows([[2.0, 4.0, 6.0], [8.0, 10.0, 12.0]])
+    );
+    assert_eq!(a.subtract(&a), SMatrix::zeros());
+    assert_eq!(a.multiply_by_scalar(2.0), a.add(&a));
+}
+
+#[test]
+fn test_determinant_and_inverse_closed_form() -> Result<(), String> {
+    let a = SMatrix::from_rows([[4.0, 7.0], [2.0, 6.0]]);
+
+    assert_eq!(a.determinant(1e-12), 10.0);
+    assert_eq!(
+        a.inverse(1e-12)?,
+        SMatrix::from_rows([[0.6, -0.7], [-0.2, 0.4]])
+    );
+
+    let b = SMatrix::from_rows([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+
+    assert_eq!(b.determinant(1e-12), 4.0);
+    assert_eq!(
+        b.inverse(1e-12)?,
+        SMatrix::from_rows([[0.75, 0.5, 0.25], [0.5, 1.0, 0.5], [0.25, 0.5, 0.75]])
+    );
+
+    let singular = SMatrix::from_rows([[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 1.0]]);
+
+    assert_eq!(singular.determinant(1e-12), 0.0);
+    assert_eq!(singular.inverse(1e-12), Err("Singular matrix".to_string()));
+
+    let nearly_singular = SMatrix::from_rows([[1.0, 1.0], [1.0, 1.0 + 1e-14]]);
+
+    assert_eq!(
+        nearly_singular.inverse(1e-12),
+        Err("Ill conditioned system".to_string())
+    );
+
+    // Condition number about 1e5: every pivot of the elimination passes `rel_tol`, so the closed
+    // form must accept it too, as the elimination does at order five. The cofactor formulas cancel
+    // heavily here, hence the loose check.
+    let e = 1e-5f64;
+    let well_posed = SMatrix::from_rows([
+        [1.0, 1.0, 1.0, 1.0],
+        [1.0, 1.0 + e, 1.0, 1.0],
+        [1.0, 1.0, 1.0 + e, 1.0],
+        [1.0, 1.0, 1.0, 1.0 + e],
+    ]);
+    let product = well_posed.multiply(&well_posed.inverse(1e-12)?);
+    for i in 0..4 {
+        for j in 0..4 {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!((product.get_rows()[i][j] - expected).abs() < 1e-6);
+        }
+    }
+    let mut x = Vector::create(&[0.0; 4]);
+    assert!(
+        well_posed
+            .into_square_matrix()
+            .inverse(&mut x, 1e-12)
+            .is_ok()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_determinant_inverse_and_solve_lup() -> Result<(), String> {
+    let a = tridiagonal_4();
+
+    assert!((a.determinant(1e-12) - 209.0).abs() < 1e-12);
+
+    let product = a.multiply(&a.inverse(1e-12)?);
+    for i in 0..4 {
+        for j in 0..4 {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!((product.get_rows()[i][j] - expected).abs() < 1e-14);
+        }
+    }
+
+    let x = a.solve(&SVector::from_components([6.0, 12.0, 18.0, 19.0]), 1e-12)?;
+    for (value, expected) in x.get_components().iter().zip([1.0, 2.0, 3.0, 4.0]) {
+        assert!((value - expected).abs() < 1e-14);
+    }
+
+    let dynamic = a.into_square_matrix();
+    assert!((a.determinant(1e-12) - dynamic.determinant(1e-12)).abs() < 1e-12);
+    Ok(())
+}
+
+#[test]
+fn test_lup_rejects_singular_matrix() {
+    // A zero row leaves an exactly zero pivot.
+    let mut rows = [[0.0; 5]; 5];
+    for i in 0..4 {
+        rows[i][i] = 2.0;
+        rows[i][i + 1] = 1.0;
+    }
+    let singular = SMatrix::<f64, 5, 5>::from_rows(rows);
+
+    assert_eq!(singular.inverse(1e-12), Err("Singular matrix".to_string()));
+    assert!(
+        singular
+            .solve(&SVector::from_components([1.0; 5]), 1e-12)
+            .is_err()
+    );
+    assert_eq!(singular.determinant(1e-12), 0.0);
+    let mut x = Vector::create(&[0.0; 5]);
+    assert!(
+        singular
+            .into_square_matrix()
+            .inverse(&mut x, 1e-12)
+            .is_err()
+    );
+}
+
+#[test]
+fn test_conversions() -> Result<(), String> {
+    let a = SMatrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+    assert_eq!(
+        a.into_matrix(),
+        Matrix::create(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0])
+    );
+    assert_eq!(SMatrix::try_from_matrix(&a.into_matrix())?, a);
+    assert_eq!(
+        SMatrix::<f64, 3, 2>::try_from_matrix(&a.into_matrix()),
+        Err("Shapes of matrices do not conform to each other!".to_string())
+    );
+
+    let b = tridiagonal_4();
+    let sparse = SquareMatrix::create(4, &[]);
+
+    assert_eq!(
+        b.into_square_matrix(),
+        SquareMatrix::create(4, b.get_rows().as_flattened())
+    );
+    assert_eq!(
+        SMatrix::<f64, 4, 4>::try_from_matrix(&sparse)?,
+        SMatrix::zeros()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_vector_operations() {
+    let a = SVector::from_components([1.0, 2.0, 3.0]);
+    let b = SVector::from_components([4.0, 5.0, 6.0]);
+
+    assert_eq!(a.dot_product(&b), 32.0);
+    assert_eq!(
+        a.cross_product(&b),
+        SVector::from_components([-3.0, 6.0, -3.0])
+    );
+    assert_eq!(SVector::from_components([3.0f64, 4.0]).norm(), 5.0);
+    assert_eq!(a.transpose().multiply(&b).get_rows(), &[[32.0]]);
+}
@@ -0,0 +1,92 @@
+#![allow(unused_imports)]
+
+use crate::tests::helpers::{laplacian_2d, max_error};
+use crate::{CsrMatrix, SparseCholeskySymbolic};
+
+#[test]
+fn test_elimination_tree_and_pattern() -> Result<(), String> {
+    // Tridiagonal: the tree is a path and L has no fill.
+    let tridiagonal = CsrMatrix::from_coo(
+        4,
+        4,
+        &[
+            (0, 0, 2.0f64),
+            (0, 1, -1.0),
+            (1, 0, -1.0),
+            (1, 1, 2.0),
+            (1, 2, -1.0),
+            (2, 1, -1.0),
+            (2, 2, 2.0),
+            (2, 3, -1.0),
+            (3, 2, -1.0),
+            (3, 3, 2.0),
+        ],
+    )?;
+    let symbolic = SparseCholeskySymbolic::create(&tridiagonal)?;
+    assert_eq!(symbolic.get_parent(), &[Some(1), Some(2), Some(3), None]);
+    assert_eq!(symbolic.get_l_col_ptr(), &[0, 2, 4, 6, 7]);
+    assert_eq!(symbolic.get_l_row_index(), &[0, 1, 1, 2, 2, 3, 3]);
+
+    // Arrow with the dense row first fills L completely.
+    let mut triplets = vec![(0, 0, 10.0f64)];
+    for i in 1..4 {
+        triplets.extend([(0, i, 1.0), (i, 0, 1.0), (i, i, 10.0)]);
+    }
+    let arrow = CsrMatrix::from_coo(4, 4, &triplets)?;
+    let symbolic = SparseCholeskySymbolic::create(&arrow)?;
+    assert_eq!(symbolic.get_parent(), &[Some(1), Some(2), Some(3), None]);
+    assert_eq!(symbolic.get_l_nnz(), 10);
+    Ok(())
+}
+
+#[test]
+fn test_factorize_and_solve() -> Result<(), String> {
+    let a = laplacian_2d(5, 0.0);
+    let expected: Vec<f64> = (0..25).map(|i| (i as f64 * 0.37).sin()).collect();
+    let b = a.spmv(&expected)?;
+
+    let x = a.cholesky()?.solve(&b)?;
+    assert!(max_error(&x, &expected) < 1e-13);
+
+    assert_eq!(
+        a.cholesky()?.solve(&[1.0]),
+        Err("SparseCholeskyFactorization: dimension mismatch: A is 25x25, b has len 1".to_string())
+    );
+    Ok(())
+}
+
+#[test]
+fn test_refactorize_with_same_pattern() -> Result<(), String> {
+    let symbolic = SparseCholeskySymbolic::create(&laplacian_2d(4, 0.0))?;
+    let mut factorization = symbolic.factorize(&laplacian_2d(4, 0.0))?;
+    let expected = vec![1.0; 16];
+
+    for shift in [0.5, 2.0, 10.0] {
+        let a = laplacian_2d(4, shift);
+        factorization.refactorize(&a)?;
+        let x = factorization.solve(&a.spmv(&expected)?)?;
+        assert!(max_error(&x, &expected) < 1e-14);
+    }
+
+    assert_eq!(
+        factorization
+            .refactorize(&laplacian_2d(3, 0.0))
+            .unwrap_err(),
+        "SparseCholesky: sparsity pattern differs from the symbolic analysis!"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_not_positive_definite() -> Result<(), String> {
+    let indefinite = CsrMatrix::from_coo(
+        2,
+        2,
+        &[(0, 0, 1.0f64), (0, 1, 2.0), (1, 0, 2.0), (1, 1, 1.0)],
+    )?;
+    assert_eq!(
+        indefinite.cholesky().unwrap_err(),
+        "SparseCholesky: matrix is not positive definite (pivot 1)"
+    );
+    Ok(())
+}
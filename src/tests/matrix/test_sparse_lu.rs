@@ -0,0 +1,126 @@
+#![allow(unused_imports)]
+
+use crate::tests::helpers::max_error;
+use crate::{CsrMatrix, SquareMatrix, SquareMatrixTrait};
+
+/// Nonsymmetric matrix with zeros on the diagonal, so the factorization has to pivot.
+fn nonsymmetric(scale: f64) -> CsrMatrix<f64> {
+    CsrMatrix::from_coo(
+        5,
+        5,
+        &[
+            (0, 1, 2.0 * scale),
+            (0, 4, 1.0),
+            (1, 0, 3.0),
+            (1, 2, -1.0),
+            (2, 1, 1.0),
+            (2, 3, 4.0 * scale),
+            (3, 0, 1.0),
+            (3, 4, -2.0),
+            (4, 2, 5.0),
+            (4, 3, 1.0),
+            (4, 4, 1.0),
+        ],
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_factorize_and_solve() -> Result<(), String> {
+    let a = nonsymmetric(1.0);
+    let expected = vec![1.0, -2.0, 3.0, 0.5, -1.5];
+    let b = a.spmv(&expected)?;
+
+    let lu = a.lu()?;
+    assert_eq!(lu.get_order(), 5);
+    let mut pivot_order = lu.get_pivot_order().to_vec();
+    pivot_order.sort();
+    assert_eq!(pivot_order, vec![0, 1, 2, 3, 4]);
+    assert!(max_error(&lu.solve(&b)?, &expected) < 1e-14);
+    Ok(())
+}
+
+#[test]
+fn test_partial_pivoting_matches_dense_lup() -> Result<(), String> {
+    let values = [
+        1e-3, 2.0, 0.0, 0.0, //
+        4.0, 1.0, 3.0, 0.0, //
+        0.0, 5.0, 1.0, 2.0, //
+        1.0, 0.0, 6.0, 1.0,
+    ];
+    let dense = SquareMatrix::create(4, &values);
+    let sparse = CsrMatrix::from_square_matrix(&dense)?;
+    let b = [1.0, 2.0, 3.0, 4.0];
+
+    let x = sparse.lu()?.solve(&b)?;
+    let x_dense = dense.lup_factorization(1e-12)?.solve(&b)?;
+    assert!(max_error(&x, &x_dense) < 1e-14);
+    // Row 1 holds the largest entry of column 0.
+    assert_eq!(sparse.lu()?.get_pivot_order()[0], 1);
+    Ok(())
+}
+
+#[test]
+fn test_refactorize_with_same_pattern() -> Result<(), String> {
+    let mut lu = nonsymmetric(1.0).lu()?;
+    let expected = vec![0.5, 1.0, -1.0, 2.0, 3.0];
+
+    for scale in [2.0, 0.5, 3.0] {
+        let a = nonsymmetric(scale);
+        lu.refactorize(&a)?;
+        let x = lu.solve(&a.spmv(&expected)?)?;
+        assert!(max_error(&x, &expected) < 1e-13);
+        assert!(max_error(&x, &a.lu()?.solve(&a.spmv(&expected)?)?) < 1e-13);
+    }
+
+    let other = CsrMatrix::from_coo(
+        5,
+        5,
+        &[
+            (0, 0, 1.0),
+            (1, 1, 1.0),
+            (2, 2, 1.0),
+            (3, 3, 1.0),
+            (4, 4, 1.0),
+        ],
+    )?;
+    assert_eq!(
+        lu.refactorize(&other).unwrap_err(),
+        "SparseLu: sparsity pattern differs from the factorized matrix!"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_duplicate_entries_are_summed() -> Result<(), String> {
+    // Row 0 stores (0, 0) twice: A = [[2, 0], [0, 3]].
+    let a = CsrMatrix::create(2, 2, vec![1.0, 1.0, 3.0], vec![0, 0, 1], vec![0, 2, 3])?;
+    assert_eq!(a.spmv(&[1.0, 1.0])?, vec![2.0, 3.0]);
+
+    let mut lu = a.lu()?;
+    assert_eq!(lu.solve(&[2.0, 3.0])?, vec![1.0, 1.0]);
+    lu.refactorize(&a)?;
+    assert_eq!(lu.solve(&[2.0, 3.0])?, vec![1.0, 1.0]);
+    Ok(())
+}
+
+#[test]
+fn test_singular_matrix() -> Result<(), String> {
+    // Columns 0 and 1 are parallel.
+    let singular = CsrMatrix::from_coo(
+        3,
+        3,
+        &[
+            (0, 0, 1.0f64),
+            (0, 1, 2.0),
+            (1, 0, 2.0),
+            (1, 1, 4.0),
+            (2, 2, 1.0),
+        ],
+    )?;
+    assert_eq!(
+        singular.lu().unwrap_err(),
+        "SparseLu: matrix is singular (column 1)"
+    );
+    Ok(())
+}
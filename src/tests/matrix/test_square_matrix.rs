@@ -102,6 +102,7 @@ fn test_add_value_inserts_and_removes_zero() {
 }
 
 #[test]
+#[allow(clippy::identity_op, clippy::erasing_op)]
 fn test_to_dense_values() {
     let mut m = SquareMatrix::create(3, &[]);
     m.add_value(Position(0, 0), 1.0);
@@ -0,0 +1,116 @@
+#![allow(unused_imports)]
+
+use crate::tests::helpers::{identity, laplacian_2d, max_error};
+use crate::{
+    AssemblyStorage, CooAssembler, CsrMatrix, LinearOperatorTrait, SparseCholeskySymbolic,
+    SquareMatrix, SymmetricCsrMatrix, conjugate_gradient,
+};
+
+#[test]
+fn test_conversions_and_spmv() -> Result<(), String> {
+    let full = laplacian_2d(4, 0.0);
+    let symmetric = SymmetricCsrMatrix::from_csr(&full, 1e-12)?;
+    assert_eq!(symmetric.get_order(), 16);
+    assert_eq!(symmetric.get_nnz(), (full.get_values().len() + 16) / 2);
+    assert_eq!(symmetric.get_row_ptr()[1], 3);
+    assert_eq!(&symmetric.get_col_index()[..3], &[0, 1, 4]);
+
+    let back = symmetric.to_csr()?;
+    assert_eq!(back.get_row_ptr(), full.get_row_ptr());
+    assert_eq!(back.get_col_index(), full.get_col_index());
+    assert_eq!(back.get_values(), full.get_values());
+
+    let x: Vec<f64> = (0..16).map(|i| (i as f64 * 0.7).cos()).collect();
+    assert!(max_error(&symmetric.spmv(&x)?, &full.spmv(&x)?) < 1e-14);
+    assert_eq!(symmetric.get_dimensions(), (16, 16));
+    assert_eq!(symmetric.apply_transpose(&x)?, symmetric.spmv(&x)?);
+    Ok(())
+}
+
+#[test]
+fn test_from_square_matrix_validates_symmetry() -> Result<(), String> {
+    let values = [
+        4.0,
+        1.0,
+        0.0, //
+        1.0 + 1e-10,
+        3.0,
+        -2.0, //
+        0.0,
+        -2.0,
+        5.0,
+    ];
+    let a = SquareMatrix::create(3, &values);
+    let symmetric = SymmetricCsrMatrix::from_square_matrix(&a, 1e-6)?;
+    assert_eq!(symmetric.get_values(), &[4.0, 1.0, 3.0, -2.0, 5.0]);
+    assert_eq!(
+        SymmetricCsrMatrix::from_square_matrix(&a, 1e-12).unwrap_err(),
+        "SymmetricCsrMatrix: element [1, 0] does not match with [0, 1]!"
+    );
+
+    // An entry without a mirror is not symmetric.
+    let one_sided = CsrMatrix::from_coo(2, 2, &[(0, 0, 1.0), (1, 0, 2.0), (1, 1, 1.0)])?;
+    assert_eq!(
+        SymmetricCsrMatrix::from_csr(&one_sided, 1e-6).unwrap_err(),
+        "SymmetricCsrMatrix: element [1, 0] does not match with [0, 1]!"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_from_upper_assembly() -> Result<(), String> {
+    let elements = [[0, 1], [1, 2], [2, 3]];
+    let element = [2.0, -1.0, -1.0, 2.0];
+    let mut upper = CooAssembler::create(4, AssemblyStorage::Upper);
+    let mut full = CooAssembler::create(4, AssemblyStorage::Full);
+    for dofs in &elements {
+        upper.add_element_matrix(dofs, &element)?;
+        full.add_element_matrix(dofs, &element)?;
+    }
+    let symmetric = upper.to_symmetric_csr()?;
+    assert_eq!(
+        symmetric,
+        SymmetricCsrMatrix::from_csr(&full.to_csr()?, 0.0)?
+    );
+
+    assert_eq!(
+        SymmetricCsrMatrix::from_upper_csr(&full.to_csr()?).unwrap_err(),
+        "SymmetricCsrMatrix: row 1 is not a sorted part of the upper triangle!"
+    );
+    assert_eq!(
+        SymmetricCsrMatrix::create(2, vec![1.0], vec![0], vec![0, 1]).unwrap_err(),
+        "SymmetricCsrMatrix: inconsistent CSR arrays!"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_solvers() -> Result<(), String> {
+    let full = laplacian_2d(6, 0.0);
+    let symmetric = SymmetricCsrMatrix::from_csr(&full, 0.0)?;
+    let expected: Vec<f64> = (0..36).map(|i| ((i * 5) % 9) as f64 - 4.0).collect();
+    let b = full.spmv(&expected)?;
+
+    let (x, _) = conjugate_gradient(&symmetric, &b, &symmetric.cholesky()?, 1e-12, 10)?;
+    assert!(max_error(&x, &expected) < 1e-10);
+    let (x, _) = conjugate_gradient(&symmetric, &b, &identity, 1e-12, 200)?;
+    assert!(max_error(&x, &expected) < 1e-9);
+
+    let symbolic = SparseCholeskySymbolic::create_symmetric(&symmetric);
+    let from_full = SparseCholeskySymbolic::create(&full)?;
+    assert_eq!(symbolic.get_parent(), from_full.get_parent());
+    assert_eq!(symbolic.get_l_row_index(), from_full.get_l_row_index());
+
+    let mut factorization = symbolic.factorize_symmetric(&symmetric)?;
+    assert!(max_error(&factorization.solve(&b)?, &expected) < 1e-12);
+    assert_eq!(
+        factorization.get_l_values(),
+        full.cholesky()?.get_l_values()
+    );
+
+    let shifted = SymmetricCsrMatrix::from_csr(&laplacian_2d(6, 1.0), 0.0)?;
+    factorization.refactorize_symmetric(&shifted)?;
+    let b = shifted.spmv(&expected)?;
+    assert!(max_error(&factorization.solve(&b)?, &expected) < 1e-12);
+    Ok(())
+}
@@ -3,7 +3,9 @@
 use std::collections::HashMap;
 
 use crate::{BasicOperationsTrait, IntoMatrixTrait, Position, Shape, VectorTrait};
-use crate::{Matrix, SquareMatrix, Vector3};
+use crate::{
+    Matrix, Quaternion, SquareMatrix, SquareMatrixTrait, TryIntoSquareMatrixTrait, Vector3,
+};
 
 #[test]
 fn test_create() {
@@ -229,7 +231,22 @@ fn test_rotation_matrix_to_align_with_vector() {
 
     let m_expected_3 = Matrix::create(3, 3, &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
 
-    let m_expected_4 = Matrix::create(3, 3, &[-1.0, 0.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, -1.0]);
+    // Half turn about u = (-1, 0, 10) / √101, perpendicular to v_1: R = 2 u uᵀ - I.
+    let m_expected_4 = Matrix::create(
+        3,
+        3,
+        &[
+            -0.9801980198019802,
+            -0.0,
+            -0.19801980198019803,
+            -0.0,
+            -1.0,
+            0.0,
+            -0.19801980198019803,
+            0.0,
+            0.9801980198019802,
+        ],
+    );
 
     let m_expected_5 = Matrix::create(3, 3, &[-1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, -1.0]);
 
@@ -245,3 +262,290 @@ fn test_rotation_matrix_to_align_with_vector() {
     assert_eq!(rotation_matrix_4, Ok(m_expected_4));
     assert_eq!(rotation_matrix_5, Ok(m_expected_5));
 }
+
+#[test]
+fn test_orthonormal_frame() -> Result<(), String> {
+    // Horizontal member along X: default reference is global Z, so local y points up.
+    let frame = Vector3::create(&[2.0, 0.0, 0.0]).orthonormal_frame(None)?;
+    assert_eq!(
+        frame,
+        Matrix::create(3, 3, &[1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, -1.0, 0.0])
+    );
+
+    // Vertical member: global Z is parallel, global Y is used instead.
+    let frame = Vector3::create(&[0.0, 0.0, -3.0]).orthonormal_frame(None)?;
+    assert_eq!(
+        frame,
+        Matrix::create(3, 3, &[0.0, 0.0, -1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0])
+    );
+
+    // A reference parallel to the axis falls back to the default.
+    let axis = Vector3::create(&[1.0, 1.0, 0.0]);
+    assert_eq!(
+        axis.orthonormal_frame(Some(&Vector3::create(&[-2.0, -2.0, 0.0]))),
+        axis.orthonormal_frame(None)
+    );
+
+    // The reference vector lies in the local x-y plane.
+    let axis = Vector3::create(&[1.0f64, 2.0, 2.0]);
+    let reference = Vector3::create(&[0.0, 1.0, 0.0]);
+    let frame = axis.orthonormal_frame(Some(&reference))?;
+    let product = frame.multiply(&frame.transpose())?;
+    for i in 0..3 {
+        for j in 0..3 {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            let value = *product.get_element_value(&Position(i, j))?;
+            assert!((value - expected).abs() < 1e-12);
+        }
+    }
+    let local_reference = frame.multiply(&reference)?;
+    assert!(local_reference.get_element_value(&Position(1, 0))? > &0.0);
+    assert!(local_reference.get_element_value(&Position(2, 0))?.abs() < 1e-12);
+
+    assert!(Vector3::create(&[0.0; 3]).orthonormal_frame(None).is_err());
+    Ok(())
+}
+
+/// Deterministic pseudo-random unit-ish vectors (64-bit LCG), scaled by `length`.
+fn sample_vectors(count: usize, length: f64) -> Vec<Vector3<f64>> {
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    let mut next = || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0
+    };
+    let mut vectors = vec![
+        Vector3::create(&[length, 0.0, 0.0]),
+        Vector3::create(&[0.0, length, 0.0]),
+        Vector3::create(&[0.0, 0.0, -length]),
+        Vector3::create(&[length / 2f64.sqrt(), length / 2f64.sqrt(), 0.0]),
+    ];
+    while vectors.len() < count {
+        let [x, y, z] = [next(), next(), next()];
+        let norm = (x * x + y * y + z * z).sqrt();
+        if norm > 1e-3 {
+            vectors.push(Vector3::create(&[
+                x / norm * length,
+                y / norm * length,
+                z / norm * length,
+            ]));
+        }
+    }
+    vectors
+}
+
+fn assert_proper_rotation_taking(m: &Matrix<f64>, from: &Vector3<f64>, to: &Vector3<f64>) {
+    let product = m.transpose().multiply(m).unwrap();
+    for i in 0..3 {
+        for j in 0..3 {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            let value = *product.get_element_value(&Position(i, j)).unwrap();
+            assert!((value - expected).abs() < 1e-12, "not orthogonal: {m:?}");
+        }
+    }
+    let determinant = m.try_into_square_matrix().unwrap().determinant(1e-12);
+    assert!(
+        (determinant - 1.0).abs() < 1e-12,
+        "det = {determinant}: {m:?}"
+    );
+    let rotated = m.multiply(from).unwrap();
+    for (i, expected) in to.get_components().into_iter().enumerate() {
+        let value = *rotated.get_element_value(&Position(i, 0)).unwrap();
+        assert!((value - expected).abs() < 1e-9, "{rotated:?} != {to:?}");
+    }
+}
+
+#[test]
+fn test_rotation_matrix_to_align_with_vector_is_proper_rotation() {
+    let (rel_tol, abs_tol) = (1e-9, 1e-12);
+    let vectors = sample_vectors(40, 3.0);
+    for from in &vectors {
+        let antiparallel = from.multiply_by_scalar(-1.0);
+        for to in vectors.iter().chain([from, &antiparallel]) {
+            let m = from
+                .rotation_matrix_to_align_with_vector(to, rel_tol, abs_tol)
+                .unwrap();
+            assert_proper_rotation_taking(&m, from, to);
+
+            let q = from
+                .quaternion_to_align_with_vector(to, rel_tol, abs_tol)
+                .unwrap();
+            assert!((q.norm() - 1.0).abs() < 1e-12);
+            assert_proper_rotation_taking(&q.to_rotation_matrix(), from, to);
+        }
+    }
+}
+
+#[test]
+fn test_quaternion_to_align_with_vector() -> Result<(), String> {
+    let (rel_tol, abs_tol) = (1e-4, 1e-7);
+    let v_1 = Vector3::create(&[0.0, 0.0, 5.0]);
+    let v_2 = Vector3::create(&[0.0, 5.0, 0.0]);
+
+    // Quarter turn about -x.
+    let q = v_1.quaternion_to_align_with_vector(&v_2, rel_tol, abs_tol)?;
+    let expected = Quaternion::from_axis_angle(
+        &Vector3::create(&[-1.0, 0.0, 0.0]),
+        std::f64::consts::FRAC_PI_2,
+    )?;
+    assert!((q.dot(&expected) - 1.0).abs() < 1e-12);
+
+    assert_eq!(
+        v_1.quaternion_to_align_with_vector(&v_1, rel_tol, abs_tol),
+        Ok(Quaternion::identity())
+    );
+    assert_eq!(
+        v_1.quaternion_to_align_with_vector(&v_1.multiply_by_scalar(-1.0), rel_tol, abs_tol),
+        Ok(Quaternion::create(0.0, 0.0, 1.0, 0.0))
+    );
+    assert!(
+        v_1.quaternion_to_align_with_vector(&Vector3::create(&[0.0, 0.0, 1.0]), rel_tol, abs_tol)
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_normalize_and_projections() -> Result<(), String> {
+    let v = Vector3::create(&[3.0, 0.0, 4.0]);
+    assert_eq!(v.normalize(), Ok(Vector3::create(&[0.6, 0.0, 0.8])));
+    assert!(Vector3::create(&[0.0; 3]).normalize().is_err());
+
+    let axis = Vector3::create(&[0.0, 0.0, 2.0]);
+    assert_eq!(
+        v.projection_onto_vector(&axis),
+        Ok(Vector3::create(&[0.0, 0.0, 4.0]))
+    );
+    assert!(
+        v.projection_onto_vector(&Vector3::create(&[0.0; 3]))
+            .is_err()
+    );
+
+    // Row-shaped inputs give the same result.
+    assert_eq!(
+        v.transpose().reflection(&axis),
+        Ok(Vector3::create(&[3.0, 0.0, -4.0]))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_signed_angle_about_axis() -> Result<(), String> {
+    use std::f64::consts::FRAC_PI_2;
+
+    let x = Vector3::create(&[1.0, 0.0, 0.0]);
+    let y = Vector3::create(&[0.0, 2.0, 5.0]);
+    let z = Vector3::create(&[0.0, 0.0, 1.0]);
+    let minus_z = Vector3::create(&[0.0, 0.0, -1.0]);
+
+    // Components along the axis are ignored.
+    assert!((x.signed_angle_about_axis(&y, &z)? - FRAC_PI_2).abs() < 1e-12);
+    assert!((x.signed_angle_about_axis(&y, &minus_z)? + FRAC_PI_2).abs() < 1e-12);
+    assert!(z.signed_angle_about_axis(&x, &z).is_err());
+    assert!(
+        x.signed_angle_about_axis(&y, &Vector3::create(&[0.0; 3]))
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_triple_products() {
+    let a = Vector3::create(&[1.0, 2.0, 3.0]);
+    let b = Vector3::create(&[-1.0, 0.0, 2.0]);
+    let c = Vector3::create(&[4.0, 1.0, 1.0]);
+
+    assert_eq!(a.scalar_triple_product(&b, &c), 13.0);
+    assert_eq!(b.scalar_triple_product(&c, &a), 13.0);
+    // a × (b × c) = b (a · c) - c (a · b)
+    assert_eq!(
+        a.vector_triple_product(&b, &c),
+        Vector3::create(&[-29.0, -5.0, 13.0])
+    );
+}
+
+#[test]
+fn test_distances() -> Result<(), String> {
+    let p = Vector3::create(&[1.0, 2.0, 3.0]);
+    assert_eq!(p.distance(&Vector3::create(&[4.0, 6.0, 3.0])), 5.0);
+
+    let origin = Vector3::create(&[0.0, 0.0, 0.0]);
+    let z = Vector3::create(&[0.0, 0.0, 3.0]);
+    assert_eq!(p.distance_to_line(&origin, &z)?, 5f64.sqrt());
+    assert!(p.distance_to_line(&origin, &origin).is_err());
+
+    assert_eq!(p.distance_to_plane(&origin, &z)?, 3.0);
+    assert_eq!(
+        p.distance_to_plane(&origin, &z.multiply_by_scalar(-1.0))?,
+        -3.0
+    );
+    assert!(p.distance_to_plane(&origin, &origin).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_triangle_area_and_normal() -> Result<(), String> {
+    let p_1 = Vector3::create(&[1.0, 1.0, 0.0]);
+    let p_2 = Vector3::create(&[3.0, 1.0, 0.0]);
+    let p_3 = Vector3::create(&[1.0, 4.0, 0.0]);
+
+    assert_eq!(Vector3::triangle_area(&p_1, &p_2, &p_3)?, 3.0);
+    assert_eq!(
+        Vector3::triangle_normal(&p_1, &p_2, &p_3)?,
+        Vector3::create(&[0.0, 0.0, 1.0])
+    );
+    assert_eq!(
+        Vector3::triangle_normal(&p_1, &p_3, &p_2)?,
+        Vector3::create(&[0.0, 0.0, -1.0])
+    );
+
+    let collinear = Vector3::create(&[5.0, 1.0, 0.0]);
+    assert_eq!(Vector3::triangle_area(&p_1, &p_2, &collinear)?, 0.0);
+    assert_eq!(
+        Vector3::triangle_normal(&p_1, &p_2, &collinear),
+        Err("Vector3: degenerate triangle has no normal!".to_string())
+    );
+    Ok(())
+}
+
+#[test]
+fn test_zero_length_inputs() -> Result<(), String> {
+    let (rel_tol, abs_tol) = (1e-4, 1e-7);
+    let v = Vector3::create(&[3.0, 0.0, 4.0]);
+    let zero = Vector3::create(&[0.0; 3]);
+
+    assert_eq!(
+        v.try_cosine_angle_between_vectors(&v.multiply_by_scalar(2.0))?,
+        1.0
+    );
+    assert_eq!(
+        v.try_cosine_angle_between_vectors(&zero),
+        Err("Vector3: angle with a zero length vector is undefined!".to_string())
+    );
+    assert!(zero.try_cosine_angle_between_vectors(&v).is_err());
+
+    assert_eq!(
+        v.try_projection_perpendicular_to_vector(&Vector3::create(&[0.0, 0.0, 2.0]))?,
+        Vector3::create(&[3.0, 0.0, 0.0])
+    );
+    assert_eq!(
+        v.try_projection_perpendicular_to_vector(&zero),
+        Err("Vector3: projection perpendicular to a zero length vector is undefined!".to_string())
+    );
+
+    let message = "Vector3: zero length vectors could not be aligned!".to_string();
+    assert_eq!(
+        zero.rotation_matrix_to_align_with_vector(&zero, rel_tol, abs_tol),
+        Err(message.clone())
+    );
+    assert_eq!(
+        zero.quaternion_to_align_with_vector(&zero, rel_tol, abs_tol),
+        Err(message)
+    );
+    assert!(
+        zero.rotation_matrix_to_align_with_vector(&v, rel_tol, abs_tol)
+            .is_err()
+    );
+    Ok(())
+}
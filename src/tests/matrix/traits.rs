@@ -1,4 +1,6 @@
 mod test_basic_operations_trait;
+mod test_linear_operator_trait;
+mod test_matrix_norm_trait;
 mod test_square_matrix_trait;
 mod test_try_into_square_matrix_trait;
 mod test_try_into_symmetric_compacted_matrix_trait;
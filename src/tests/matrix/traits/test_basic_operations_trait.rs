@@ -290,6 +290,34 @@ fn test_multiply() {
     );
 }
 
+#[test]
+fn test_multiply_blocked_matches_triple_loop() {
+    // Shapes chosen to cross the row and inner block boundaries of the kernel.
+    let (rows_number, inner, columns_number) = (37, 70, 23);
+    let lhs_values = (0..rows_number * inner)
+        .map(|i| ((i * 7919) % 101) as f64 / 13.0 - 3.0)
+        .collect::<Vec<f64>>();
+    let rhs_values = (0..inner * columns_number)
+        .map(|i| ((i * 104729) % 97) as f64 / 11.0 - 4.0)
+        .collect::<Vec<f64>>();
+    let lhs = Matrix::create(rows_number, inner, &lhs_values);
+    let rhs = Matrix::create(inner, columns_number, &rhs_values);
+
+    let mut expected_values = Vec::new();
+    for i in 0..rows_number {
+        for j in 0..columns_number {
+            let mut value = 0.0;
+            for k in 0..inner {
+                value += lhs_values[i * inner + k] * rhs_values[k * columns_number + j];
+            }
+            expected_values.push(value);
+        }
+    }
+    let expected = Matrix::create(rows_number, columns_number, &expected_values);
+
+    assert_eq!(lhs.multiply(&rhs), Ok(expected));
+}
+
 #[test]
 fn test_transpose() {
     let m = Matrix::create(2, 3, &[1.0, -2.0, 3.0, -4.0, 5.0, -6.0]);
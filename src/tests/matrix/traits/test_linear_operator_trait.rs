@@ -0,0 +1,121 @@
+#![allow(unused_imports)]
+
+use crate::tests::helpers::identity;
+use crate::{
+    ClosureOperator, CsrMatrix, LinearOperatorTrait, Matrix, SquareMatrix, conjugate_gradient,
+    largest_singular_value,
+};
+
+/// Matrix-free `tridiag(-1, 2, -1)`.
+fn laplacian_1d(x: &[f64]) -> Vec<f64> {
+    let n = x.len();
+    (0..n)
+        .map(|i| {
+            let left = if i > 0 { x[i - 1] } else { 0.0 };
+            let right = if i + 1 < n { x[i + 1] } else { 0.0 };
+            2.0 * x[i] - left - right
+        })
+        .collect()
+}
+
+#[test]
+fn test_storages_agree() -> Result<(), String> {
+    let values = [1.0, -2.0, 0.0, 3.0, 4.0, 5.0];
+    let dense = Matrix::create(2, 3, &values);
+    let sparse = CsrMatrix::from_coo(
+        2,
+        3,
+        &[
+            (0, 0, 1.0),
+            (0, 1, -2.0),
+            (1, 0, 3.0),
+            (1, 1, 4.0),
+            (1, 2, 5.0),
+        ],
+    )?;
+    let x = [1.0, 2.0, -1.0];
+    let y = [2.0, -1.0];
+
+    assert_eq!(dense.get_dimensions(), (2, 3));
+    assert_eq!(sparse.get_dimensions(), (2, 3));
+    assert_eq!(dense.apply(&x)?, vec![-3.0, 6.0]);
+    assert_eq!(sparse.apply(&x)?, vec![-3.0, 6.0]);
+    assert_eq!(dense.apply_transpose(&y)?, vec![-1.0, -8.0, -5.0]);
+    assert_eq!(sparse.apply_transpose(&y)?, vec![-1.0, -8.0, -5.0]);
+
+    let square = SquareMatrix::create(2, &[2.0, 1.0, 0.0, 3.0]);
+    assert_eq!(square.apply(&y)?, vec![3.0, -3.0]);
+    assert_eq!(square.apply_transpose(&y)?, vec![4.0, -1.0]);
+
+    assert_eq!(
+        dense.apply(&y).unwrap_err(),
+        "LinearOperator: dimension mismatch: operator is 2x3, operand has len 2"
+    );
+
+    // 1e16 + 1 - 1e16 is 0 in column order and 1 in others; every fresh map gets a new hash seed.
+    let cancelling = [1e16, 1.0, -1e16];
+    let ones = [1.0; 3];
+    let sparse_row = CsrMatrix::from_coo(1, 3, &[(0, 0, 1e16), (0, 1, 1.0), (0, 2, -1e16)])?;
+    assert_eq!(sparse_row.apply(&ones)?, vec![0.0]);
+    for _ in 0..20 {
+        assert_eq!(Matrix::create(1, 3, &cancelling).apply(&ones)?, vec![0.0]);
+        assert_eq!(
+            Matrix::create(3, 1, &cancelling).apply_transpose(&ones)?,
+            vec![0.0]
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_closure_operator() -> Result<(), String> {
+    let n = 20;
+    let operator = ClosureOperator::create(n, n, laplacian_1d);
+    assert_eq!(operator.get_dimensions(), (n, n));
+
+    let expected: Vec<f64> = (0..n).map(|i| (i as f64 * 0.3).sin()).collect();
+    let b = operator.apply(&expected)?;
+    let (x, info) = conjugate_gradient(&operator, &b, &identity, 1e-12, 100)?;
+    assert!(info.iterations <= n);
+    assert!(x.iter().zip(&expected).all(|(a, e)| (a - e).abs() < 1e-9));
+
+    assert_eq!(
+        operator.apply_transpose(&b).unwrap_err(),
+        "LinearOperator: transpose product is not available!"
+    );
+    assert_eq!(
+        operator.apply(&b[..5]).unwrap_err(),
+        "LinearOperator: dimension mismatch: operator is 20x20, operand has len 5"
+    );
+
+    let truncated = ClosureOperator::create(3, 3, |x: &[f64]| x[..2].to_vec());
+    assert_eq!(
+        truncated.apply(&[1.0, 2.0, 3.0]).unwrap_err(),
+        "ClosureOperator: closure returned 2 components, expected 3"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_largest_singular_value() -> Result<(), String> {
+    // A = diag(3, -5, 1) given as two closures.
+    let diagonal = [3.0, -5.0, 1.0];
+    let operator = ClosureOperator::create_with_transpose(
+        3,
+        3,
+        |x: &[f64]| x.iter().zip(&diagonal).map(|(x, d)| x * d).collect(),
+        |y: &[f64]| y.iter().zip(&diagonal).map(|(y, d)| y * d).collect(),
+    );
+    assert!((largest_singular_value(&operator, 1e-14, 1000)? - 5.0).abs() < 1e-10);
+
+    let dense = SquareMatrix::create(2, &[1.0f64, -2.0, 3.0, 4.0]);
+    let expected = (15.0 + 125f64.sqrt()).sqrt();
+    assert!((largest_singular_value(&dense, 1e-14, 1000)? - expected).abs() < 1e-10);
+
+    let without_transpose = ClosureOperator::create(20, 20, laplacian_1d);
+    assert_eq!(
+        largest_singular_value(&without_transpose, 1e-14, 1000).unwrap_err(),
+        "LinearOperator: transpose product is not available!"
+    );
+    Ok(())
+}
@@ -0,0 +1,54 @@
+#![allow(unused_imports)]
+
+use crate::{Complex, Matrix, MatrixNormTrait, SquareMatrix, Vector, Vector3};
+
+#[test]
+fn test_norms() {
+    // A = [[1, -2, 0],
+    //      [3,  4, 0]]
+    let a = Matrix::create(2, 3, &[1.0, -2.0, 0.0, 3.0, 4.0, 0.0]);
+    assert_eq!(a.norm_1(), 6.0);
+    assert_eq!(a.norm_inf(), 7.0);
+    assert_eq!(a.norm_frobenius(), 30f64.sqrt());
+    assert_eq!(a.norm_max(), 4.0);
+
+    let v = Vector::create(&[3.0, -4.0]);
+    assert_eq!(v.norm_1(), 7.0);
+    assert_eq!(v.norm_inf(), 4.0);
+    assert_eq!(v.norm_frobenius(), 5.0);
+
+    let v_3 = Vector3::create(&[1.0, -2.0, 2.0]);
+    assert_eq!(v_3.norm_frobenius(), 3.0);
+}
+
+#[test]
+fn test_norm_2() -> Result<(), String> {
+    // AᵀA = [[10, 10], [10, 20]], so σ_max² = 15 + √125.
+    let a = SquareMatrix::create(2, &[1.0f64, -2.0, 3.0, 4.0]);
+    let expected = (15.0 + 125f64.sqrt()).sqrt();
+    assert!((a.norm_2(1e-14, 1000)? - expected).abs() < 1e-10);
+
+    let a = Matrix::create(3, 2, &[2.0f64, 0.0, 0.0, -7.0, 0.0, 0.0]);
+    assert!((a.norm_2(1e-14, 1000)? - 7.0).abs() < 1e-10);
+
+    let v = Vector::create(&[3.0f64, -4.0]);
+    assert!((v.norm_2(1e-14, 1000)? - 5.0).abs() < 1e-12);
+
+    let zero = Matrix::create(2, 2, &[0.0; 4]);
+    assert_eq!(zero.norm_2(1e-14, 1000), Ok(0.0));
+
+    assert!(a.norm_2(1e-14, 0).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_complex_norms() -> Result<(), String> {
+    let c = |re: f64, im: f64| Complex::create(re, im);
+    let a = SquareMatrix::create(2, &[c(3.0, 4.0), c(0.0, 0.0), c(0.0, 0.0), c(0.0, 1.0)]);
+    assert_eq!(a.norm_1(), 5.0);
+    assert_eq!(a.norm_inf(), 5.0);
+    assert_eq!(a.norm_max(), 5.0);
+    assert!((a.norm_frobenius() - 26f64.sqrt()).abs() < 1e-12);
+    assert!((a.norm_2(1e-14, 1000)? - 5.0).abs() < 1e-10);
+    Ok(())
+}
@@ -1,6 +1,10 @@
 #![allow(unused_imports)]
 
-use crate::{BasicOperationsTrait, SquareMatrix, SquareMatrixTrait, Vector, Vector3};
+use crate::{
+    BasicOperationsTrait, Complex, Precision, Rational, SquareMatrix, SquareMatrixTrait, Vector,
+    Vector3,
+};
+use crate::{Matrix, Position, Shape};
 
 #[test]
 fn test_determinant() {
@@ -32,11 +36,11 @@ fn test_determinant() {
     assert_eq!(sm_1.determinant(1e-6), -2.0);
     assert_eq!(sm_2.determinant(1e-6), 0.0);
     assert_eq!(sm_3.determinant(1e-6), 1.0);
-    assert_eq!(sm_4.determinant(1e-6), -2.9999999999999982);
+    assert_eq!(sm_4.determinant(1e-6), -3.0);
     assert_eq!(sm_5.determinant(1e-6), 8.0);
     assert_eq!(sm_6.determinant(1e-6), 100.0);
     assert_eq!(sm_7.determinant(1e-6), 89.99999999999986);
-    assert_eq!(sm_8.determinant(1e-6), -9.999998);
+    assert_eq!(sm_8.determinant(1e-6), -10.0);
 }
 
 #[test]
@@ -121,27 +125,22 @@ fn test_inverse() {
     let expected_a_i_1 = SquareMatrix::create(
         3,
         &[
-            0.33248872,
+            0.33248875,
             0.0049440702,
             0.0067980965,
             -0.005181766,
             0.14290264,
             0.004183444,
-            -0.010078297,
-            0.0027097305,
+            -0.010078298,
+            0.002709731,
             0.09987973,
         ],
     );
 
-    let expected_a_i_2 = SquareMatrix::create(2, &[-2.0000002, 1.0000001, 1.5000001, -0.50000006]);
+    let expected_a_i_2 = SquareMatrix::create(2, &[-2.0, 1.0, 1.5, -0.5]);
 
-    let expected_a_i_3 = SquareMatrix::create(
-        3,
-        &[
-            1.0000025, -1.0000013, 1.0000025, -38.00014, 41.00015, -34.000126, 27.000103,
-            -29.000109, 24.000092,
-        ],
-    );
+    let expected_a_i_3 =
+        SquareMatrix::create(3, &[1.0, -1.0, 1.0, -38.0, 41.0, -34.0, 27.0, -29.0, 24.0]);
 
     let expected_a_i_4 = SquareMatrix::create(
         4,
@@ -157,6 +156,133 @@ fn test_inverse() {
     assert_eq!(a_4.inverse(&mut x_4, 1e-6), Ok(expected_a_i_4));
 }
 
+#[test]
+fn test_adjugate_and_cofactor_matrix() -> Result<(), String> {
+    let a_1 = SquareMatrix::create(2, &[1.0, 2.0, 3.0, 4.0]);
+
+    assert_eq!(
+        a_1.adjugate()?,
+        SquareMatrix::create(2, &[4.0, -2.0, -3.0, 1.0])
+    );
+    assert_eq!(
+        a_1.cofactor_matrix()?,
+        SquareMatrix::create(2, &[4.0, -3.0, -2.0, 1.0])
+    );
+
+    // A * adj(A) = det(A) * I, for a singular matrix as well.
+    let a_2 = SquareMatrix::create(
+        4,
+        &[
+            1.0, 1.0, 3.0, 4.0, 2.0, 0.0, 0.0, 8.0, 3.0, 0.0, 0.0, 2.0, 4.0, 4.0, 7.0, 5.0,
+        ],
+    );
+    let a_3 = SquareMatrix::create(3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    for (a, det) in [(a_2, 100.0), (a_3, 0.0)] {
+        let n = a.get_shape().0;
+        let product = a.multiply(&a.adjugate()?)?;
+        for i in 0..n {
+            for j in 0..n {
+                let expected = if i == j { det } else { 0.0 };
+                assert_eq!(*product.get_element_value(&Position(i, j))?, expected);
+            }
+        }
+        assert_eq!(a.cofactor_matrix()?, a.adjugate()?.transpose());
+    }
+
+    assert_eq!(
+        SquareMatrix::create(5, &[1.0; 25]).adjugate(),
+        Err("Closed-form adjugate is only available up to order 4!".to_string())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_inverse_of_singular_matrix() {
+    let a_1 = SquareMatrix::create(3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    let mut x_1 = Vector::create(&[0.0; 3]);
+
+    let a_2 = SquareMatrix::create(2, &[1.0, 1.0, 1.0, 1.0 + 1e-12]);
+    let mut x_2 = Vector::create(&[0.0; 2]);
+
+    assert_eq!(a_1.determinant(1e-6), 0.0);
+    assert_eq!(
+        a_1.inverse(&mut x_1, 1e-6),
+        Err("Singular matrix".to_string())
+    );
+    assert_eq!(
+        a_2.inverse(&mut x_2, 1e-6),
+        Err("Ill conditioned system".to_string())
+    );
+}
+
+#[test]
+fn test_gauss_gep_matrix_and_lup_decomp_matrix() -> Result<(), String> {
+    // The pivot search of the first column has to swap rows.
+    let a = SquareMatrix::create(
+        5,
+        &[
+            1.0, -2.0, 0.0, 0.0, -1.0, -2.0, 3.0, -2.0, 0.0, 0.0, 4.0, -2.0, 5.0, -3.0, 0.0, 0.0,
+            0.0, -3.0, 10.0, 4.0, -1.0, 0.0, 0.0, 4.0, 10.0,
+        ],
+    );
+    let columns = [
+        [0.0, 1.0, 0.0, 0.0, 0.0],
+        [1.0, 2.0, 3.0, 4.0, 5.0],
+        [-7.5, 0.25, 3.0, 0.0, 11.0],
+    ];
+    let mut b_values = Vec::new();
+    for i in 0..5 {
+        for column in columns.iter() {
+            b_values.push(column[i]);
+        }
+    }
+    let b = Matrix::create(5, 3, &b_values);
+
+    let x_gauss = a.gauss_gep_matrix(&b, 1e-12)?;
+    let x_lup = a.lup_decomp_matrix(&b, 1e-12)?;
+    assert_eq!(x_gauss.get_shape(), &Shape(5, 3));
+    assert_eq!(x_lup.get_shape(), &Shape(5, 3));
+
+    // Every column is exactly what the single right-hand side solvers give.
+    for (j, column) in columns.iter().enumerate() {
+        let b_j = Vector::create(column);
+        let mut x_gauss_j = Vector::create(&[0.0; 5]);
+        let mut x_lup_j = Vector::create(&[0.0; 5]);
+        a.gauss_gep(&b_j, &mut x_gauss_j, 1e-12)?;
+        a.lup_decomp(&b_j, &mut x_lup_j, 1e-12)?;
+        for i in 0..5 {
+            assert_eq!(
+                x_gauss.get_element_value(&Position(i, j))?,
+                x_gauss_j.get_element_value(&Position(i, 0))?
+            );
+            assert_eq!(
+                x_lup.get_element_value(&Position(i, j))?,
+                x_lup_j.get_element_value(&Position(i, 0))?
+            );
+        }
+    }
+
+    assert_eq!(
+        a.gauss_gep_matrix(&Matrix::create(4, 3, &[0.0; 12]), 1e-12),
+        Err("Shapes of matrices do not conform to each other!".to_string())
+    );
+
+    let singular = SquareMatrix::create(2, &[1, 2, 2, 4].map(Rational::from_integer));
+    let zero = Rational::from_integer(0);
+    let rhs = Matrix::create(2, 2, &[zero; 4]);
+    assert_eq!(
+        singular.gauss_gep_matrix(&rhs, zero),
+        Err("Singular matrix".to_string())
+    );
+    assert_eq!(
+        singular.lup_decomp_matrix(&rhs, zero),
+        Err("Singular matrix".to_string())
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_lup_decomp() -> Result<(), String> {
     let a_1 = SquareMatrix::create(3, &[3.0, -0.1, -0.2, 0.1, 7.0, -0.3, 0.3, -0.2, 10.0]);
@@ -216,3 +342,263 @@ fn test_lup_decomp() -> Result<(), String> {
 
     Ok(())
 }
+
+fn assert_complex_close(actual: Complex<f64>, expected: Complex<f64>) {
+    assert!(
+        (actual - expected).abs() < 1e-12,
+        "{actual:?} differs from {expected:?}"
+    );
+}
+
+#[test]
+fn test_complex_matrices() -> Result<(), String> {
+    let c = |re: f64, im: f64| Complex::create(re, im);
+
+    // Off-diagonal entries dominate the first column, so scaled pivoting must swap rows.
+    let a = SquareMatrix::create(
+        3,
+        &[
+            c(0.1, 0.1),
+            c(2.0, 0.0),
+            c(0.0, 1.0),
+            c(3.0, -1.0),
+            c(1.0, 1.0),
+            c(0.0, 0.0),
+            c(1.0, 0.0),
+            c(0.0, -2.0),
+            c(4.0, 1.0),
+        ],
+    );
+    let expected_x = [c(1.0, 1.0), c(2.0, -1.0), c(0.0, 3.0)];
+    let b = a.multiply(&Vector::create(&expected_x))?;
+    let b = Vector::create(&[
+        *b.get_element_value(&Position(0, 0))?,
+        *b.get_element_value(&Position(1, 0))?,
+        *b.get_element_value(&Position(2, 0))?,
+    ]);
+
+    let mut x_gauss = Vector::create(&[c(0.0, 0.0); 3]);
+    let mut x_lup = Vector::create(&[c(0.0, 0.0); 3]);
+    a.gauss_gep(&b, &mut x_gauss, 1e-9)?;
+    a.lup_decomp(&b, &mut x_lup, 1e-9)?;
+    for (i, &expected) in expected_x.iter().enumerate() {
+        assert_complex_close(*x_gauss.get_element_value(&Position(i, 0))?, expected);
+        assert_complex_close(*x_lup.get_element_value(&Position(i, 0))?, expected);
+    }
+
+    // Cofactor expansion along the first row: 0.1(1+i)(3+5i) - 2(13-i) + i(-3-7i).
+    assert_complex_close(a.determinant(1e-9), c(-19.2, -0.2));
+
+    let mut workspace = Vector::create(&[c(0.0, 0.0); 3]);
+    let a_inverse = a.inverse(&mut workspace, 1e-9)?;
+    let identity = a.multiply(&a_inverse)?;
+    for i in 0..3 {
+        for j in 0..3 {
+            let expected = if i == j { c(1.0, 0.0) } else { c(0.0, 0.0) };
+            assert_complex_close(*identity.get_element_value(&Position(i, j))?, expected);
+        }
+    }
+
+    let singular = SquareMatrix::create(2, &[c(1.0, 1.0), c(2.0, 2.0), c(1.0, 0.0), c(2.0, 0.0)]);
+    assert_eq!(singular.determinant(1e-9), c(0.0, 0.0));
+
+    Ok(())
+}
+
+#[test]
+fn test_rational_matrices_are_exact() -> Result<(), String> {
+    let r = |numerator: i128, denominator: i128| Rational::create(numerator, denominator).unwrap();
+    let zero = Rational::from_integer(0);
+
+    // 3x3 Hilbert matrix: notoriously ill conditioned, exact results are known.
+    let h = SquareMatrix::create(
+        3,
+        &[
+            r(1, 1),
+            r(1, 2),
+            r(1, 3),
+            r(1, 2),
+            r(1, 3),
+            r(1, 4),
+            r(1, 3),
+            r(1, 4),
+            r(1, 5),
+        ],
+    );
+    let expected_h_inverse = SquareMatrix::create(
+        3,
+        &[9, -36, 30, -36, 192, -180, 30, -180, 180].map(Rational::from_integer),
+    );
+    let b = Vector::create(&[r(11, 6), r(13, 12), r(47, 60)]);
+    let expected_x = Vector::create(&[r(1, 1), r(1, 1), r(1, 1)]);
+
+    let mut x_gauss = Vector::create(&[zero; 3]);
+    let mut x_lup = Vector::create(&[zero; 3]);
+    let mut workspace = Vector::create(&[zero; 3]);
+
+    assert_eq!(h.determinant(zero), r(1, 2160));
+    assert_eq!(h.inverse(&mut workspace, zero), Ok(expected_h_inverse));
+    h.gauss_gep(&b, &mut x_gauss, zero)?;
+    h.lup_decomp(&b, &mut x_lup, zero)?;
+    assert_eq!(x_gauss, expected_x);
+    assert_eq!(x_lup, expected_x);
+
+    // Zero leading entry: first-non-zero pivoting has to swap rows.
+    let p = SquareMatrix::create(2, &[0, 2, 3, 1].map(Rational::from_integer));
+    assert_eq!(p.determinant(zero), Rational::from_integer(-6));
+
+    let singular = SquareMatrix::create(2, &[1, 2, 2, 4].map(Rational::from_integer));
+    let mut x = Vector::create(&[zero; 2]);
+    assert_eq!(singular.determinant(zero), zero);
+    assert_eq!(
+        singular.lup_decomp(&Vector::create(&[zero; 2]), &mut x, zero),
+        Err("Singular matrix".to_string())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_try_determinant_and_log_determinant() -> Result<(), String> {
+    let a = SquareMatrix::create(2, &[1.0, 2.0, 3.0, 4.0]);
+    assert!((a.try_determinant(1e-6)? - -2.0f64).abs() < 1e-12);
+
+    // `determinant` gives a tiny value here; the elimination rejects the second pivot.
+    let ill_conditioned = SquareMatrix::create(2, &[1.0, 1.0, 1.0, 1.0 + 1e-12]);
+    assert_eq!(
+        ill_conditioned.try_determinant(1e-6),
+        Err("Ill conditioned system".to_string())
+    );
+    assert_eq!(
+        ill_conditioned.log_determinant(1e-6),
+        Err("Ill conditioned system".to_string())
+    );
+
+    // An exactly zero pivot is told apart from a small one in floating point as well.
+    let singular = SquareMatrix::create(2, &[1.0f64, 2.0, 2.0, 4.0]);
+    assert_eq!(
+        singular.try_determinant(1e-6),
+        Err("Singular matrix".to_string())
+    );
+    assert_eq!(
+        singular.log_determinant(1e-6),
+        Err("Singular matrix".to_string())
+    );
+
+    let zero = Rational::from_integer(0);
+    let singular = SquareMatrix::create(2, &[1, 2, 2, 4].map(Rational::from_integer));
+    assert_eq!(singular.determinant(zero), zero);
+    assert_eq!(
+        singular.try_determinant(zero),
+        Err("Singular matrix".to_string())
+    );
+
+    // det = -1e2000 overflows f64, its logarithm does not.
+    let n = 200;
+    let mut values = vec![0.0f64; n * n];
+    for i in 0..n {
+        values[i * n + i] = 1e10;
+    }
+    values[0] = -1e10;
+    let large = SquareMatrix::create(n, &values);
+    let (sign, log_modulus) = large.log_determinant(1e-6)?;
+    assert_eq!(sign, -1.0);
+    assert!((log_modulus - 200.0 * 1e10f64.ln()).abs() < 1e-9);
+    assert_eq!(large.try_determinant(1e-6)?, f64::NEG_INFINITY);
+
+    Ok(())
+}
+
+#[test]
+fn test_condition_number_estimate() -> Result<(), String> {
+    // ‖A‖₁ = 6, ‖A⁻¹‖₁ = 3.5.
+    let a = SquareMatrix::create(2, &[1.0f64, 2.0, 3.0, 4.0]);
+    assert!((a.condition_number_estimate(1e-12)? - 21.0).abs() < 1e-10);
+
+    // κ₁ of the 4 x 4 Hilbert matrix is 28375.
+    let values = (0..16)
+        .map(|k| 1.0 / ((k / 4 + k % 4 + 1) as f64))
+        .collect::<Vec<f64>>();
+    let h = SquareMatrix::create(4, &values);
+    let estimate = h.condition_number_estimate(1e-15)?;
+    assert!(estimate <= 28375.0 * (1.0 + 1e-6));
+    assert!(estimate >= 28375.0 / 3.0);
+
+    let mut x = Vector::create(&[0.0; 4]);
+    let b = Vector::create(&[1.0; 4]);
+    assert!(h.lup_decomp_checked(&b, &mut x, 1e-15, 1e3).is_err());
+    let condition_number = h.lup_decomp_checked(&b, &mut x, 1e-15, 1e6)?;
+    assert_eq!(condition_number, estimate);
+    let mut x_lup = Vector::create(&[0.0; 4]);
+    h.lup_decomp(&b, &mut x_lup, 1e-15)?;
+    for i in 0..4 {
+        let value = *x.get_element_value(&Position(i, 0))?;
+        let expected = *x_lup.get_element_value(&Position(i, 0))?;
+        assert!((value - expected).abs() < 1e-8);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_lup_decomp_refined() -> Result<(), String> {
+    // Diagonally dominant 8 x 8 system with known solution x_i = i + 1.
+    let n = 8;
+    let values = (0..n * n)
+        .map(|k| {
+            let (i, j) = (k / n, k % n);
+            if i == j {
+                10.0 + i as f64 / 3.0
+            } else {
+                1.0 / (1.0 + (i as f64 - j as f64).abs()) + 0.1 * ((i * 7 + j * 3) % 5) as f64
+            }
+        })
+        .collect::<Vec<f64>>();
+    let expected = (0..n).map(|i| (i + 1) as f64).collect::<Vec<f64>>();
+    let b_values = (0..n)
+        .map(|i| (0..n).map(|j| values[i * n + j] * expected[j]).sum())
+        .collect::<Vec<f64>>();
+    let a = SquareMatrix::create(n, &values);
+    let b = Vector::create(&b_values);
+
+    // Factoring in f32 and refining in f64 recovers double-precision accuracy.
+    let mut x = Vector::create(&vec![0.0; n]);
+    let error = a.lup_decomp_refined(&b, &mut x, 1e-12, Precision::Single, 1e-15, 10)?;
+    assert!(error <= 1e-15);
+    for (i, &expected) in expected.iter().enumerate() {
+        let value = *x.get_element_value(&Position(i, 0))?;
+        assert!((value - expected).abs() < 1e-12);
+    }
+
+    let mut x_double = Vector::create(&vec![0.0; n]);
+    let error = a.lup_decomp_refined(&b, &mut x_double, 1e-12, Precision::Double, 1e-15, 10)?;
+    assert!(error <= 1e-15);
+
+    // An f32 matrix gets an f32-accurate answer even though every residual is taken in f64.
+    let a_single = SquareMatrix::create(n, &values.iter().map(|v| *v as f32).collect::<Vec<f32>>());
+    let b_single = Vector::create(&b_values.iter().map(|v| *v as f32).collect::<Vec<f32>>());
+    let mut x_single = Vector::create(&vec![0f32; n]);
+    a_single.lup_decomp_refined(&b_single, &mut x_single, 1e-6, Precision::Single, 1e-15, 10)?;
+    for (i, &expected) in expected.iter().enumerate() {
+        let value = *x_single.get_element_value(&Position(i, 0))?;
+        assert!((value - expected as f32).abs() < 1e-5 * expected as f32);
+    }
+
+    // With κ(A) beyond 1 / ε_f32 the f32 factors cannot drive the error down; refinement stops
+    // early and reports the error it reached instead of looping until the cap.
+    let h_values = (0..100)
+        .map(|k| 1.0 / ((k / 10 + k % 10 + 1) as f64))
+        .collect::<Vec<f64>>();
+    let h = SquareMatrix::create(10, &h_values);
+    let mut x_h = Vector::create(&[0.0; 10]);
+    let error = h.lup_decomp_refined(
+        &Vector::create(&[1.0; 10]),
+        &mut x_h,
+        1e-30,
+        Precision::Single,
+        1e-15,
+        50,
+    )?;
+    assert!(error > 1e-15);
+
+    Ok(())
+}
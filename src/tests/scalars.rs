@@ -0,0 +1,2 @@
+mod test_complex;
+mod test_rational;
@@ -0,0 +1,54 @@
+#![allow(unused_imports)]
+
+use crate::{Complex, FieldTrait};
+
+const ABS_TOL: f64 = 1e-12;
+
+#[test]
+fn test_arithmetic() {
+    let a = Complex::create(1.0, 2.0);
+    let b = Complex::create(3.0, -1.0);
+
+    assert_eq!(a + b, Complex::create(4.0, 1.0));
+    assert_eq!(a - b, Complex::create(-2.0, 3.0));
+    assert_eq!(a * b, Complex::create(5.0, 5.0));
+    assert_eq!(-a, Complex::create(-1.0, -2.0));
+    assert_eq!(a.conj(), Complex::create(1.0, -2.0));
+    assert_eq!(Complex::<f64>::i() * Complex::i(), Complex::from(-1.0));
+}
+
+#[test]
+fn test_div() {
+    let a = Complex::create(5.0f64, 5.0);
+    let b = Complex::create(3.0, -1.0);
+    let c = Complex::create(1e-3, 4.0);
+
+    let q_1 = a / b;
+    let q_2 = (a * c) / c;
+
+    assert!((q_1.re - 1.0).abs() < ABS_TOL && (q_1.im - 2.0).abs() < ABS_TOL);
+    assert!((q_2.re - 5.0).abs() < ABS_TOL && (q_2.im - 5.0).abs() < ABS_TOL);
+}
+
+#[test]
+fn test_abs_and_polar() {
+    let a = Complex::create(3.0, -4.0);
+    let b = Complex::create(3e200f64, 4e200);
+    let c = Complex::from_polar(2.0, std::f64::consts::FRAC_PI_2);
+
+    assert_eq!(a.abs(), 5.0);
+    assert_eq!(a.modulus(), 5.0);
+    assert!((b.abs() / 5e200 - 1.0).abs() < ABS_TOL);
+    assert!(c.re.abs() < ABS_TOL && (c.im - 2.0).abs() < ABS_TOL);
+    assert!((c.arg() - std::f64::consts::FRAC_PI_2).abs() < ABS_TOL);
+}
+
+#[test]
+fn test_field_trait() {
+    let a = Complex::create(1.0f32, -1.0);
+
+    assert_eq!(Complex::from_real(2.0f32), Complex::create(2.0, 0.0));
+    assert_eq!(a.conjugate(), Complex::create(1.0, 1.0));
+    assert!(!a.is_nan());
+    assert!(Complex::create(f32::NAN, 0.0).is_nan());
+}
@@ -0,0 +1,87 @@
+#![allow(unused_imports)]
+
+use crate::{FieldTrait, Pivoting, Rational};
+
+fn r(numerator: i128, denominator: i128) -> Rational {
+    Rational::create(numerator, denominator).unwrap()
+}
+
+#[test]
+fn test_create_reduces() {
+    let a = r(6, -8);
+
+    assert_eq!(a.get_numerator(), -3);
+    assert_eq!(a.get_denominator(), 4);
+    assert_eq!(
+        Rational::create(1, 0),
+        Err("Rational: denominator equals to zero!".to_string())
+    );
+}
+
+#[test]
+fn test_arithmetic() {
+    let a = r(1, 6);
+    let b = r(3, 4);
+
+    assert_eq!(a + b, r(11, 12));
+    assert_eq!(a - b, r(-7, 12));
+    assert_eq!(a * b, r(1, 8));
+    assert_eq!(a / b, r(2, 9));
+    assert_eq!(-a, r(-1, 6));
+    assert_eq!(b.recip(), r(4, 3));
+}
+
+#[test]
+fn test_from_f32_is_exact() {
+    assert_eq!(Rational::from(0.5f32), r(1, 2));
+    assert_eq!(Rational::from(-3.0f32), Rational::from_integer(-3));
+    assert_eq!(Rational::from(0.1f32), r(13421773, 134217728));
+    assert_eq!(Rational::from(f32::MIN_POSITIVE), r(1, 1 << 126));
+}
+
+#[test]
+fn test_from_f32_limits() {
+    assert_eq!(Rational::from_f32(2f32.powi(126)), Ok(r(1 << 126, 1)));
+    assert!(Rational::from_f32(-2f32.powi(127)).is_err());
+    assert!(Rational::from_f32(f32::MAX).is_err());
+    assert!(Rational::from_f32(f32::NAN).is_err());
+    assert!(Rational::from_f32(f32::INFINITY).is_err());
+    // 1.5 * 2^-126 needs a denominator of 2^127.
+    assert!(Rational::from_f32(1.5 * f32::MIN_POSITIVE).is_err());
+    assert!(Rational::from_f32(f32::from_bits(1)).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Rational: arithmetic overflow!")]
+fn test_abs_overflow_panics() {
+    assert_eq!(r(i128::MIN, 1).get_numerator(), i128::MIN);
+    Rational::from_integer(i128::MIN).abs();
+}
+
+#[test]
+#[should_panic(expected = "Rational: arithmetic overflow!")]
+fn test_negation_overflow_panics() {
+    let _ = -Rational::from_integer(i128::MIN);
+}
+
+#[test]
+#[should_panic(expected = "Rational: arithmetic overflow!")]
+fn test_reduction_overflow_panics() {
+    let _ = Rational::create(i128::MIN, -1);
+}
+
+#[test]
+fn test_ordering_and_display() {
+    assert!(r(1, 3) < r(1, 2));
+    assert!(r(-1, 2) < r(-1, 3));
+    assert_eq!(r(-7, 3).modulus(), r(7, 3));
+    assert_eq!(r(5, 1).to_string(), "5");
+    assert_eq!(r(-2, 6).to_string(), "-1/3");
+}
+
+#[test]
+fn test_field_trait() {
+    assert_eq!(Rational::PIVOTING, Pivoting::FirstNonZero);
+    assert_eq!(r(2, 3).conjugate(), r(2, 3));
+    assert!(!r(2, 3).is_nan());
+}
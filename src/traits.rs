@@ -1,3 +1,7 @@
+mod field_trait;
 mod float_trait;
+mod maybe_send_sync_trait;
 
+pub use field_trait::FieldTrait;
 pub use float_trait::FloatTrait;
+pub use maybe_send_sync_trait::MaybeSendSyncTrait;
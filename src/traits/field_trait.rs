@@ -0,0 +1,76 @@
+// external imports
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
+
+use crate::{MaybeSendSyncTrait, Pivoting};
+
+/// Scalar that supports the field operations the square-matrix algorithms need.
+///
+/// Ordering is deliberately not part of this trait: complex numbers have none. Pivot selection,
+/// tolerances and other size comparisons go through `modulus`, whose result lives in the ordered
+/// `Real` type (`Self` for `f32`/`f64`, the component type for `Complex`). Exact types override
+/// `PIVOTING` so elimination takes the first non-zero pivot instead of comparing magnitudes.
+pub trait FieldTrait:
+    Debug
+    + Copy
+    + PartialEq
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + From<f32>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + MaybeSendSyncTrait
+    + 'static
+{
+    type Real: FieldTrait<Real = Self::Real> + PartialOrd;
+
+    const PIVOTING: Pivoting = Pivoting::LargestMagnitude;
+
+    fn modulus(&self) -> Self::Real;
+    fn from_real(value: Self::Real) -> Self;
+    fn conjugate(&self) -> Self;
+    fn is_nan(&self) -> bool;
+}
+
+impl FieldTrait for f32 {
+    type Real = f32;
+
+    fn modulus(&self) -> f32 {
+        self.abs()
+    }
+
+    fn from_real(value: f32) -> Self {
+        value
+    }
+
+    fn conjugate(&self) -> Self {
+        *self
+    }
+
+    fn is_nan(&self) -> bool {
+        f32::is_nan(*self)
+    }
+}
+
+impl FieldTrait for f64 {
+    type Real = f64;
+
+    fn modulus(&self) -> f64 {
+        self.abs()
+    }
+
+    fn from_real(value: f64) -> Self {
+        value
+    }
+
+    fn conjugate(&self) -> Self {
+        *self
+    }
+
+    fn is_nan(&self) -> bool {
+        f64::is_nan(*self)
+    }
+}
@@ -3,6 +3,8 @@ use extended_matrix_float::MyFloatTrait;
 use std::fmt::Debug;
 use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
 
+use crate::MaybeSendSyncTrait;
+
 pub trait FloatTrait:
     Debug
     + Copy
@@ -18,9 +20,16 @@ pub trait FloatTrait:
     + Mul<Output = <Self as FloatTrait>::Output>
     + Div<Output = <Self as FloatTrait>::Output>
     + MyFloatTrait
+    + MaybeSendSyncTrait
     + 'static
 {
     type Output;
+
+    /// Conversion from `f64`, rounding to nearest when `Self` is narrower. The default goes through
+    /// `f32`, so types wider than `f32` should override it.
+    fn from_f64(value: f64) -> Self {
+        Self::from(value as f32)
+    }
 }
 
 impl FloatTrait for f32 {
@@ -29,4 +38,8 @@ impl FloatTrait for f32 {
 
 impl FloatTrait for f64 {
     type Output = f64;
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
 }
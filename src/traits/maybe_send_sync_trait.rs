@@ -0,0 +1,19 @@
+/// Thread-safety bound that only exists when the `rayon` feature is enabled.
+///
+/// Without `rayon` every type implements it, so generic code can require it unconditionally
+/// and serial builds keep accepting non-`Send` element types and closures.
+#[cfg(feature = "rayon")]
+pub trait MaybeSendSyncTrait: Send + Sync {}
+
+#[cfg(feature = "rayon")]
+impl<T> MaybeSendSyncTrait for T where T: Send + Sync {}
+
+/// Thread-safety bound that only exists when the `rayon` feature is enabled.
+///
+/// Without `rayon` every type implements it, so generic code can require it unconditionally
+/// and serial builds keep accepting non-`Send` element types and closures.
+#[cfg(not(feature = "rayon"))]
+pub trait MaybeSendSyncTrait {}
+
+#[cfg(not(feature = "rayon"))]
+impl<T> MaybeSendSyncTrait for T {}